@@ -0,0 +1,89 @@
+// ~/src/annotated.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Annotated Date Ranges
+//!
+//! This module defines [`AnnotatedDay`] and the [`AnnotatedRange`] iterator returned by
+//! [`ParsiDate::annotated_range`](crate::ParsiDate::annotated_range), which yields each day of a
+//! range together with the information a calendar grid usually needs for it (weekday, weekend
+//! and holiday markers).
+
+use crate::constants::FIXED_SOLAR_HOLIDAYS;
+use crate::date::ParsiDate;
+use crate::weekday::ParsiWeekday;
+
+/// A single day produced by [`ParsiDate::annotated_range`](crate::ParsiDate::annotated_range).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct AnnotatedDay {
+    /// The date itself.
+    pub date: ParsiDate,
+    /// The day of the week.
+    pub weekday: ParsiWeekday,
+    /// `true` if the day is a Friday (جمعه), the Iranian weekly holiday.
+    pub is_friday: bool,
+    /// `true` if the day is an official public holiday on a fixed solar calendar date
+    /// (e.g., Nowruz or 22 Bahman). Fridays are not counted here; see `is_friday`.
+    ///
+    /// Holidays defined on the lunar Hijri calendar are not included.
+    pub is_holiday: bool,
+    /// The Persian name of the holiday, if `is_holiday` is `true`.
+    pub holiday_name: Option<&'static str>,
+}
+
+/// An iterator over the days of an inclusive date range, yielding [`AnnotatedDay`] values.
+///
+/// Created by [`ParsiDate::annotated_range`](crate::ParsiDate::annotated_range). Only the start
+/// date is converted to the Gregorian calendar (to find its weekday); subsequent days are
+/// produced by stepping through the Persian calendar directly.
+#[derive(Debug, Clone)]
+pub struct AnnotatedRange {
+    next: Option<ParsiDate>,
+    weekday: u32,
+    end: ParsiDate,
+}
+
+impl AnnotatedRange {
+    /// **Internal**: Creates the iterator. `start_weekday` is the Saturday-0 weekday of `start`.
+    pub(crate) fn new(start: ParsiDate, end: ParsiDate, start_weekday: u32) -> Self {
+        AnnotatedRange {
+            next: if start <= end { Some(start) } else { None },
+            weekday: start_weekday,
+            end,
+        }
+    }
+}
+
+impl Iterator for AnnotatedRange {
+    type Item = AnnotatedDay;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let date = self.next?;
+        let weekday = ParsiWeekday::from_num_sat_0(self.weekday)?;
+        let holiday_name = FIXED_SOLAR_HOLIDAYS
+            .iter()
+            .find(|(m, d, _)| *m == date.month() && *d == date.day())
+            .map(|(_, _, name)| *name);
+
+        // Step to the following day without a Gregorian round-trip.
+        self.next = if date >= self.end {
+            None
+        } else {
+            Some(date.following_day_unchecked())
+        };
+        self.weekday = (self.weekday + 1) % 7;
+
+        Some(AnnotatedDay {
+            date,
+            weekday,
+            is_friday: weekday == ParsiWeekday::Jomeh,
+            is_holiday: holiday_name.is_some(),
+            holiday_name,
+        })
+    }
+}
@@ -0,0 +1,45 @@
+// ~/src/arbitrary.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Fuzzing Support
+//!
+//! This module (available with the `arbitrary` feature) implements [`arbitrary::Arbitrary`] for
+//! [`ParsiDate`] and [`ParsiDateTime`], so that they can be used as structured input in
+//! `cargo-fuzz` targets.
+//!
+//! Only **valid** instances are generated: the year is drawn from 1-9999 and the month from
+//! 1-12, then the day is drawn from the actual length of that month (respecting leap years),
+//! so no input is rejected or retried.
+
+use crate::date::ParsiDate;
+use crate::datetime::ParsiDateTime;
+use ::arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for ParsiDate {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let year = u.int_in_range(1..=9999)?;
+        let month = u.int_in_range(1..=12)?;
+        let day = u.int_in_range(1..=ParsiDate::days_in_month(year, month))?;
+        Ok(ParsiDate { year, month, day })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ParsiDateTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let date = ParsiDate::arbitrary(u)?;
+        let hour = u.int_in_range(0..=23)?;
+        let minute = u.int_in_range(0..=59)?;
+        let second = u.int_in_range(0..=59)?;
+        let nano = u.int_in_range(0..=999_999_999)?;
+        // All components are in range, so this cannot fail.
+        ParsiDateTime::from_date_and_time(date, hour, minute, second)
+            .and_then(|dt| dt.with_nanosecond(nano))
+            .map_err(|_| Error::IncorrectFormat)
+    }
+}
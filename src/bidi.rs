@@ -0,0 +1,74 @@
+// ~/src/bidi.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Bidirectional Text Isolation
+//!
+//! This module defines the [`BidiIsolate`] enum, which selects the Unicode bidirectional
+//! isolate control character used by [`ParsiDate::format_bidi_isolated`](crate::ParsiDate::format_bidi_isolated).
+//!
+//! When a formatted Persian date (containing right-to-left month names and left-to-right digits)
+//! is embedded in text of the opposite direction, the Unicode Bidirectional Algorithm may reorder
+//! its parts in confusing ways. Wrapping the date in an isolate (`LRI`, `RLI` or `FSI`, closed by
+//! `PDI`) makes it render as a self-contained unit regardless of the surrounding text direction.
+
+/// The Unicode "Pop Directional Isolate" character (U+2069) that closes any isolate.
+pub(crate) const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+/// Selects the Unicode bidi isolate control used to open an isolated run of text.
+///
+/// Every isolate is closed with the "Pop Directional Isolate" character (U+2069).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BidiIsolate {
+    /// **Left-to-Right Isolate** (U+2066). Forces the isolated text to be laid out left-to-right.
+    LeftToRight,
+    /// **Right-to-Left Isolate** (U+2067). Forces the isolated text to be laid out right-to-left.
+    RightToLeft,
+    /// **First Strong Isolate** (U+2068). Derives the direction from the first strong
+    /// directional character inside the isolated text.
+    FirstStrong,
+}
+
+impl BidiIsolate {
+    /// Returns the Unicode control character that opens this kind of isolate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::BidiIsolate;
+    ///
+    /// assert_eq!(BidiIsolate::LeftToRight.opening_char(), '\u{2066}');
+    /// assert_eq!(BidiIsolate::RightToLeft.opening_char(), '\u{2067}');
+    /// assert_eq!(BidiIsolate::FirstStrong.opening_char(), '\u{2068}');
+    /// ```
+    #[inline]
+    pub fn opening_char(&self) -> char {
+        match self {
+            BidiIsolate::LeftToRight => '\u{2066}', // LRI
+            BidiIsolate::RightToLeft => '\u{2067}', // RLI
+            BidiIsolate::FirstStrong => '\u{2068}', // FSI
+        }
+    }
+
+    /// Returns the Unicode control character that closes an isolate (`PDI`, U+2069).
+    ///
+    /// The closing character is the same for all isolate kinds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::BidiIsolate;
+    ///
+    /// assert_eq!(BidiIsolate::FirstStrong.closing_char(), '\u{2069}');
+    /// ```
+    #[inline]
+    pub fn closing_char(&self) -> char {
+        POP_DIRECTIONAL_ISOLATE
+    }
+}
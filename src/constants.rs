@@ -98,6 +98,25 @@ pub(crate) const MONTH_NAMES_PERSIAN: [&str; 12] = [
     "اسفند",
 ];
 
+/// Latin transliterations ("Finglish") of the Persian month names, indexed from 0 (Farvardin).
+///
+/// Used for the `%b` format specifier, e.g., in URLs, file names and ASCII-only logs. The
+/// spellings are part of the public output and must not change.
+pub(crate) const MONTH_NAMES_FINGLISH: [&str; 12] = [
+    "Farvardin",
+    "Ordibehesht",
+    "Khordad",
+    "Tir",
+    "Mordad",
+    "Shahrivar",
+    "Mehr",
+    "Aban",
+    "Azar",
+    "Dey",
+    "Bahman",
+    "Esfand",
+];
+
 /// An array of Persian weekday names, indexed from 0, starting with Saturday.
 ///
 /// This is used internally for formatting dates, specifically for the `%A` format specifier.
@@ -117,6 +136,33 @@ pub(crate) const WEEKDAY_NAMES_PERSIAN: [&str; 7] = [
     "جمعه",
 ];
 
+/// Latin transliterations ("Finglish") of the Persian weekday names, used for the `%a` format
+/// specifier. The order matches `WEEKDAY_NAMES_PERSIAN` (Saturday first) and the spellings must
+/// not change.
+pub(crate) const WEEKDAY_NAMES_FINGLISH: [&str; 7] = [
+    "Shanbeh",
+    "Yekshanbeh",
+    "Doshanbeh",
+    "Seshanbeh",
+    "Chaharshanbeh",
+    "Panjshanbeh",
+    "Jomeh",
+];
+
+/// An array of English weekday names, corresponding to `WEEKDAY_NAMES_PERSIAN`.
+///
+/// This is used internally by the [`ParsiWeekday`](crate::weekday::ParsiWeekday) enum to provide
+/// English string representations. The order matches `WEEKDAY_NAMES_PERSIAN` (Saturday first).
+pub(crate) const WEEKDAY_NAMES_ENGLISH: [&str; 7] = [
+    "Saturday",
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+];
+
 /// An array of Persian season names, indexed from 0.
 ///
 /// This is used internally by the [`Season`](crate::season::Season) enum to provide string representations,
@@ -138,3 +184,96 @@ pub(crate) const SEASON_NAMES_PERSIAN: [&str; 4] = ["بهار", "تابستان"
 /// - `index 2`: "Autumn"
 /// - `index 3`: "Winter"
 pub(crate) const SEASON_NAMES_ENGLISH: [&str; 4] = ["Spring", "Summer", "Autumn", "Winter"];
+
+/// Latin transliterations ("Finglish") of the Persian season names, matching the
+/// [`Season`](crate::season::Season) variant names. The order matches `SEASON_NAMES_PERSIAN`.
+pub(crate) const SEASON_NAMES_FINGLISH: [&str; 4] = ["Bahar", "Tabestan", "Paeez", "Zemestan"];
+
+/// AM/PM markers recognized when parsing the `%p` specifier.
+///
+/// Even indices are ante meridiem (before noon) markers and odd indices are post meridiem
+/// (after noon) markers, so `index % 2 == 1` identifies PM.
+///
+/// - `index 0`: "ق.ظ" (Ghabl az Zohr / AM)
+/// - `index 1`: "ب.ظ" (Ba'd az Zohr / PM)
+/// - `index 2..=5`: "AM", "PM", "am", "pm"
+pub(crate) const AM_PM_MARKERS: [&str; 6] = ["ق.ظ", "ب.ظ", "AM", "PM", "am", "pm"];
+
+/// Persian words for the numbers 0 through 19, used when spelling out years (`%EY`).
+///
+/// `index 0` is empty because zero is never spelled out as part of a larger number.
+pub(crate) const PERSIAN_WORDS_BELOW_20: [&str; 20] = [
+    "",
+    "یک",
+    "دو",
+    "سه",
+    "چهار",
+    "پنج",
+    "شش",
+    "هفت",
+    "هشت",
+    "نه",
+    "ده",
+    "یازده",
+    "دوازده",
+    "سیزده",
+    "چهارده",
+    "پانزده",
+    "شانزده",
+    "هفده",
+    "هجده",
+    "نوزده",
+];
+
+/// Persian words for the multiples of ten (`index * 10`), used when spelling out years.
+///
+/// Indices 0 and 1 are empty; 10-19 are covered by `PERSIAN_WORDS_BELOW_20`.
+pub(crate) const PERSIAN_WORDS_TENS: [&str; 10] = [
+    "",
+    "",
+    "بیست",
+    "سی",
+    "چهل",
+    "پنجاه",
+    "شصت",
+    "هفتاد",
+    "هشتاد",
+    "نود",
+];
+
+/// Persian words for the multiples of one hundred (`index * 100`), used when spelling out years.
+pub(crate) const PERSIAN_WORDS_HUNDREDS: [&str; 10] = [
+    "",
+    "صد",
+    "دویست",
+    "سیصد",
+    "چهارصد",
+    "پانصد",
+    "ششصد",
+    "هفتصد",
+    "هشتصد",
+    "نهصد",
+];
+
+/// Official public holidays of Iran that fall on a fixed Persian (solar) calendar date.
+///
+/// Each entry is `(month, day, name)`. Holidays defined on the lunar Hijri calendar (e.g., Eid
+/// al-Fitr) move relative to the solar calendar every year and are therefore not included.
+pub(crate) const FIXED_SOLAR_HOLIDAYS: [(u32, u32, &str); 10] = [
+    (1, 1, "عید نوروز"),
+    (1, 2, "عید نوروز"),
+    (1, 3, "عید نوروز"),
+    (1, 4, "عید نوروز"),
+    (1, 12, "روز جمهوری اسلامی"),
+    (1, 13, "روز طبیعت"),
+    (3, 14, "رحلت امام خمینی"),
+    (3, 15, "قیام ۱۵ خرداد"),
+    (11, 22, "پیروزی انقلاب اسلامی"),
+    (12, 29, "روز ملی شدن صنعت نفت"),
+];
+
+/// The era marker of the Solar Hijri (Persian) calendar, "ه.ش" (هجری شمسی), used by `%Ee`.
+pub(crate) const ERA_MARKER_PERSIAN: &str = "ه.ش";
+
+/// The Persian term for a leap year, "کبیسه", used by `%?L`.
+pub(crate) const LEAP_YEAR_MARKER_PERSIAN: &str = "کبیسه";
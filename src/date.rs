@@ -11,15 +11,115 @@
 //! dates within the Persian (Jalali or Shamsi) calendar system.
 
 // Use necessary items from other modules and external crates
+use crate::annotated::AnnotatedRange;
+use crate::bidi::BidiIsolate;
 use crate::constants::{
-    MAX_PARSI_DATE, MIN_PARSI_DATE, MONTH_NAMES_PERSIAN, WEEKDAY_NAMES_PERSIAN,
+    ERA_MARKER_PERSIAN, LEAP_YEAR_MARKER_PERSIAN, MAX_PARSI_DATE, MIN_PARSI_DATE,
+    MONTH_NAMES_FINGLISH, MONTH_NAMES_PERSIAN, PERSIAN_WORDS_BELOW_20, PERSIAN_WORDS_HUNDREDS,
+    PERSIAN_WORDS_TENS, WEEKDAY_NAMES_ENGLISH, WEEKDAY_NAMES_FINGLISH,
 };
-use crate::error::{DateError, ParseErrorKind};
+use crate::digits::{normalize_digits, DigitStyle};
+use crate::error::{DateError, LocatedParseError, ParseErrorKind};
+use crate::interval::ParsiInterval;
+use crate::iter::ParsiDateIter;
+use crate::names::ParsiNames;
 use crate::season::Season;
+use crate::view::ParsiDateView;
+use crate::weekday::ParsiWeekday;
+use crate::year_calendar::{MonthInfo, YearCalendar};
 use chrono::{Datelike, NaiveDate};
+use std::borrow::Cow;
 use std::fmt;
+use std::ops::Sub;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// **Internal**: Returns the Gregorian date of the Persian epoch (1/1/1 Parsi), March 21st, 622.
+///
+/// The `NaiveDate` is constructed once and cached, so the conversion hot paths neither rebuild it
+/// nor need to handle the (impossible) failure of `NaiveDate::from_ymd_opt` for this fixed date.
+fn persian_epoch_gregorian_start() -> NaiveDate {
+    static EPOCH: OnceLock<NaiveDate> = OnceLock::new();
+    *EPOCH.get_or_init(|| {
+        NaiveDate::from_ymd_opt(622, 3, 21).expect("622-03-21 is a valid Gregorian date")
+    })
+}
+
+/// **Internal**: The Julian Day Number of the Persian epoch (1/1/1 Parsi, Gregorian 622-03-21).
+const PERSIAN_EPOCH_JDN: i64 = 1_948_320;
+
+/// **Internal**: The number of days in one 33-year leap cycle (8 leap years).
+const DAYS_PER_33_YEAR_CYCLE: i64 = 33 * 365 + 8;
+
+/// **Internal**: Writes `n` using the Extended Arabic-Indic (Persian) digits `۰`-`۹`.
+fn to_persian_digits(n: u32) -> String {
+    DigitStyle::Persian.convert(&n.to_string())
+}
+
+/// **Internal**: Prepares `YYYY/MM/DD` or `YYYY-MM-DD` input (optionally followed by more text)
+/// for [`ParsiDate::parse`], as used by the `FromStr` implementations.
+///
+/// `Display` writes years below 1000 without padding (e.g., `50/01/01`), while `%Y` parses exactly
+/// four digits, so a shorter leading year is zero-padded here. Returns the prepared input with the
+/// matching date format.
+pub(crate) fn prepare_display_date(s: &str) -> (Cow<'_, str>, &'static str) {
+    let year_len = s.bytes().take_while(|b| b.is_ascii_digit()).count();
+    let format = if s.as_bytes().get(year_len) == Some(&b'-') {
+        "%Y-%m-%d"
+    } else {
+        "%Y/%m/%d"
+    };
+    if (1..4).contains(&year_len) {
+        (
+            Cow::Owned(format!("{}{}", "0".repeat(4 - year_len), s)),
+            format,
+        )
+    } else {
+        (Cow::Borrowed(s), format)
+    }
+}
+
+/// **Internal**: Returns the English weekday name of a Gregorian date, for `%GA` (or its
+/// three-letter abbreviation, for `%Ga`).
+pub(crate) fn english_weekday_name(gregorian: &NaiveDate, abbreviated: bool) -> &'static str {
+    // WEEKDAY_NAMES_ENGLISH starts on Saturday, chrono counts from Sunday.
+    let name =
+        WEEKDAY_NAMES_ENGLISH[((gregorian.weekday().num_days_from_sunday() + 1) % 7) as usize];
+    if abbreviated {
+        &name[..3]
+    } else {
+        name
+    }
+}
+
+/// **Internal**: Writes `s` honoring the formatter's width, fill and alignment, as used by the
+/// `Display` implementations.
+///
+/// Unlike [`fmt::Formatter::pad`], a precision (e.g., `{:.4}`) is ignored instead of truncating
+/// the output, since a cut-off date would read as a different value. Width counts characters,
+/// and text without an explicit alignment is left-aligned.
+pub(crate) fn pad_display(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let len = s.chars().count();
+    let padding = match f.width() {
+        Some(width) if width > len => width - len,
+        _ => return f.write_str(s),
+    };
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
 // use std::ops::{Add, Sub}; // For potential future Duration addition
-// use std::str::FromStr; // For potential future direct FromStr impl
 
 // --- Data Structures ---
 
@@ -38,7 +138,10 @@ use std::fmt;
 /// Operations resulting in dates outside this range will typically return an error.
 ///
 /// **Serialization:** If the `serde` feature is enabled, this struct derives `Serialize` and `Deserialize`.
-#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+///
+/// **Debugging:** The `Debug` output names the calendar system, e.g., `ParsiDate(1403-05-02 [Jalali])`
+/// (see the `Debug` implementation).
+#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParsiDate {
     /// The year component of the Persian date (e.g., 1403). Must be between 1 and 9999 inclusive.
@@ -49,6 +152,19 @@ pub struct ParsiDate {
     pub(crate) day: u32,
 }
 
+/// The calendar system detected by [`ParsiDate::parse_auto_calendar_typed`].
+///
+/// Tells the caller how an ambiguous numeric date string was interpreted, e.g., so that a date
+/// typed in Gregorian can be echoed back in Gregorian.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DetectedCalendar {
+    /// The input was interpreted as a Persian (Jalali) date.
+    Persian,
+    /// The input was interpreted as a Gregorian date and converted to Persian.
+    Gregorian,
+}
+
 // --- Core Implementation ---
 
 impl ParsiDate {
@@ -171,6 +287,25 @@ impl ParsiDate {
         ParsiDate { year, month, day }
     }
 
+    /// **Internal**: Creates a `ParsiDate`, validating only the month and day.
+    ///
+    /// The caller must guarantee that `year` is within the supported range (1-9999), e.g.
+    /// because it has already range-checked it or taken it from a valid date. The arithmetic and
+    /// `with_*` methods share this month/day check; for such years the result is identical to
+    /// [`ParsiDate::new`].
+    pub(crate) fn new_validated_components(
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> Result<Self, DateError> {
+        debug_assert!((MIN_PARSI_DATE.year..=MAX_PARSI_DATE.year).contains(&year));
+        // days_in_month returns 0 for an invalid month, which also rejects it here.
+        if day == 0 || day > Self::days_in_month(year, month) {
+            return Err(DateError::InvalidDate);
+        }
+        Ok(ParsiDate { year, month, day })
+    }
+
     /// Creates a `ParsiDate` from the day number within a given Persian year (the ordinal day).
     ///
     /// The ordinal day counts from the beginning of the year, where `ordinal = 1` corresponds
@@ -184,10 +319,12 @@ impl ParsiDate {
     ///
     /// # Errors
     ///
-    /// Returns `Err(DateError::InvalidOrdinal)` if the `ordinal` value is 0 or greater than
-    /// the number of days actually present in the specified `year`.
-    /// Returns `Err(DateError::InvalidDate)` if the provided `year` is outside the supported
-    /// range [1, 9999] (this check is performed by the final internal call to `ParsiDate::new`).
+    /// *   `Err(DateError::InvalidDate)` if the `year` is outside the supported range [1, 9999].
+    ///     The year is checked first, so this is returned even when the `ordinal` is also invalid.
+    /// *   `Err(DateError::InvalidOrdinal)` if the `year` is supported but the `ordinal` is 0 or
+    ///     greater than the number of days in that year.
+    ///
+    /// Use [`checked_from_ordinal`](#method.checked_from_ordinal) to get an `Option` instead.
     ///
     /// # Examples
     ///
@@ -216,10 +353,15 @@ impl ParsiDate {
     /// // Error: Ordinal is zero
     /// assert_eq!(ParsiDate::from_ordinal(1403, 0), Err(DateError::InvalidOrdinal));
     ///
-    /// // Error: Invalid year
-    /// assert!(matches!(ParsiDate::from_ordinal(0, 100), Err(DateError::InvalidDate))); // Final validation fails
+    /// // Error: Invalid year, regardless of the ordinal
+    /// assert_eq!(ParsiDate::from_ordinal(0, 100), Err(DateError::InvalidDate));
+    /// assert_eq!(ParsiDate::from_ordinal(10000, 0), Err(DateError::InvalidDate));
     /// ```
     pub fn from_ordinal(year: i32, ordinal: u32) -> Result<Self, DateError> {
+        // Year problems take precedence, so the error kind does not depend on the ordinal.
+        if !(MIN_PARSI_DATE.year..=MAX_PARSI_DATE.year).contains(&year) {
+            return Err(DateError::InvalidDate);
+        }
         // Basic validation: ordinal must be positive.
         if ordinal == 0 {
             return Err(DateError::InvalidOrdinal);
@@ -236,31 +378,52 @@ impl ParsiDate {
             return Err(DateError::InvalidOrdinal);
         }
 
-        // Calculate the month and day corresponding to the ordinal day.
-        let mut month = 1u32;
-        let mut day = ordinal; // Start with day = ordinal
-        let month_lengths = Self::month_lengths(year);
-
-        // Iterate through the months, subtracting month lengths until the correct month is found.
-        for (m_idx, length) in month_lengths.iter().enumerate() {
-            if day <= *length {
-                // The remaining 'day' value falls within this month's length.
-                month = (m_idx + 1) as u32; // Found the month (m_idx is 0-based, month is 1-based)
-                                            // The 'day' value at this point is the correct day of the month.
-                break; // Exit the loop
-            }
-            // Subtract the full length of the current month and proceed to the next.
-            day -= *length;
-            // Note: 'month' variable is implicitly updated in the next iteration or keeps the last value if loop ends.
-        }
+        // Calculate the month and day corresponding to the ordinal day: the month is the last
+        // one starting before `ordinal`, and the day is the remainder past its start offset.
+        let offsets = Self::month_start_offsets(year);
+        let month = Self::month_containing_offset(&offsets, ordinal - 1);
+        let day = ordinal - offsets[(month - 1) as usize];
 
         // Use the safe ParsiDate::new() constructor for final validation.
-        // This ensures the calculated year/month/day are valid and checks the year range.
-        // While the logic above should produce valid month/day if ordinal was valid,
-        // this provides robustness and handles the year range check [1, 9999].
+        // The logic above should always produce a valid month/day for a valid ordinal;
+        // this provides robustness.
         ParsiDate::new(year, month, day)
     }
 
+    /// Creates a `ParsiDate` from a year and day of the year, returning `None` if the combination
+    /// is invalid.
+    ///
+    /// This is the `Option` counterpart of [`from_ordinal`](#method.from_ordinal), convenient in
+    /// code that iterates over ordinals and simply skips the ones that do not exist (such as day
+    /// 366 of a common year).
+    ///
+    /// # Arguments
+    ///
+    /// * `year`: The Persian year (must be 1-9999).
+    /// * `ordinal`: The day number within the year (1 to 365 or 1 to 366).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// assert_eq!(ParsiDate::checked_from_ordinal(1403, 366), ParsiDate::new(1403, 12, 30).ok());
+    /// assert_eq!(ParsiDate::checked_from_ordinal(1404, 366), None); // Common year
+    /// assert_eq!(ParsiDate::checked_from_ordinal(1403, 0), None);
+    /// assert_eq!(ParsiDate::checked_from_ordinal(0, 1), None);
+    ///
+    /// // Day 366 exists only in leap years
+    /// let leap_ends: Vec<i32> = (1400..=1410)
+    ///     .filter_map(|y| ParsiDate::checked_from_ordinal(y, 366))
+    ///     .map(|d| d.year())
+    ///     .collect();
+    /// assert_eq!(leap_ends, vec![1403, 1408]);
+    /// ```
+    #[inline]
+    pub fn checked_from_ordinal(year: i32, ordinal: u32) -> Option<Self> {
+        Self::from_ordinal(year, ordinal).ok()
+    }
+
     /// Converts a Gregorian date (`chrono::NaiveDate`) to its equivalent Persian (Jalali) `ParsiDate`.
     ///
     /// This function implements the conversion algorithm from the Gregorian calendar to the
@@ -306,18 +469,15 @@ impl ParsiDate {
     /// let before_epoch = NaiveDate::from_ymd_opt(622, 3, 20).unwrap();
     /// assert_eq!(ParsiDate::from_gregorian(before_epoch), Err(DateError::GregorianConversionError));
     ///
-    /// // Error: Far future date likely resulting in year > 9999 (example only, actual limit depends on chrono)
-    /// let far_future_g = NaiveDate::MAX; // Chrono's max date
-    /// // The result depends on whether chrono's max date converts to a Persian year > 9999
-    /// match ParsiDate::from_gregorian(far_future_g) {
-    ///     Ok(pd) => println!("Conversion succeeded for NaiveDate::MAX: {}", pd), // Might succeed if within ParsiDate range
-    ///     Err(e) => assert!(matches!(e, DateError::GregorianConversionError)), // Likely error if exceeds 9999
-    /// }
+    /// // Error: Dates after 10621-03-19 (Persian 9999/12/29) are beyond the supported range
+    /// let last_g = NaiveDate::from_ymd_opt(10621, 3, 19).unwrap();
+    /// assert_eq!(ParsiDate::from_gregorian(last_g), Ok(ParsiDate::new(9999, 12, 29).unwrap()));
+    /// assert!(ParsiDate::from_gregorian(last_g.succ_opt().unwrap()).is_err());
+    /// assert_eq!(ParsiDate::from_gregorian(NaiveDate::MAX), Err(DateError::GregorianConversionError));
     /// ```
     pub fn from_gregorian(gregorian_date: NaiveDate) -> Result<Self, DateError> {
         // Define the Gregorian start date corresponding to the Persian epoch (1/1/1 Parsi).
-        let persian_epoch_gregorian_start =
-            NaiveDate::from_ymd_opt(622, 3, 21).ok_or(DateError::GregorianConversionError)?; // Handle potential chrono error
+        let persian_epoch_gregorian_start = persian_epoch_gregorian_start();
 
         // Ensure the input Gregorian date is not before the Persian epoch start.
         if gregorian_date < persian_epoch_gregorian_start {
@@ -419,31 +579,39 @@ impl ParsiDate {
         if days_into_year < 0 {
             return Err(DateError::GregorianConversionError); // Internal calculation error
         }
-        let mut remaining_days_in_year = days_into_year as u32; // 0-indexed day number
-
-        // Determine month and day by iterating through month lengths for the correct p_year.
-        let month_lengths = Self::month_lengths(p_year);
-        let mut p_month = 1u32;
-        let mut p_day = 1u32; // Placeholder, will be set in the loop
+        let days_into_year = days_into_year as u32; // 0-indexed day number
 
-        for (m_idx, length) in month_lengths.iter().enumerate() {
-            // Check if the day falls within this month (length is number of days).
-            // Since remaining_days_in_year is 0-indexed, we check if it's less than length.
-            if remaining_days_in_year < *length {
-                p_month = (m_idx + 1) as u32; // Month index is 0-based, month number is 1-based
-                p_day = remaining_days_in_year + 1; // Day number is 0-based index + 1
-                break; // Found the correct month and day
-            }
-            // Subtract the days of this full month and continue to the next.
-            remaining_days_in_year -= *length;
-        }
-        // The loop should always find a month/day if days_into_year was valid for the year length.
+        // Determine month and day from the month start offsets of the correct p_year.
+        let offsets = Self::month_start_offsets(p_year);
+        let p_month = Self::month_containing_offset(&offsets, days_into_year);
+        let p_day = days_into_year - offsets[(p_month - 1) as usize] + 1;
 
         // Use ParsiDate::new for final construction and validation (e.g., year range).
         // The calculated p_month/p_day should be logically valid based on the derivation.
         ParsiDate::new(p_year, p_month, p_day)
     }
 
+    /// The latest `ParsiDate` whose [`to_gregorian`](#method.to_gregorian) conversion succeeds.
+    ///
+    /// `chrono::NaiveDate` reaches far beyond the Gregorian equivalent of Persian year 9999, so
+    /// every supported date converts and this equals [`MAX_PARSI_DATE`](crate::MAX_PARSI_DATE)
+    /// (9999/12/29, which is Gregorian 10621-03-19). It is provided so callers can bound their
+    /// inputs without relying on that reasoning.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use parsidate::{ParsiDate, MAX_PARSI_DATE};
+    ///
+    /// assert_eq!(ParsiDate::MAX_GREGORIAN_CONVERTIBLE, MAX_PARSI_DATE);
+    /// assert_eq!(
+    ///     ParsiDate::MAX_GREGORIAN_CONVERTIBLE.to_gregorian(),
+    ///     Ok(NaiveDate::from_ymd_opt(10621, 3, 19).unwrap())
+    /// );
+    /// ```
+    pub const MAX_GREGORIAN_CONVERTIBLE: ParsiDate = MAX_PARSI_DATE;
+
     /// Converts this Persian (Jalali) `ParsiDate` to its equivalent Gregorian `chrono::NaiveDate`.
     ///
     /// This function first validates the `ParsiDate` instance itself using `\[`is_valid`\]`.
@@ -460,6 +628,10 @@ impl ParsiDate {
     /// supported range) or if the resulting Gregorian date falls outside the range supported by
     /// `chrono::NaiveDate`.
     ///
+    /// In practice, every valid date converts: the latest one,
+    /// [`MAX_GREGORIAN_CONVERTIBLE`](#associatedconstant.MAX_GREGORIAN_CONVERTIBLE) (9999/12/29),
+    /// maps to 10621-03-19, far below `NaiveDate::MAX`.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -510,8 +682,7 @@ impl ParsiDate {
     // Marked pub(crate) as it's an internal helper assuming validity.
     pub(crate) fn to_gregorian_internal(self) -> Result<NaiveDate, DateError> {
         // Define the Gregorian start date corresponding to the Persian epoch (1/1/1 Parsi).
-        let persian_epoch_gregorian_start =
-            NaiveDate::from_ymd_opt(622, 3, 21).ok_or(DateError::GregorianConversionError)?;
+        let persian_epoch_gregorian_start = persian_epoch_gregorian_start();
 
         // --- Calculate total days elapsed since 1/1/1 ---
         // Sum days in full years preceding self.year.
@@ -530,20 +701,12 @@ impl ParsiDate {
                 .ok_or(DateError::GregorianConversionError)?; // Map overflow to conversion error
         }
 
-        // Sum days in full months preceding self.month within self.year.
-        // Assumes self.month >= 1 (checked by caller via is_valid).
-        if self.month > 1 {
-            let month_lengths_current_year = Self::month_lengths(self.year);
-            // Loop from month 1 up to (but not including) self.month.
-            // Indexing `month_lengths_current_year` with (m - 1) is safe because m <= self.month <= 12.
-            for m in 1..self.month {
-                let days_in_month = month_lengths_current_year[(m - 1) as usize] as i64;
-                // Add days, checking for potential i64 overflow.
-                total_days_offset = total_days_offset
-                    .checked_add(days_in_month)
-                    .ok_or(DateError::GregorianConversionError)?;
-            }
-        }
+        // Add the days in full months preceding self.month within self.year.
+        // Assumes 1 <= self.month <= 12 (checked by caller via is_valid).
+        let month_offset = Self::month_start_offsets(self.year)[(self.month - 1) as usize];
+        total_days_offset = total_days_offset
+            .checked_add(i64::from(month_offset))
+            .ok_or(DateError::GregorianConversionError)?;
 
         // Add the day of the month (minus 1, as we need the 0-based offset from the start of the month).
         // Assumes self.day >= 1 (checked by caller via is_valid).
@@ -566,6 +729,96 @@ impl ParsiDate {
             .ok_or(DateError::GregorianConversionError) // Map chrono's None result (overflow/out of range) to our error type.
     }
 
+    /// Returns the Julian Day Number (JDN) of this date.
+    ///
+    /// The JDN counts days continuously from 1 January 4713 BCE (proleptic Julian calendar), so it
+    /// gives a stable integer key that other calendar and astronomy libraries understand. It is
+    /// computed directly from the 33-year leap cycle, without a Gregorian conversion. The
+    /// supported range maps to JDN 1948320 (1/1/1) through 5600378 (9999/12/29), and
+    /// [`from_jdn`](#method.from_jdn) is its exact inverse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the `ParsiDate` instance is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, MIN_PARSI_DATE};
+    ///
+    /// let date = ParsiDate::new(1403, 5, 2).unwrap(); // Gregorian 2024-07-23
+    /// assert_eq!(date.to_jdn(), Ok(2_460_515));
+    /// assert_eq!(MIN_PARSI_DATE.to_jdn(), Ok(1_948_320));
+    ///
+    /// // Consecutive days have consecutive numbers, across year boundaries too.
+    /// let nowruz = ParsiDate::new(1404, 1, 1).unwrap();
+    /// assert_eq!(nowruz.to_jdn().unwrap() - 1, ParsiDate::new(1403, 12, 30).unwrap().to_jdn().unwrap());
+    /// ```
+    pub fn to_jdn(&self) -> Result<i64, DateError> {
+        if !self.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        let day_of_year =
+            Self::month_start_offsets(self.year)[(self.month - 1) as usize] + self.day - 1;
+        Ok(PERSIAN_EPOCH_JDN + Self::days_before_year(self.year) + i64::from(day_of_year))
+    }
+
+    /// Creates a `ParsiDate` from a Julian Day Number, the inverse of [`to_jdn`](#method.to_jdn).
+    ///
+    /// # Arguments
+    ///
+    /// * `jdn`: The Julian Day Number, between 1948320 (1/1/1) and 5600378 (9999/12/29).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `jdn` lies outside the supported range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDate, MAX_PARSI_DATE};
+    ///
+    /// assert_eq!(ParsiDate::from_jdn(2_460_515), Ok(ParsiDate::new(1403, 5, 2).unwrap()));
+    /// assert_eq!(ParsiDate::from_jdn(5_600_378), Ok(MAX_PARSI_DATE));
+    /// assert_eq!(ParsiDate::from_jdn(5_600_379), Err(DateError::InvalidDate));
+    /// assert_eq!(ParsiDate::from_jdn(1_948_319), Err(DateError::InvalidDate));
+    /// ```
+    pub fn from_jdn(jdn: i64) -> Result<Self, DateError> {
+        let max_jdn = PERSIAN_EPOCH_JDN + Self::days_before_year(MAX_PARSI_DATE.year + 1) - 1;
+        if !(PERSIAN_EPOCH_JDN..=max_jdn).contains(&jdn) {
+            return Err(DateError::InvalidDate);
+        }
+        let days = jdn - PERSIAN_EPOCH_JDN;
+        // Skip whole 33-year cycles, then walk the (at most 33) remaining years.
+        let mut year = (days / DAYS_PER_33_YEAR_CYCLE * 33 + 1) as i32;
+        let mut day_of_year = days % DAYS_PER_33_YEAR_CYCLE;
+        loop {
+            let year_length = if Self::is_persian_leap_year(year) {
+                366
+            } else {
+                365
+            };
+            if day_of_year < year_length {
+                break;
+            }
+            day_of_year -= year_length;
+            year += 1;
+        }
+        Self::from_ordinal(year, day_of_year as u32 + 1)
+    }
+
+    /// **Internal**: Returns the number of days in the Persian years `1..year`, using the closed
+    /// form of the 33-year leap cycle. Assumes `year >= 1`.
+    fn days_before_year(year: i32) -> i64 {
+        let elapsed = i64::from(year) - 1;
+        let cycle_position = elapsed % 33;
+        let leaps_in_partial_cycle = [1, 5, 9, 13, 17, 22, 26, 30]
+            .iter()
+            .filter(|&&r| r <= cycle_position)
+            .count() as i64;
+        elapsed * 365 + elapsed / 33 * 8 + leaps_in_partial_cycle
+    }
+
     /// Returns the current system date, converted to `ParsiDate`.
     ///
     /// This function determines the current date based on the system's local timezone setting,
@@ -607,6 +860,130 @@ impl ParsiDate {
         Self::from_gregorian(gregorian_today)
     }
 
+    /// Checks whether this date is the current date according to the system clock.
+    ///
+    /// This is a convenience predicate for UI code (e.g., highlighting the current day in a
+    /// calendar). Any error while determining today's date is treated as "not today"; use
+    /// [`is_today_result`](#method.is_today_result) to observe such errors explicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let today = ParsiDate::today().unwrap();
+    /// assert!(today.is_today());
+    /// assert!(!ParsiDate::new(1300, 1, 1).unwrap().is_today());
+    /// ```
+    pub fn is_today(&self) -> bool {
+        self.is_today_result().unwrap_or(false)
+    }
+
+    /// Checks whether this date is the current date, propagating clock/conversion errors.
+    ///
+    /// Compares `self` against [`ParsiDate::today()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by [`ParsiDate::today()`] (e.g., `GregorianConversionError`
+    /// if the system clock is set outside the supported range).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let today = ParsiDate::today().unwrap();
+    /// assert_eq!(today.is_today_result(), Ok(true));
+    /// assert_eq!(today.add_days(1).unwrap().is_today_result(), Ok(false));
+    /// ```
+    pub fn is_today_result(&self) -> Result<bool, DateError> {
+        Ok(*self == Self::today()?)
+    }
+
+    /// Returns `true` if this date is strictly before `other`.
+    ///
+    /// A named form of `self < other`. Together with [`is_after`](#method.is_after),
+    /// [`is_same_or_before`](#method.is_same_or_before) and
+    /// [`is_same_or_after`](#method.is_same_or_after), it makes an inclusive or exclusive
+    /// boundary explicit at the call site.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let earlier = ParsiDate::new(1403, 5, 2).unwrap();
+    /// let later = ParsiDate::new(1403, 5, 3).unwrap();
+    /// assert!(earlier.is_before(&later));
+    /// assert!(!later.is_before(&earlier));
+    /// assert!(!earlier.is_before(&earlier));
+    /// ```
+    #[inline]
+    pub fn is_before(&self, other: &Self) -> bool {
+        self < other
+    }
+
+    /// Returns `true` if this date is strictly after `other`.
+    ///
+    /// A named form of `self > other`; see [`is_before`](#method.is_before).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let earlier = ParsiDate::new(1403, 5, 2).unwrap();
+    /// let later = ParsiDate::new(1403, 5, 3).unwrap();
+    /// assert!(later.is_after(&earlier));
+    /// assert!(!earlier.is_after(&later));
+    /// assert!(!later.is_after(&later));
+    /// ```
+    #[inline]
+    pub fn is_after(&self, other: &Self) -> bool {
+        self > other
+    }
+
+    /// Returns `true` if this date is the same as or before `other`.
+    ///
+    /// A named form of `self <= other`; see [`is_before`](#method.is_before).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let earlier = ParsiDate::new(1403, 5, 2).unwrap();
+    /// let later = ParsiDate::new(1403, 5, 3).unwrap();
+    /// assert!(earlier.is_same_or_before(&later));
+    /// assert!(earlier.is_same_or_before(&earlier));
+    /// assert!(!later.is_same_or_before(&earlier));
+    /// ```
+    #[inline]
+    pub fn is_same_or_before(&self, other: &Self) -> bool {
+        self <= other
+    }
+
+    /// Returns `true` if this date is the same as or after `other`.
+    ///
+    /// A named form of `self >= other`; see [`is_before`](#method.is_before).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let earlier = ParsiDate::new(1403, 5, 2).unwrap();
+    /// let later = ParsiDate::new(1403, 5, 3).unwrap();
+    /// assert!(later.is_same_or_after(&earlier));
+    /// assert!(later.is_same_or_after(&later));
+    /// assert!(!earlier.is_same_or_after(&later));
+    /// ```
+    #[inline]
+    pub fn is_same_or_after(&self, other: &Self) -> bool {
+        self >= other
+    }
+
     // --- Accessors ---
 
     /// Returns the year component of the Persian date.
@@ -668,6 +1045,26 @@ impl ParsiDate {
         self.day
     }
 
+    /// Returns the date components as a tuple `(year, month, day)`.
+    ///
+    /// Convenient for destructuring, logging, or passing the components to other APIs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let date = ParsiDate::new(1403, 5, 2).unwrap();
+    /// let (year, month, day) = date.as_tuple();
+    /// assert_eq!(year, 1403);
+    /// assert_eq!(month, 5);
+    /// assert_eq!(day, 2);
+    /// ```
+    #[inline]
+    pub const fn as_tuple(&self) -> (i32, u32, u32) {
+        (self.year, self.month, self.day)
+    }
+
     // --- Validation and Leap Year ---
 
     /// Checks if the current `ParsiDate` instance represents a valid date.
@@ -743,7 +1140,10 @@ impl ParsiDate {
     /// Years less than 1 are considered non-leap by this function.
     ///
     /// **Note:** While extremely accurate for historical and near-future dates, this is still an
-    /// approximation of the true astronomical rule.
+    /// approximation of the true astronomical rule. It agrees with the official (astronomical)
+    /// Iranian calendar for every year from 1178 to 1633, which covers the commonly published
+    /// official span 1206-1498, so no separate lookup table is needed there. The first
+    /// disagreements outside that window are 1176-1177 and 1634-1635.
     ///
     /// # Arguments
     ///
@@ -768,7 +1168,7 @@ impl ParsiDate {
     /// assert!(!ParsiDate::is_persian_leap_year(0));    // Year 0 is not considered leap
     /// assert!(!ParsiDate::is_persian_leap_year(-5));   // Negative years are not considered leap
     /// ```
-    pub fn is_persian_leap_year(year: i32) -> bool {
+    pub const fn is_persian_leap_year(year: i32) -> bool {
         // Persian years are positive; years <= 0 are treated as non-leap.
         if year <= 0 {
             return false;
@@ -813,6 +1213,28 @@ impl ParsiDate {
         (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
     }
 
+    /// Returns the Persian leap-year marker "کبیسه" if this date's year is a leap year, or an
+    /// empty string otherwise.
+    ///
+    /// Useful for calendar footnotes shown only in leap years. The same text is produced by the
+    /// `%?L` format specifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// assert_eq!(ParsiDate::new(1403, 5, 2).unwrap().leap_year_marker(), "کبیسه");
+    /// assert_eq!(ParsiDate::new(1404, 5, 2).unwrap().leap_year_marker(), "");
+    /// ```
+    pub fn leap_year_marker(&self) -> &'static str {
+        if Self::is_persian_leap_year(self.year) {
+            LEAP_YEAR_MARKER_PERSIAN
+        } else {
+            ""
+        }
+    }
+
     /// Returns the number of days in a specific month of a given Persian year.
     ///
     /// This function accounts for the standard lengths of Persian months and the leap year
@@ -853,7 +1275,7 @@ impl ParsiDate {
     /// assert_eq!(ParsiDate::days_in_month(1403, 0), 0);
     /// assert_eq!(ParsiDate::days_in_month(1403, 13), 0);
     /// ```
-    pub fn days_in_month(year: i32, month: u32) -> u32 {
+    pub const fn days_in_month(year: i32, month: u32) -> u32 {
         match month {
             1..=6 => 31,  // First 6 months have 31 days
             7..=11 => 30, // Next 5 months have 30 days
@@ -869,12 +1291,15 @@ impl ParsiDate {
         }
     }
 
-    /// **Internal**: Returns an array containing the lengths of the 12 months for a given Persian year.
+    /// Returns an array containing the lengths of the 12 months for a given Persian year.
     ///
-    /// This is primarily a helper function used internally by methods like `from_ordinal`
-    /// and `to_gregorian_internal` that need quick access to the length of each month.
+    /// This is the bulk counterpart of [`days_in_month`](#method.days_in_month): instead of
+    /// twelve separate calls, it returns the whole month structure of a year at once, which is
+    /// convenient for laying out month pickers or year overviews.
     /// The length of the 12th month (Esfand, index 11) depends on whether the `year` is leap.
     ///
+    /// Being a `const fn`, it can also be evaluated at compile time.
+    ///
     /// # Arguments
     ///
     /// * `year`: The Persian year for which to get month lengths.
@@ -884,8 +1309,20 @@ impl ParsiDate {
     /// An array `[u32; 12]` where `array[0]` is the length of Farvardin (month 1),
     /// `array[1]` is the length of Ordibehesht (month 2), ..., and `array[11]` is the
     /// length of Esfand (month 12).
-    // Marked pub(crate) as it's an implementation detail.
-    pub(crate) fn month_lengths(year: i32) -> [u32; 12] {
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// const LENGTHS_1403: [u32; 12] = ParsiDate::month_lengths_of(1403);
+    /// assert_eq!(LENGTHS_1403, [31, 31, 31, 31, 31, 31, 30, 30, 30, 30, 30, 30]); // 1403 is leap
+    ///
+    /// let lengths_1404 = ParsiDate::month_lengths_of(1404);
+    /// assert_eq!(lengths_1404[11], 29); // Esfand of a common year
+    /// assert_eq!(lengths_1404.iter().sum::<u32>(), 365);
+    /// ```
+    pub const fn month_lengths_of(year: i32) -> [u32; 12] {
         [
             31, // 1: Farvardin
             31, // 2: Ordibehesht
@@ -903,57 +1340,431 @@ impl ParsiDate {
         ]
     }
 
-    /// Calculates the week number of the year for this date.
-    ///
-    /// The week number is determined based on the following rules:
-    /// *   Weeks start on Saturday (Shanbeh).
-    /// *   Week 1 is the week containing the first day of the year (Farvardin 1st).
-    /// *   Weeks are numbered sequentially starting from 1.
-    ///
-    /// For example, if Farvardin 1st is a Wednesday, then Farvardin 1st, 2nd (Thursday),
-    /// and 3rd (Friday) are all in week 1. Farvardin 4th (Saturday) would be the start of week 2.
+    /// Returns the cumulative day offsets at which each month of a Persian year starts.
     ///
-    /// # Errors
+    /// Element `m - 1` is the number of days in the year before month `m` begins, so
+    /// `offsets[0]` is `0` (Farvardin) and `offsets[11]` is the offset of Esfand. The final
+    /// element, `offsets[12]`, is the length of the whole year (365 or 366). The ordinal of a
+    /// date is therefore `offsets[month - 1] + day`.
     ///
-    /// Returns `Err(DateError::InvalidDate)` if the `ParsiDate` instance holds invalid data.
-    /// Returns `Err(DateError::GregorianConversionError)` if the required calculation of the
-    /// first day's weekday fails (which involves Gregorian conversion).
-    /// Returns `Err(DateError::ArithmeticOverflow)` if calculating the ordinal day fails.
+    /// This is the prefix sum of [`month_lengths_of`](#method.month_lengths_of), and is what the
+    /// library uses internally for ordinal and Gregorian conversions.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// The week number (typically between 1 and 53) within the Persian year.
+    /// * `year`: The Persian year for which to get the offsets.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use parsidate::ParsiDate;
     ///
-    /// // Farvardin 1st, 1403 was a Wednesday (weekday 4)
-    /// let farvardin_1st = ParsiDate::new(1403, 1, 1).unwrap();
-    /// assert_eq!(farvardin_1st.week_of_year(), Ok(1));
+    /// let offsets = ParsiDate::month_start_offsets(1403);
+    /// assert_eq!(offsets, [0, 31, 62, 93, 124, 155, 186, 216, 246, 276, 306, 336, 366]);
+    /// assert_eq!(ParsiDate::month_start_offsets(1404)[12], 365); // Common year
     ///
-    /// // Farvardin 3rd, 1403 was a Friday (weekday 6) - Still week 1
-    /// let farvardin_3rd = ParsiDate::new(1403, 1, 3).unwrap();
-    /// assert_eq!(farvardin_3rd.week_of_year(), Ok(1));
+    /// let date = ParsiDate::new(1403, 5, 2).unwrap();
+    /// assert_eq!(offsets[date.month() as usize - 1] + date.day(), date.ordinal().unwrap());
+    /// ```
+    pub const fn month_start_offsets(year: i32) -> [u32; 13] {
+        let lengths = Self::month_lengths_of(year);
+        let mut offsets = [0u32; 13];
+        let mut i = 0;
+        while i < 12 {
+            offsets[i + 1] = offsets[i] + lengths[i];
+            i += 1;
+        }
+        offsets
+    }
+
+    /// Builds a [`YearCalendar`] snapshot describing the month structure of a Persian year.
     ///
-    /// // Farvardin 4th, 1403 was a Saturday (weekday 0) - Start of week 2
-    /// let farvardin_4th = ParsiDate::new(1403, 1, 4).unwrap();
-    /// assert_eq!(farvardin_4th.week_of_year(), Ok(2));
+    /// For each month the snapshot holds its length, the weekday of its first day (Saturday = 0)
+    /// and its season. Only Farvardin 1st is converted to Gregorian; the remaining weekdays are
+    /// derived arithmetically from the month lengths.
     ///
-    /// // A date later in the year
-    /// let mordad_2nd = ParsiDate::new(1403, 5, 2).unwrap(); // Ordinal 126
-    /// // Calculation: first day weekday=4. effective_ordinal = 126 + 4 = 130.
-    /// // week = 130 / 7 = 19
-    /// assert_eq!(mordad_2nd.week_of_year(), Ok(19));
+    /// # Arguments
     ///
-    /// // Last day of leap year 1403 (Esfand 30th, Thursday, weekday 5, ordinal 366)
-    /// let end_of_1403 = ParsiDate::new(1403, 12, 30).unwrap();
-    /// // Calculation: first day weekday=4. effective_ordinal = 366 + 4 = 370.
-    /// // week = 370 / 7 = 53
-    /// assert_eq!(end_of_1403.week_of_year(), Ok(53));
+    /// * `year`: The Persian year (1-9999).
     ///
-    /// // Last day of common year 1404 (Esfand 29th, Friday, weekday 6, ordinal 365)
+    /// # Errors
+    ///
+    /// *   `DateError::InvalidDate` if `year` is outside the supported range.
+    /// *   `DateError::GregorianConversionError` if the weekday of Farvardin 1st cannot be computed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, Season};
+    ///
+    /// let calendar = ParsiDate::year_calendar(1403).unwrap();
+    /// assert_eq!(calendar.year, 1403);
+    /// assert_eq!(calendar.months[0].first_weekday, 4); // Farvardin 1st, 1403 was a Wednesday
+    /// assert_eq!(calendar.months[11].length, 30); // Leap Esfand
+    /// assert_eq!(calendar.months[9].season, Season::Zemestan);
+    ///
+    /// assert!(ParsiDate::year_calendar(0).is_err());
+    /// ```
+    pub fn year_calendar(year: i32) -> Result<YearCalendar, DateError> {
+        let first_day = ParsiDate::new(year, 1, 1)?;
+        let mut weekday = first_day.weekday_num_sat_0()?;
+        let lengths = Self::month_lengths(year);
+
+        let mut months = [MonthInfo {
+            month: 1,
+            length: lengths[0],
+            first_weekday: weekday,
+            season: Season::Bahar,
+        }; 12];
+        for (idx, info) in months.iter_mut().enumerate() {
+            let month = idx as u32 + 1;
+            *info = MonthInfo {
+                month,
+                length: lengths[idx],
+                first_weekday: weekday,
+                // Months 1-3 -> Bahar, 4-6 -> Tabestan, 7-9 -> Paeez, 10-12 -> Zemestan.
+                season: ParsiDate {
+                    year,
+                    month,
+                    day: 1,
+                }
+                .season()?,
+            };
+            // The next month starts `length` days later.
+            weekday = (weekday + lengths[idx]) % 7;
+        }
+
+        Ok(YearCalendar {
+            year,
+            is_leap: Self::is_persian_leap_year(year),
+            months,
+        })
+    }
+
+    /// Returns every date in the given Persian year that falls on `weekday`, in chronological order.
+    ///
+    /// Useful for annual planning (e.g., "all Fridays in 1403"). Only Farvardin 1st is converted
+    /// to Gregorian to find its weekday; the remaining dates are found by stepping 7 days at a time.
+    /// A year contains 52 occurrences of each weekday, plus a 53rd for the weekday of Farvardin 1st
+    /// (and, in leap years, also for the following weekday).
+    ///
+    /// # Arguments
+    ///
+    /// * `year`: The Persian year (1-9999).
+    /// * `weekday`: The [`ParsiWeekday`] to collect.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `year` is outside the supported range, or
+    /// `Err(DateError::GregorianConversionError)` if determining the weekday of Farvardin 1st fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, ParsiWeekday};
+    ///
+    /// // 1403 is a leap year starting on a Wednesday, so it has 53 Wednesdays and Thursdays.
+    /// let fridays = ParsiDate::weekdays_in_year(1403, ParsiWeekday::Jomeh).unwrap();
+    /// assert_eq!(fridays.len(), 52);
+    /// assert_eq!(fridays[0], ParsiDate::new(1403, 1, 3).unwrap());
+    /// assert_eq!(ParsiDate::weekdays_in_year(1403, ParsiWeekday::Chaharshanbeh).unwrap().len(), 53);
+    /// assert_eq!(ParsiDate::weekdays_in_year(1403, ParsiWeekday::Panjshanbeh).unwrap().len(), 53);
+    /// ```
+    pub fn weekdays_in_year(year: i32, weekday: ParsiWeekday) -> Result<Vec<ParsiDate>, DateError> {
+        let first_day = ParsiDate::new(year, 1, 1)?;
+        let first_weekday = first_day.weekday_num_sat_0()?;
+        let lengths = Self::month_lengths(year);
+
+        // Day of Farvardin on which the first occurrence falls (1-7).
+        let mut month = 1u32;
+        let mut day = 1 + (weekday.num_sat_0() + 7 - first_weekday) % 7;
+        let mut dates = Vec::with_capacity(53);
+        loop {
+            // Carry the day over into the following month(s) when it exceeds the month length.
+            while day > lengths[(month - 1) as usize] {
+                day -= lengths[(month - 1) as usize];
+                month += 1;
+                if month > 12 {
+                    return Ok(dates);
+                }
+            }
+            dates.push(ParsiDate { year, month, day });
+            day += 7;
+        }
+    }
+
+    /// Counts the occurrences of `weekday` from this date (inclusive) through the end of its month.
+    ///
+    /// Useful for projections over partial months, such as "working Saturdays left this month".
+    /// Only the weekday of `self` is computed via a Gregorian conversion; the count itself is
+    /// derived arithmetically.
+    ///
+    /// # Arguments
+    ///
+    /// * `weekday`: The [`ParsiWeekday`] to count.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self` is invalid, or
+    /// `Err(DateError::GregorianConversionError)` if determining its weekday fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, ParsiWeekday};
+    ///
+    /// // Mordad 1403 has 31 days; Mordad 20th is a Saturday.
+    /// let date = ParsiDate::new(1403, 5, 20).unwrap();
+    /// assert_eq!(date.count_weekday_in_month_from(ParsiWeekday::Shanbeh), Ok(2)); // 20th, 27th
+    /// assert_eq!(date.count_weekday_in_month_from(ParsiWeekday::Jomeh), Ok(1)); // 26th
+    /// assert_eq!(date.count_weekday_in_month_from(ParsiWeekday::Yekshanbeh), Ok(2)); // 21st, 28th
+    /// ```
+    pub fn count_weekday_in_month_from(&self, weekday: ParsiWeekday) -> Result<u32, DateError> {
+        let current_weekday = self.weekday_num_sat_0()?; // Validates self
+        let remaining_days = Self::days_in_month(self.year, self.month) - self.day + 1;
+        // Days from `self` to the first occurrence of `weekday` (0-6).
+        let offset = (weekday.num_sat_0() + 7 - current_weekday) % 7;
+        if offset >= remaining_days {
+            Ok(0)
+        } else {
+            Ok((remaining_days - offset - 1) / 7 + 1)
+        }
+    }
+
+    /// Counts the occurrences of `weekday` in the given month (4 or 5).
+    ///
+    /// # Arguments
+    ///
+    /// * `year`: The Persian year.
+    /// * `month`: The Persian month (1-12).
+    /// * `weekday`: The [`ParsiWeekday`] to count.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `year` or `month` is out of range, or
+    /// `Err(DateError::GregorianConversionError)` if determining the weekday fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, ParsiWeekday};
+    ///
+    /// // Esfand 1403 (leap, 30 days) starts on a Wednesday.
+    /// assert_eq!(ParsiDate::count_weekday_in_month(1403, 12, ParsiWeekday::Chaharshanbeh), Ok(5));
+    /// assert_eq!(ParsiDate::count_weekday_in_month(1403, 12, ParsiWeekday::Panjshanbeh), Ok(5));
+    /// assert_eq!(ParsiDate::count_weekday_in_month(1403, 12, ParsiWeekday::Jomeh), Ok(4));
+    /// ```
+    pub fn count_weekday_in_month(
+        year: i32,
+        month: u32,
+        weekday: ParsiWeekday,
+    ) -> Result<u32, DateError> {
+        ParsiDate::new(year, month, 1)?.count_weekday_in_month_from(weekday)
+    }
+
+    /// Returns the weekday number (Saturday=0, ..., Friday=6, as for `%w`) of every day of a month.
+    ///
+    /// Element `i` of the result belongs to day `i + 1`. Only the 1st of the month is converted
+    /// to the Gregorian calendar; the remaining values are derived by advancing modulo 7. This is
+    /// much cheaper than computing the weekday of each day separately, e.g., when rendering a
+    /// month grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `year`: The Persian year.
+    /// * `month`: The Persian month (1-12).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `year` or `month` is out of range, or
+    /// `Err(DateError::GregorianConversionError)` if determining the weekday of the 1st fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// // Esfand 1403 (leap, 30 days) starts on a Wednesday (4).
+    /// let weekdays = ParsiDate::weekday_numbers_for_month(1403, 12).unwrap();
+    /// assert_eq!(weekdays.len(), 30);
+    /// assert_eq!(&weekdays[..8], &[4, 5, 6, 0, 1, 2, 3, 4]);
+    /// assert_eq!(weekdays[29].to_string(), ParsiDate::new(1403, 12, 30).unwrap().format("%w"));
+    /// ```
+    pub fn weekday_numbers_for_month(year: i32, month: u32) -> Result<Vec<u8>, DateError> {
+        let first_weekday = ParsiDate::new(year, month, 1)?.weekday_num_sat_0()?;
+        let length = Self::days_in_month(year, month);
+        Ok((0..length)
+            .map(|offset| ((first_weekday + offset) % 7) as u8)
+            .collect())
+    }
+
+    /// Returns an iterator over the days from `start` to `end` (inclusive), each annotated with
+    /// its weekday and weekend/holiday markers.
+    ///
+    /// This is intended for rendering calendar grids. Only `start` is converted to the Gregorian
+    /// calendar (to determine its weekday); each following day is produced by stepping through the
+    /// Persian calendar and advancing the weekday modulo 7, so long ranges stay cheap.
+    ///
+    /// Holiday information covers the official public holidays of Iran that fall on fixed solar
+    /// calendar dates (Nowruz, 12 and 13 Farvardin, 14 and 15 Khordad, 22 Bahman, 29 Esfand).
+    /// Holidays of the lunar Hijri calendar are not included, so `is_holiday` is `false` and
+    /// `holiday_name` is `None` on those days.
+    ///
+    /// # Arguments
+    ///
+    /// * `start`: The first day of the range.
+    /// * `end`: The last day of the range. If `end` is before `start`, the iterator is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `start` or `end` is invalid, or
+    /// `Err(DateError::GregorianConversionError)` if determining the weekday of `start` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, ParsiWeekday};
+    ///
+    /// let start = ParsiDate::new(1403, 12, 29).unwrap(); // A Wednesday
+    /// let end = ParsiDate::new(1404, 1, 2).unwrap();
+    /// let days: Vec<_> = ParsiDate::annotated_range(start, end).unwrap().collect();
+    ///
+    /// assert_eq!(days.len(), 4);
+    /// assert_eq!(days[1].date, ParsiDate::new(1403, 12, 30).unwrap());
+    /// assert_eq!(days[1].weekday, ParsiWeekday::Panjshanbeh);
+    /// assert!(!days[1].is_holiday);
+    ///
+    /// // 1 Farvardin 1404 is both a Friday and Nowruz.
+    /// assert!(days[2].is_friday);
+    /// assert!(days[2].is_holiday);
+    /// assert_eq!(days[2].holiday_name, Some("عید نوروز"));
+    /// assert_eq!(days[3].weekday, ParsiWeekday::Shanbeh);
+    /// ```
+    pub fn annotated_range(start: ParsiDate, end: ParsiDate) -> Result<AnnotatedRange, DateError> {
+        if !end.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        let start_weekday = start.weekday_num_sat_0()?;
+        Ok(AnnotatedRange::new(start, end, start_weekday))
+    }
+
+    /// Returns an iterator over the days from `self` up to, but not including, `end`.
+    ///
+    /// The number of days is computed once, here, with
+    /// [`signed_days_between`](#method.signed_days_between); each following day is then produced
+    /// by stepping through the Persian calendar, so no date is converted to or from the Gregorian
+    /// calendar per step. The iterator reports its exact length through `size_hint` and
+    /// [`ExactSizeIterator::len`].
+    ///
+    /// # Arguments
+    ///
+    /// * `end`: The first day **not** yielded.
+    ///
+    /// # Returns
+    ///
+    /// A [`ParsiDateIter`]. It is empty if `end <= self`, or if either date is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let start = ParsiDate::new(1403, 12, 29).unwrap();
+    /// let end = ParsiDate::new(1404, 1, 2).unwrap();
+    /// let days: Vec<_> = start.iter_until(end).collect();
+    /// assert_eq!(
+    ///     days,
+    ///     [
+    ///         ParsiDate::new(1403, 12, 29).unwrap(),
+    ///         ParsiDate::new(1403, 12, 30).unwrap(), // 1403 is a leap year
+    ///         ParsiDate::new(1404, 1, 1).unwrap(),
+    ///     ]
+    /// );
+    /// assert_eq!(start.iter_until(end).len(), 3);
+    ///
+    /// // `end` itself is excluded, so an equal or earlier end gives no days.
+    /// assert_eq!(start.iter_until(start).count(), 0);
+    /// assert_eq!(end.iter_until(start).count(), 0);
+    /// ```
+    pub fn iter_until(&self, end: ParsiDate) -> ParsiDateIter {
+        let remaining = end.signed_days_between(self).unwrap_or(0).max(0) as usize;
+        ParsiDateIter::new(*self, remaining)
+    }
+
+    /// **Internal**: Returns an array containing the lengths of the 12 months for a given Persian year.
+    ///
+    /// This is primarily a helper function used internally by methods like `from_ordinal`
+    /// and `to_gregorian_internal` that need quick access to the length of each month.
+    /// It is equivalent to the public [`month_lengths_of`](#method.month_lengths_of).
+    // Marked pub(crate) as it's an implementation detail.
+    pub(crate) fn month_lengths(year: i32) -> [u32; 12] {
+        Self::month_lengths_of(year)
+    }
+
+    /// **Internal**: Returns the month (1-12) containing the 0-based day `day_index` of a year,
+    /// given that year's [`month_start_offsets`](#method.month_start_offsets).
+    ///
+    /// Indices past the end of the year resolve to month 12.
+    fn month_containing_offset(offsets: &[u32; 13], day_index: u32) -> u32 {
+        // Number of months (after Farvardin) that start at or before `day_index`.
+        offsets[1..12]
+            .iter()
+            .take_while(|&&start| start <= day_index)
+            .count() as u32
+            + 1
+    }
+
+    /// Calculates the week number of the year for this date.
+    ///
+    /// The week number is determined based on the following rules:
+    /// *   Weeks start on Saturday (Shanbeh).
+    /// *   Week 1 is the week containing the first day of the year (Farvardin 1st).
+    /// *   Weeks are numbered sequentially starting from 1.
+    ///
+    /// For example, if Farvardin 1st is a Wednesday, then Farvardin 1st, 2nd (Thursday),
+    /// and 3rd (Friday) are all in week 1. Farvardin 4th (Saturday) would be the start of week 2.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the `ParsiDate` instance holds invalid data.
+    /// Returns `Err(DateError::GregorianConversionError)` if the required calculation of the
+    /// first day's weekday fails (which involves Gregorian conversion).
+    /// Returns `Err(DateError::ArithmeticOverflow)` if calculating the ordinal day fails.
+    ///
+    /// # Returns
+    ///
+    /// The week number (typically between 1 and 53) within the Persian year.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// // Farvardin 1st, 1403 was a Wednesday (weekday 4)
+    /// let farvardin_1st = ParsiDate::new(1403, 1, 1).unwrap();
+    /// assert_eq!(farvardin_1st.week_of_year(), Ok(1));
+    ///
+    /// // Farvardin 3rd, 1403 was a Friday (weekday 6) - Still week 1
+    /// let farvardin_3rd = ParsiDate::new(1403, 1, 3).unwrap();
+    /// assert_eq!(farvardin_3rd.week_of_year(), Ok(1));
+    ///
+    /// // Farvardin 4th, 1403 was a Saturday (weekday 0) - Start of week 2
+    /// let farvardin_4th = ParsiDate::new(1403, 1, 4).unwrap();
+    /// assert_eq!(farvardin_4th.week_of_year(), Ok(2));
+    ///
+    /// // A date later in the year
+    /// let mordad_2nd = ParsiDate::new(1403, 5, 2).unwrap(); // Ordinal 126
+    /// // Calculation: first day weekday=4. effective_ordinal = 126 + 4 = 130.
+    /// // week = 130 / 7 = 19
+    /// assert_eq!(mordad_2nd.week_of_year(), Ok(19));
+    ///
+    /// // Last day of leap year 1403 (Esfand 30th, Thursday, weekday 5, ordinal 366)
+    /// let end_of_1403 = ParsiDate::new(1403, 12, 30).unwrap();
+    /// // Calculation: first day weekday=4. effective_ordinal = 366 + 4 = 370.
+    /// // week = 370 / 7 = 53
+    /// assert_eq!(end_of_1403.week_of_year(), Ok(53));
+    ///
+    /// // Last day of common year 1404 (Esfand 29th, Friday, weekday 6, ordinal 365)
     /// // Farvardin 1st, 1404 was a Friday (weekday 6)
     /// let end_of_1404 = ParsiDate::new(1404, 12, 29).unwrap();
     /// // Calculation: first day weekday=6. effective_ordinal = 365 + 6 = 371.
@@ -961,36 +1772,257 @@ impl ParsiDate {
     /// assert_eq!(end_of_1404.week_of_year(), Ok(53));
     /// ```
     pub fn week_of_year(&self) -> Result<u32, DateError> {
-        // 1. Validate the input date first.
+        // Saturday-based weeks, where the partial week containing Farvardin 1st is week 1.
+        self.week_of_year_with(ParsiWeekday::Shanbeh, 1)
+    }
+
+    /// Calculates the week number of the year, assigning week 0 to a short leading partial week.
+    ///
+    /// Like [`week_of_year`](#method.week_of_year), weeks start on Saturday. However, the partial
+    /// week containing Farvardin 1st only counts as week 1 if at least `min_days` of its days fall
+    /// in the new year; otherwise its days are in **week 0** and numbering starts at the first
+    /// following Saturday. This mirrors the `%U`/`%W` conventions of other systems:
+    ///
+    /// *   `min_days = 1` never produces week 0 and matches `week_of_year`.
+    /// *   `min_days = 4` resembles ISO 8601's "majority of the week" rule.
+    /// *   `min_days = 7` requires a full first week (US-style `%U` numbering).
+    ///
+    /// Values of `0` behave like `1`, and values above `7` behave like `7`.
+    ///
+    /// To attribute week-0 days to the last week of the previous year instead, use
+    /// [`week_of_year_us_with_roll`](#method.week_of_year_us_with_roll).
+    ///
+    /// # Arguments
+    ///
+    /// * `min_days`: The minimum number of days (1-7) the first week must have in the new year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self` is invalid, or
+    /// `Err(DateError::GregorianConversionError)` if determining the weekday of Farvardin 1st fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// // Farvardin 1st, 1403 was a Wednesday: the first week has only 3 days (Wed-Fri).
+    /// let farvardin_1st = ParsiDate::new(1403, 1, 1).unwrap();
+    /// assert_eq!(farvardin_1st.week_of_year_us(1), Ok(1)); // Same as week_of_year()
+    /// assert_eq!(farvardin_1st.week_of_year_us(4), Ok(0)); // Too short: week 0
+    ///
+    /// let farvardin_4th = ParsiDate::new(1403, 1, 4).unwrap(); // First Saturday
+    /// assert_eq!(farvardin_4th.week_of_year_us(4), Ok(1));
+    /// ```
+    pub fn week_of_year_us(&self, min_days: u32) -> Result<u32, DateError> {
+        self.week_of_year_us_with_roll(min_days, false)
+    }
+
+    /// Calculates the week number like [`week_of_year_us`](#method.week_of_year_us), optionally
+    /// rolling the days of week 0 into the previous year's last week.
+    ///
+    /// With `roll_into_previous_year` set to `false` this is exactly `week_of_year_us`. With it
+    /// set to `true`, a date that would be in week 0 instead gets the week number of Esfand's
+    /// last day in the previous year (52, 53 or 54), since both fall in the same Saturday-based
+    /// week. The returned number then belongs to the previous year; all other dates are
+    /// unaffected. Year 1 has no previous year, so its week-0 days stay in week 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_days`: The minimum number of days (1-7) the first week must have in the new year.
+    /// * `roll_into_previous_year`: Whether week-0 days are reported as the previous year's
+    ///   last week.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`week_of_year_us`](#method.week_of_year_us).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// // Farvardin 1st, 1403 was a Wednesday: with a minimum of 4 days it is in week 0.
+    /// let farvardin_1st = ParsiDate::new(1403, 1, 1).unwrap();
+    /// assert_eq!(farvardin_1st.week_of_year_us_with_roll(4, false), Ok(0));
+    /// // Rolled, it shares the last week of 1402 with Esfand 29th, 1402.
+    /// assert_eq!(farvardin_1st.week_of_year_us_with_roll(4, true), Ok(53));
+    /// assert_eq!(ParsiDate::new(1402, 12, 29).unwrap().week_of_year_us(4), Ok(53));
+    ///
+    /// // Dates from week 1 on are unaffected.
+    /// let farvardin_4th = ParsiDate::new(1403, 1, 4).unwrap();
+    /// assert_eq!(farvardin_4th.week_of_year_us_with_roll(4, true), Ok(1));
+    /// ```
+    pub fn week_of_year_us_with_roll(
+        &self,
+        min_days: u32,
+        roll_into_previous_year: bool,
+    ) -> Result<u32, DateError> {
+        let week = self.week_of_year_with(ParsiWeekday::Shanbeh, min_days)?;
+        if week != 0 || !roll_into_previous_year || self.year <= MIN_PARSI_DATE.year {
+            return Ok(week);
+        }
+        // Week 0 is the tail of the week containing the previous year's last day.
+        let previous_year = self.year - 1;
+        let last_day = Self::new_validated_components(
+            previous_year,
+            12,
+            Self::days_in_month(previous_year, 12),
+        )?;
+        last_day.week_of_year_with(ParsiWeekday::Shanbeh, min_days)
+    }
+
+    /// Calculates the week number of the year with a configurable first day of the week and
+    /// first-week rule.
+    ///
+    /// Weeks start on `first_weekday`. The partial week containing Farvardin 1st is week 1 if at
+    /// least `min_days_in_first_week` of its days fall in the new year; otherwise its days are in
+    /// **week 0** and week 1 starts on the first following `first_weekday`. Numbering restarts
+    /// every Farvardin 1st, so the last days of Esfand are always in the last week of their own
+    /// year (52, 53 or 54), never in week 1 of the next year.
+    ///
+    /// [`week_of_year`](#method.week_of_year) is `week_of_year_with(ParsiWeekday::Shanbeh, 1)`
+    /// and [`week_of_year_us`](#method.week_of_year_us) is
+    /// `week_of_year_with(ParsiWeekday::Shanbeh, min_days)`. With a `min_days_in_first_week` of
+    /// 4, week 1 is the first week with a majority of its days in the new year, as in ISO 8601;
+    /// unlike [`iso_week`](#method.iso_week), the week-0 days stay in the current year. To
+    /// attribute them to the previous year's last week instead, compute the week of Esfand's last
+    /// day (see the example below), or use
+    /// [`week_of_year_us_with_roll`](#method.week_of_year_us_with_roll) for Saturday-based weeks.
+    ///
+    /// Values of `min_days_in_first_week` of `0` behave like `1`, and values above `7` behave
+    /// like `7`.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_weekday`: The day each week starts on.
+    /// * `min_days_in_first_week`: The minimum number of days (1-7) the first week must have in
+    ///   the new year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self` is invalid, or
+    /// `Err(DateError::GregorianConversionError)` if determining the weekday of Farvardin 1st fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, ParsiWeekday};
+    ///
+    /// // Farvardin 1st, 1403 was a Wednesday (چهارشنبه).
+    /// let farvardin_1st = ParsiDate::new(1403, 1, 1).unwrap();
+    /// assert_eq!(farvardin_1st.week_of_year_with(ParsiWeekday::Shanbeh, 1), Ok(1));
+    /// // Monday-based weeks: Wed-Sun is a five-day first week, enough for a minimum of 4...
+    /// assert_eq!(farvardin_1st.week_of_year_with(ParsiWeekday::Doshanbeh, 4), Ok(1));
+    /// // ...but not for a full first week.
+    /// assert_eq!(farvardin_1st.week_of_year_with(ParsiWeekday::Doshanbeh, 7), Ok(0));
+    ///
+    /// // The first Monday starts week 2, or week 1 when a full first week is required.
+    /// let farvardin_6th = ParsiDate::new(1403, 1, 6).unwrap();
+    /// assert_eq!(farvardin_6th.week_of_year_with(ParsiWeekday::Doshanbeh, 4), Ok(2));
+    /// assert_eq!(farvardin_6th.week_of_year_with(ParsiWeekday::Doshanbeh, 7), Ok(1));
+    ///
+    /// // Rolling week 0 into the previous year's last week:
+    /// let week = match farvardin_1st.week_of_year_with(ParsiWeekday::Doshanbeh, 7).unwrap() {
+    ///     0 => farvardin_1st
+    ///         .sub_days(1)
+    ///         .unwrap()
+    ///         .week_of_year_with(ParsiWeekday::Doshanbeh, 7)
+    ///         .unwrap(),
+    ///     week => week,
+    /// };
+    /// assert_eq!(week, 52); // Last week of 1402
+    /// ```
+    pub fn week_of_year_with(
+        &self,
+        first_weekday: ParsiWeekday,
+        min_days_in_first_week: u32,
+    ) -> Result<u32, DateError> {
         if !self.is_valid() {
             return Err(DateError::InvalidDate);
         }
+        // Position of Farvardin 1st within its week (0 when the year starts on `first_weekday`).
+        let offset =
+            (self.first_day_of_year().weekday_num_sat_0()? + 7 - first_weekday.num_sat_0()) % 7;
+        let current_ordinal = self.ordinal_internal()?;
 
-        // 2. Get the date of the first day of this year.
-        let first_day = self.first_day_of_year(); // This is inherently valid if self is valid.
-
-        // 3. Find the weekday number of the first day (Saturday = 0).
-        //    This involves Gregorian conversion, so it can return an error.
-        let first_day_weekday = first_day.weekday_num_sat_0()?; // Result<u32, DateError>
+        // Numbering where the partial first week is week 1.
+        let week_number = (current_ordinal - 1 + offset) / 7 + 1;
 
-        // 4. Get the ordinal day number of the current date (1-based).
-        //    This also involves validation and potential errors.
-        let current_ordinal = self.ordinal_internal()?; // Result<u32, DateError>
-
-        // 5. Calculate the "effective" ordinal day relative to the start of the week containing Farvardin 1st.
-        //    Add the weekday number of the first day to the current ordinal day.
-        //    Example: If Far 1st is Wednesday (4), and date is Far 1st (ord 1), effective = 1 + 4 = 5.
-        //             If Far 1st is Wednesday (4), and date is Far 4th (ord 4, Saturday), effective = 4 + 4 = 8.
-        let effective_ordinal = current_ordinal
-            .checked_add(first_day_weekday)
-            .ok_or(DateError::ArithmeticOverflow)?; // Check for potential overflow (highly unlikely)
+        // Days of the first week that fall in this year: 7 when the year starts on `first_weekday`.
+        let days_in_first_week = 7 - offset;
+        if days_in_first_week >= min_days_in_first_week.clamp(1, 7) {
+            Ok(week_number)
+        } else {
+            Ok(week_number - 1)
+        }
+    }
 
-        // 6. Calculate the week number (1-based).
-        //    Divide the (0-based effective day) by 7 and add 1.
-        //    (effective_ordinal - 1) gives the 0-based day count from the start of week 1.
-        let week_number = (effective_ordinal - 1) / 7 + 1;
+    /// Calculates the week-based year and week number, following the ISO 8601 rules adapted to
+    /// the Persian week (Saturday to Friday).
+    ///
+    /// Week 1 of a week-based year is the first week with at least four days in that year, i.e.
+    /// the week containing the year's first Tuesday (سه‌شنبه). Days before it belong to the last
+    /// week (52 or 53) of the previous week-based year, and the last days of a year may belong to
+    /// week 1 of the next one. Use this for `%G`/`%V`-style week labels such as `"1403-W19"`.
+    ///
+    /// # Lower and upper bounds
+    ///
+    /// Farvardin 1st of year 1 falls on a Thursday, so 1/1/1 and 1/1/2 would belong to the last
+    /// week of year 0, which does not exist. They are instead reported as **week 1 of week-based
+    /// year 1**, which therefore spans nine days (1/1/1 to 1/1/9). At the other end, the last days
+    /// of year 9999 may be reported as week 1 of week-based year 10000.
+    ///
+    /// # Returns
+    ///
+    /// `Ok((week_year, week))`, where `week` is between 1 and 53.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self` is invalid, or
+    /// `Err(DateError::GregorianConversionError)` if determining the weekday fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// // 1403 starts on a Wednesday: its first three days belong to 1402's last week.
+    /// assert_eq!(ParsiDate::new(1403, 1, 1).unwrap().iso_week(), Ok((1402, 53)));
+    /// assert_eq!(ParsiDate::new(1403, 1, 4).unwrap().iso_week(), Ok((1403, 1))); // Saturday
+    /// assert_eq!(ParsiDate::new(1403, 5, 2).unwrap().iso_week(), Ok((1403, 18)));
+    ///
+    /// // Year 1 has no preceding year.
+    /// assert_eq!(ParsiDate::new(1, 1, 1).unwrap().iso_week(), Ok((1, 1)));
+    /// assert_eq!(ParsiDate::new(1, 1, 10).unwrap().iso_week(), Ok((1, 2)));
+    /// ```
+    pub fn iso_week(&self) -> Result<(i32, u32), DateError> {
+        if !self.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        let weekday = i64::from(self.weekday_num_sat_0()?);
+        let ordinal = i64::from(self.ordinal_internal()?);
 
-        Ok(week_number)
+        // The week's Tuesday (weekday 3) decides which year the week belongs to.
+        let week = (ordinal - weekday + 9) / 7;
+        if week == 0 {
+            if self.year == 1 {
+                // No year 0 to attribute the leading partial week to.
+                return Ok((1, 1));
+            }
+            // Last week of the previous year, from the weekday of its last day.
+            let prev_last_weekday = (weekday - ordinal).rem_euclid(7);
+            let prev_days = i64::from(Self::days_in_year(self.year - 1));
+            return Ok((
+                self.year - 1,
+                ((prev_days - prev_last_weekday + 9) / 7) as u32,
+            ));
+        }
+        if ordinal + 3 - weekday > i64::from(Self::days_in_year(self.year)) {
+            // This week's Tuesday falls in the next year.
+            return Ok((self.year + 1, 1));
+        }
+        Ok((self.year, week as u32))
     }
 
     // --- Formatting ---
@@ -1004,8 +2036,16 @@ impl ParsiDate {
     /// * `style_or_pattern`: A string slice (`&str`) specifying the desired format. It can be:
     ///     *   `"short"`: Formats as "YYYY/MM/DD" (e.g., "1403/05/02"). This is the default style used by the `Display` trait implementation (`.to_string()`).
     ///     *   `"long"`: Formats as "D MonthName YYYY" using the full Persian month name (e.g., "2 مرداد 1403"). Note: The day `D` is *not* zero-padded in this style.
+    ///     *   `"medium"`: Formats as "D MonthName YYYY" (e.g., "2 مرداد 1403"). Since the crate has no abbreviated month names, this currently matches `"long"`.
+    ///     *   `"full"`: Formats as "WeekdayName، D MonthName YYYY" (e.g., "سه‌شنبه، 2 مرداد 1403"), with the Persian comma after the weekday.
     ///     *   `"iso"`: Formats according to ISO 8601 style for dates: "YYYY-MM-DD" (e.g., "1403-05-02").
-    ///     *   **Custom Pattern**: If the string does not match "short", "long", or "iso", it is treated as a custom format pattern string to be processed by [`format_strftime`](#method.format_strftime). See that method's documentation for supported specifiers like `%Y`, `%m`, `%d`, `%B`, `%A`, `%w`, `%j`, `%K` `%W` etc.
+    ///     *   `"numeric-dotted"`: Formats as "YYYY.MM.DD" (e.g., "1403.05.02").
+    ///     *   `"compact"`: Formats as "YYYYMMDD" with the year zero-padded to 4 digits (e.g., "14030502"), suitable for sortable identifiers and file names.
+    ///     *   `"ordinal"`: Formats as "YYYY-DDD", the ISO 8601 ordinal-date layout applied to the Persian year, with the day of the year zero-padded to 3 digits (e.g., "1403-126"). Equivalent to the pattern `"%Y-%j"`.
+    ///     *   **Custom Pattern**: If the string does not match one of the styles above, it is treated as a custom format pattern string to be processed by [`format_strftime`](#method.format_strftime). See that method's documentation for supported specifiers like `%Y`, `%m`, `%d`, `%B`, `%A`, `%w`, `%j`, `%K` `%W` etc.
+    ///
+    /// The `"short"`, `"medium"`, `"long"` and `"full"` styles mirror the CLDR date-style levels used by
+    /// localization frameworks.
     ///
     /// # Returns
     ///
@@ -1024,6 +2064,11 @@ impl ParsiDate {
     /// assert_eq!(date.format("short"), "1403/05/02");
     /// assert_eq!(date.format("long"), "2 مرداد 1403"); // Day '2' is not zero-padded
     /// assert_eq!(date.format("iso"), "1403-05-02");
+    /// assert_eq!(date.format("medium"), "2 مرداد 1403");
+    /// assert_eq!(date.format("full"), "سه‌شنبه، 2 مرداد 1403");
+    /// assert_eq!(date.format("numeric-dotted"), "1403.05.02");
+    /// assert_eq!(date.format("compact"), "14030502");
+    /// assert_eq!(date.format("ordinal"), "1403-126");
     ///
     /// // Default display format (same as "short")
     /// assert_eq!(date.to_string(), "1403/05/02");
@@ -1033,22 +2078,49 @@ impl ParsiDate {
     /// assert_eq!(date.format("%Y-%j (%K)"), "1403-126 (تابستان)"); // ISO date with ordinal day and season
     /// ```
     pub fn format(&self, style_or_pattern: &str) -> String {
-        match style_or_pattern {
+        // If not a predefined style, treat as a custom strftime pattern.
+        self.format_named_style(style_or_pattern, &ParsiNames::IRANIAN)
+            .unwrap_or_else(|| self.format_strftime(style_or_pattern))
+    }
+
+    /// **Internal**: Formats one of the predefined styles (see [`format`](#method.format)) using the
+    /// given name table, or returns `None` if `style` is not a predefined style name.
+    fn format_named_style(&self, style: &str, names: &ParsiNames<'_>) -> Option<String> {
+        // Safely get month name, handling potential invalid month in self.
+        let month_name = || {
+            *names
+                .months
+                .get((self.month.saturating_sub(1)) as usize)
+                .unwrap_or(&"?InvalidMonth?") // Fallback if month index is out of bounds
+        };
+        let formatted = match style {
             "short" => format!("{}/{:02}/{:02}", self.year, self.month, self.day),
-            "long" => format!(
-                // Day is NOT zero-padded in the "long" style. Month name is used.
-                "{} {} {}",
-                self.day,
-                // Safely get month name, handling potential invalid month in self.
-                MONTH_NAMES_PERSIAN
-                    .get((self.month.saturating_sub(1)) as usize)
-                    .unwrap_or(&"?InvalidMonth?"), // Fallback if month index is out of bounds
-                self.year
-            ),
+            // Day is NOT zero-padded in the "medium"/"long" styles. Month name is used.
+            "medium" | "long" => format!("{} {} {}", self.day, month_name(), self.year),
+            "full" => {
+                let weekday_name = self
+                    .weekday_num_sat_0()
+                    .ok()
+                    .and_then(|num| names.weekdays.get(num as usize).copied())
+                    .unwrap_or("?WeekdayError?");
+                format!(
+                    "{}، {} {} {}",
+                    weekday_name,
+                    self.day,
+                    month_name(),
+                    self.year
+                )
+            }
             "iso" => format!("{}-{:02}-{:02}", self.year, self.month, self.day),
-            // If not a predefined style, treat as a custom strftime pattern.
-            pattern => self.format_strftime(pattern),
-        }
+            "numeric-dotted" => format!("{}.{:02}.{:02}", self.year, self.month, self.day),
+            "compact" => format!("{:04}{:02}{:02}", self.year, self.month, self.day),
+            "ordinal" => match self.ordinal_internal() {
+                Ok(ord) => format!("{}-{:03}", self.year, ord),
+                Err(_) => format!("{}-???", self.year), // Same placeholder as `%j`
+            },
+            _ => return None,
+        };
+        Some(formatted)
     }
 
     /// Formats the `ParsiDate` into a string according to `strftime`-like format specifiers.
@@ -1064,20 +2136,51 @@ impl ParsiDate {
     ///
     /// | Specifier | Replaced By                                        | Example (for 1403/05/02) |
     /// | :-------- | :------------------------------------------------- | :----------------------- |
-    /// | `%Y`      | Year with century (4 digits)                       | `1403`                   |
+    /// | `%Y`      | Year, not zero-padded (same as `%-Y`)              | `1403` (year 50: `50`)   |
+    /// | `%0Y`     | Year zero-padded to 4 digits                       | `1403` (year 50: `0050`) |
+    /// | `%-Y`     | Year without padding                               | `1403` (year 50: `50`)   |
+    /// | `%4Y`     | Same as `%0Y`; the fixed-width form used by parsing | `1403` (year 50: `0050`) |
+    /// | `%EY`     | Year spelled out in Persian words                  | `هزار و چهارصد و سه`     |
+    /// | `%Eg`     | Gregorian date as `(YYYY-MM-DD)`                   | `(2024-07-23)`           |
+    /// | `%GA`     | English (Gregorian) weekday name                   | `Tuesday`                |
+    /// | `%Ga`     | Abbreviated English weekday name                   | `Tue`                    |
+    /// | `%Ee`     | Era marker of the Solar Hijri calendar             | `ه.ش`                    |
+    /// | `%?L`     | `کبیسه` in leap years, nothing otherwise           | `کبیسه`                  |
     /// | `%m`      | Month as a zero-padded number                      | `05`                     |
     /// | `%d`      | Day of the month as a zero-padded number           | `02`                     |
     /// | `%B`      | Full Persian month name                            | `مرداد`                  |
     /// | `%A`      | Full Persian weekday name (Saturday to Friday)     | `سه‌شنبه`                 |
+    /// | `%b`      | Month name in Latin letters (Finglish)             | `Mordad`                 |
+    /// | `%a`      | Weekday name in Latin letters (Finglish)           | `Seshanbeh`              |
     /// | `%w`      | Weekday as a number (Saturday=0, ..., Friday=6)    | `3`                      |
     /// | `%j`      | Day of the year as a zero-padded number (001-366)  | `126`                    |
     /// | `%K`      | Full Persian season name                           | `تابستان`                |
+    /// | `%q`      | Quarter of the year (1-4)                          | `2`                      |
     /// | `%W`      | Week number of the year (Saturday start, 01-53)    | `19`                     |
+    /// | `%G`      | Week-based year (see `iso_week`)                   | `1403`                   |
+    /// | `%V`      | Week of the week-based year (01-53)                | `18`                     |
     /// | `%%`      | A literal percent sign (`%`)                       | `%`                      |
     ///
     /// **Note:** Unrecognized specifiers (e.g., `%x`, `%y`) are treated as literal characters
     /// and will appear in the output string as `%x`, `%y`, etc.
     ///
+    /// **English weekday:** `%GA` and `%Ga` take precedence over `%G`, so a week-based year
+    /// followed by a literal `a` or `A` has to quote it (`%G'a'`). `%Eg`, `%GA` and `%Ga` share a
+    /// single Gregorian conversion per call.
+    ///
+    /// **Year padding:** `%Y` prints the year as-is, which keeps existing output unchanged for
+    /// years 1000-9999 but gives a shorter field for earlier years. Use `%0Y` where fixed-width
+    /// output matters, e.g., so that formatted dates sort lexicographically across all supported
+    /// years; its output is also what [`parse`](#method.parse) expects for `%Y`.
+    ///
+    /// **Leap-year marker:** `%?L` only removes its own output in common years; surrounding literal
+    /// text is kept, so `"%Y %?L"` gives `"1404 "` with a trailing space. Trim the result if that
+    /// matters.
+    ///
+    /// **Quoted literals:** Text between single quotes (`'...'`) is emitted verbatim, without
+    /// interpreting specifiers, similar to ICU/LDML patterns. Use `''` for a literal single quote,
+    /// both inside and outside a quoted block. The quotes themselves are not emitted.
+    ///
     /// # Arguments
     ///
     /// * `pattern`: The format string containing literal characters and supported format specifiers.
@@ -1113,8 +2216,105 @@ impl ParsiDate {
     ///
     /// // Unrecognized specifier is output literally
     /// assert_eq!(date.format_strftime("%Y %x %m"), "1403 %x 01");
+    ///
+    /// // Quoted literal blocks are emitted verbatim; '' is a literal quote
+    /// assert_eq!(date.format_strftime("%Y 'سال' %m"), "1403 سال 01");
+    /// assert_eq!(date.format_strftime("'%Y is literal' %Y"), "%Y is literal 1403");
+    /// assert_eq!(date.format_strftime("%d ''%m"), "07 '01");
+    /// ```
+    pub fn format_strftime(&self, pattern: &str) -> String {
+        self.format_strftime_with_names(pattern, &ParsiNames::IRANIAN, DigitStyle::Ascii)
+    }
+
+    /// Formats the `ParsiDate` like [`format_strftime`](#method.format_strftime), writing the
+    /// numbers with the given digits.
+    ///
+    /// With [`DigitStyle::Persian`], every ASCII digit produced by a specifier (`%Y`, `%m`, `%d`,
+    /// `%j`, `%W`, `%w`, ...) is written with the Persian digits `۰`-`۹`. Literal text in the
+    /// pattern, including quoted literals and digits typed directly into it, is left intact, as
+    /// are names (`%B`, `%A`, `%K`) and the `%%` escape. [`DigitStyle::Ascii`] reproduces
+    /// `format_strftime` exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern`: The format string, with the same specifiers as [`format_strftime`](#method.format_strftime).
+    /// * `digits`: The [`DigitStyle`] used for numbers.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the formatted date. Invalid instances produce the same error
+    /// placeholders as `format_strftime`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DigitStyle, ParsiDate};
+    ///
+    /// let date = ParsiDate::new(1403, 5, 2).unwrap();
+    /// assert_eq!(date.format_strftime_localized("%Y/%m/%d", DigitStyle::Persian), "۱۴۰۳/۰۵/۰۲");
+    /// assert_eq!(date.format_strftime_localized("%d %B %Y", DigitStyle::Persian), "۰۲ مرداد ۱۴۰۳");
+    ///
+    /// // Literal digits and '%%' are not converted.
+    /// assert_eq!(
+    ///     date.format_strftime_localized("'Q3' %j, 100%%", DigitStyle::Persian),
+    ///     "Q3 ۱۲۶, 100%"
+    /// );
+    /// assert_eq!(
+    ///     date.format_strftime_localized("%Y/%m/%d", DigitStyle::Ascii),
+    ///     date.format_strftime("%Y/%m/%d")
+    /// );
+    /// ```
+    pub fn format_strftime_localized(&self, pattern: &str, digits: DigitStyle) -> String {
+        self.format_strftime_with_names(pattern, &ParsiNames::IRANIAN, digits)
+    }
+
+    /// Formats the `ParsiDate` using a runtime-provided table of month, weekday and season names.
+    ///
+    /// This behaves exactly like [`format`](#method.format), except that the names emitted for
+    /// `%B` (month), `%A` (weekday), `%K` (season) and the named styles are taken from `names`
+    /// instead of the built-in Iranian tables. Numeric specifiers are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `style_or_pattern`: A predefined style (see [`format`](#method.format)) or a custom pattern (see [`format_strftime`](#method.format_strftime)).
+    /// * `names`: The [`ParsiNames`] table to use. [`ParsiNames::IRANIAN`] reproduces the output of `format`.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the formatted date. Invalid instances produce the same error
+    /// placeholders as `format_strftime`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, ParsiNames};
+    ///
+    /// let names = ParsiNames {
+    ///     weekdays: ["Sat", "Sun", "Mon", "Tue", "Wed", "Thu", "Fri"],
+    ///     ..ParsiNames::IRANIAN
+    /// };
+    ///
+    /// let date = ParsiDate::new(1403, 1, 7).unwrap(); // A Tuesday
+    /// assert_eq!(date.format_with_names("%A %d %B", &names), "Tue 07 فروردین");
+    ///
+    /// // The Iranian table reproduces the default formatter.
+    /// assert_eq!(date.format_with_names("long", &ParsiNames::IRANIAN), date.format("long"));
     /// ```
-    pub fn format_strftime(&self, pattern: &str) -> String {
+    pub fn format_with_names(&self, style_or_pattern: &str, names: &ParsiNames<'_>) -> String {
+        self.format_named_style(style_or_pattern, names)
+            .unwrap_or_else(|| {
+                self.format_strftime_with_names(style_or_pattern, names, DigitStyle::Ascii)
+            })
+    }
+
+    /// **Internal**: `strftime`-style formatting using the given name table for `%B`, `%A` and `%K`,
+    /// writing the output of each specifier with the given digits.
+    pub(crate) fn format_strftime_with_names(
+        &self,
+        pattern: &str,
+        names: &ParsiNames<'_>,
+        digits: DigitStyle,
+    ) -> String {
         // Preallocate string capacity for potentially better performance.
         let mut result = String::with_capacity(pattern.len() + 10); // Estimate a bit extra
                                                                     // Use a character iterator to handle multi-byte characters in the pattern correctly.
@@ -1124,21 +2324,70 @@ impl ParsiDate {
         // Cache results of potentially expensive calculations (weekday, ordinal, season)
         // if they are requested multiple times in the same format pattern.
         // Store the Result to handle potential errors during calculation only once.
-        let mut weekday_name_cache: Option<Result<String, DateError>> = None;
         let mut ordinal_day_cache: Option<Result<u32, DateError>> = None;
         let mut weekday_num_cache: Option<Result<u32, DateError>> = None;
         let mut season_cache: Option<Result<Season, DateError>> = None;
         let mut week_of_year_cache: Option<Result<u32, DateError>> = None;
+        let mut iso_week_cache: Option<Result<(i32, u32), DateError>> = None;
+        let mut gregorian_cache: Option<Result<NaiveDate, DateError>> = None;
 
         // Iterate through the format pattern characters
         while let Some(c) = chars.next() {
             if c == '%' {
+                // Remember where this specifier's output starts, for digit conversion below.
+                let start = result.len();
                 // Found a potential specifier, look at the next character.
                 match chars.next() {
                     // %% -> Literal percent sign
                     Some('%') => result.push('%'),
-                    // %Y -> Year with century
+                    // %Y or %-Y -> Year without padding
                     Some('Y') => result.push_str(&self.year.to_string()),
+                    Some('-') if chars.peek() == Some(&'Y') => {
+                        chars.next(); // Consume 'Y'
+                        result.push_str(&self.year.to_string());
+                    }
+                    // %0Y or %4Y -> Year zero-padded to 4 digits
+                    Some('0' | '4') if chars.peek() == Some(&'Y') => {
+                        chars.next(); // Consume 'Y'
+                        result.push_str(&format!("{:04}", self.year));
+                    }
+                    // %EY -> Year spelled out in Persian words
+                    Some('E') if chars.peek() == Some(&'Y') => {
+                        chars.next(); // Consume 'Y'
+                        result.push_str(&self.year_in_words_persian());
+                    }
+                    // %Ee -> Era marker ("ه.ش")
+                    Some('E') if chars.peek() == Some(&'e') => {
+                        chars.next(); // Consume 'e'
+                        result.push_str(ERA_MARKER_PERSIAN);
+                    }
+                    // %?L -> Leap-year marker ("کبیسه" or nothing)
+                    Some('?') if chars.peek() == Some(&'L') => {
+                        chars.next(); // Consume 'L'
+                        result.push_str(self.leap_year_marker());
+                    }
+                    // %Eg -> Gregorian date in parentheses, e.g. "(2024-07-23)"
+                    Some('E') if chars.peek() == Some(&'g') => {
+                        chars.next(); // Consume 'g'
+                        if gregorian_cache.is_none() {
+                            gregorian_cache = Some(self.to_gregorian());
+                        }
+                        match gregorian_cache.as_ref().unwrap() {
+                            Ok(g) => result.push_str(&format!("({})", g.format("%Y-%m-%d"))),
+                            Err(_) => result.push_str("?GregorianError?"),
+                        }
+                    }
+                    // %GA / %Ga -> English weekday name, full or abbreviated
+                    Some('G') if matches!(chars.peek(), Some('A' | 'a')) => {
+                        let abbreviated = chars.next() == Some('a');
+                        if gregorian_cache.is_none() {
+                            gregorian_cache = Some(self.to_gregorian());
+                        }
+                        match gregorian_cache.as_ref().unwrap() {
+                            Ok(g) => result.push_str(english_weekday_name(g, abbreviated)),
+                            Err(_) => result.push_str("?WeekdayError?"),
+                        }
+                    }
                     // %m -> Month number (01-12)
                     Some('m') => result.push_str(&format!("{:02}", self.month)),
                     // %d -> Day number (01-31)
@@ -1147,7 +2396,7 @@ impl ParsiDate {
                     Some('B') => {
                         // Safely access the month name using 0-based index.
                         if let Some(name) =
-                            MONTH_NAMES_PERSIAN.get((self.month.saturating_sub(1)) as usize)
+                            names.months.get((self.month.saturating_sub(1)) as usize)
                         {
                             result.push_str(name);
                         } else {
@@ -1156,12 +2405,42 @@ impl ParsiDate {
                     }
                     // %A -> Full Persian weekday name
                     Some('A') => {
-                        if weekday_name_cache.is_none() {
-                            weekday_name_cache = Some(self.weekday_internal());
+                        // Shares the weekday number cache with %w.
+                        if weekday_num_cache.is_none() {
+                            weekday_num_cache = Some(self.weekday_num_sat_0());
                         }
-                        match weekday_name_cache.as_ref().unwrap() {
-                            Ok(name) => result.push_str(name),
-                            Err(_) => result.push_str("?WeekdayError?"),
+                        match weekday_num_cache
+                            .as_ref()
+                            .unwrap()
+                            .as_ref()
+                            .ok()
+                            .and_then(|num| names.weekdays.get(*num as usize))
+                        {
+                            Some(name) => result.push_str(name),
+                            None => result.push_str("?WeekdayError?"),
+                        }
+                    }
+                    // %b -> Finglish month name
+                    Some('b') => {
+                        match MONTH_NAMES_FINGLISH.get(self.month.wrapping_sub(1) as usize) {
+                            Some(name) => result.push_str(name),
+                            None => result.push_str("?InvalidMonth?"),
+                        }
+                    }
+                    // %a -> Finglish weekday name
+                    Some('a') => {
+                        if weekday_num_cache.is_none() {
+                            weekday_num_cache = Some(self.weekday_num_sat_0());
+                        }
+                        match weekday_num_cache
+                            .as_ref()
+                            .unwrap()
+                            .as_ref()
+                            .ok()
+                            .and_then(|num| WEEKDAY_NAMES_FINGLISH.get(*num as usize))
+                        {
+                            Some(name) => result.push_str(name),
+                            None => result.push_str("?WeekdayError?"),
                         }
                     }
                     // %w -> Weekday number (Saturday=0)
@@ -1192,10 +2471,15 @@ impl ParsiDate {
                         }
                         // Use the cached Result.
                         match season_cache.as_ref().unwrap() {
-                            Ok(season) => result.push_str(season.name_persian()),
+                            Ok(season) => result.push_str(names.seasons[*season as usize]),
                             Err(_) => result.push_str("?SeasonError?"), // Indicate calculation error
                         }
                     }
+                    // %q -> Quarter of the year (1-4)
+                    Some('q') => match self.quarter() {
+                        Ok(quarter) => result.push_str(&quarter.to_string()),
+                        Err(_) => result.push('?'),
+                    },
                     Some('W') => {
                         if week_of_year_cache.is_none() {
                             week_of_year_cache = Some(self.week_of_year()); // Calculate if not cached
@@ -1205,6 +2489,17 @@ impl ParsiDate {
                             Err(_) => result.push_str("?WeekError?"), // Error indicator
                         }
                     }
+                    // %G / %V -> Week-based year and week (shared cache)
+                    Some(spec @ ('G' | 'V')) => {
+                        if iso_week_cache.is_none() {
+                            iso_week_cache = Some(self.iso_week());
+                        }
+                        match (spec, iso_week_cache.as_ref().unwrap()) {
+                            ('G', Ok((week_year, _))) => result.push_str(&week_year.to_string()),
+                            (_, Ok((_, week))) => result.push_str(&format!("{:02}", week)),
+                            (_, Err(_)) => result.push_str("?WeekError?"),
+                        }
+                    }
                     // Unrecognized Specifier (e.g., %x)
                     Some(other) => {
                         result.push('%');
@@ -1216,6 +2511,15 @@ impl ParsiDate {
                         break;
                     }
                 }
+                // Rewrite the digits of the specifier's output only, leaving literals intact.
+                if digits != DigitStyle::Ascii {
+                    let converted = digits.convert(&result[start..]);
+                    result.truncate(start);
+                    result.push_str(&converted);
+                }
+            } else if c == '\'' {
+                // Start of a quoted literal block ('...'), or an escaped quote ('').
+                Self::push_quoted_literal(&mut chars, &mut result);
             } else {
                 // Not a '%', so it's a literal character. Append it directly.
                 result.push(c);
@@ -1224,6 +2528,260 @@ impl ParsiDate {
         result // Return the final formatted string
     }
 
+    /// Lists the specifier letters referenced by a `strftime`-style pattern.
+    ///
+    /// Scans `pattern` the same way [`format_strftime`](#method.format_strftime) does, without
+    /// needing a date, and returns the character following each `%`, in order of first appearance
+    /// and without duplicates. This lets a format editor preview the fields a custom pattern
+    /// uses, or warn about specifiers that are not supported before any formatting happens.
+    ///
+    /// *   `%%` (a literal percent sign) and a dangling `%` at the end are not reported.
+    /// *   Text inside quoted literals (`'...'`) is skipped, as the formatter does not interpret it.
+    /// *   For extended specifiers, only the prefix is reported: `%EY`, `%Eg` and `%Ee` yield `'E'`,
+    ///     `%GA` and `%Ga` yield `'G'`, `%?L` yields `'?'`, and the padded and unpadded years `%0Y`
+    ///     and `%-Y` yield `'0'` and `'-'`.
+    /// *   Unrecognized letters are reported too, since detecting them is one of the purposes.
+    ///
+    /// Predefined style names such as `"short"` are not expanded; they contain no specifiers.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern`: The format pattern to inspect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// assert_eq!(ParsiDate::format_specifiers_used("%Y/%m/%d"), vec!['Y', 'm', 'd']);
+    /// assert_eq!(ParsiDate::format_specifiers_used("%d %B %Y, %d%%"), vec!['d', 'B', 'Y']);
+    /// assert_eq!(ParsiDate::format_specifiers_used("'%Y' %EY %x"), vec!['E', 'x']);
+    /// assert!(ParsiDate::format_specifiers_used("short").is_empty());
+    /// ```
+    pub fn format_specifiers_used(pattern: &str) -> Vec<char> {
+        let mut used = Vec::new();
+        let mut chars = pattern.chars().peekable();
+        // Quoted literal text is consumed into a scratch buffer and discarded.
+        let mut skipped = String::new();
+        while let Some(c) = chars.next() {
+            if c == '%' {
+                match chars.next() {
+                    Some('%') | None => {}
+                    Some(specifier) => {
+                        if !used.contains(&specifier) {
+                            used.push(specifier);
+                        }
+                    }
+                }
+            } else if c == '\'' {
+                Self::push_quoted_literal(&mut chars, &mut skipped);
+            }
+        }
+        used
+    }
+
+    /// **Internal**: Emits a quoted literal block of a format pattern verbatim.
+    ///
+    /// Called right after an opening single quote has been consumed. Everything up to the
+    /// matching closing quote is appended to `result` without interpreting specifiers. A doubled
+    /// quote (`''`) produces a single literal quote, both directly after the opening quote (so `''`
+    /// outside a block is an escaped quote) and inside a block. An unterminated block runs to the
+    /// end of the pattern.
+    pub(crate) fn push_quoted_literal(
+        chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+        result: &mut String,
+    ) {
+        // A quote directly after the opening one is an escaped quote, not an empty block.
+        if chars.peek() == Some(&'\'') {
+            chars.next();
+            result.push('\'');
+            return;
+        }
+        while let Some(c) = chars.next() {
+            if c == '\'' {
+                if chars.peek() == Some(&'\'') {
+                    // Escaped quote: emit one quote and keep going.
+                    chars.next();
+                    result.push('\'');
+                } else {
+                    // Closing quote: the literal block ends here.
+                    return;
+                }
+            } else {
+                result.push(c);
+            }
+        }
+    }
+
+    /// Formats the `ParsiDate` like [`format`](#method.format), avoiding an allocation when the
+    /// pattern contains no format specifiers.
+    ///
+    /// If `style_or_pattern` is one of the predefined styles (`"short"`, `"long"`, `"iso"`, etc.) or
+    /// contains a `%` or a quote (`'`), the date is formatted and returned as `Cow::Owned`. Otherwise
+    /// the pattern is a pure literal, and it is returned unchanged as `Cow::Borrowed` without
+    /// allocating. This is useful for templating systems that format many strings, only some of
+    /// which contain specifiers.
+    ///
+    /// # Arguments
+    ///
+    /// * `style_or_pattern`: A predefined style or a custom pattern, as accepted by [`format`](#method.format).
+    ///
+    /// # Returns
+    ///
+    /// A `Cow<str>` that always compares equal to `self.format(style_or_pattern)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    /// use std::borrow::Cow;
+    ///
+    /// let date = ParsiDate::new(1403, 5, 2).unwrap();
+    ///
+    /// // Pure literal: borrowed, no allocation
+    /// assert!(matches!(date.format_cow("Due date"), Cow::Borrowed("Due date")));
+    ///
+    /// // Specifiers or predefined styles: owned
+    /// assert!(matches!(date.format_cow("%Y/%m/%d"), Cow::Owned(_)));
+    /// assert_eq!(date.format_cow("%Y/%m/%d"), "1403/05/02");
+    /// assert_eq!(date.format_cow("iso"), "1403-05-02");
+    /// ```
+    pub fn format_cow<'a>(&self, style_or_pattern: &'a str) -> Cow<'a, str> {
+        if let Some(formatted) = self.format_named_style(style_or_pattern, &ParsiNames::IRANIAN) {
+            return Cow::Owned(formatted);
+        }
+        match style_or_pattern {
+            pattern if pattern.contains(['%', '\'']) => Cow::Owned(self.format_strftime(pattern)),
+            literal => Cow::Borrowed(literal),
+        }
+    }
+
+    /// Formats the `ParsiDate` like [`format`](#method.format) and wraps the result in Unicode
+    /// bidirectional isolate controls.
+    ///
+    /// The output starts with the opening control selected by `isolate` (`LRI` U+2066, `RLI` U+2067
+    /// or `FSI` U+2068) and ends with `PDI` (U+2069). This keeps a formatted Persian date from being
+    /// reordered by the Unicode Bidirectional Algorithm when it is embedded in text of the opposite
+    /// direction, such as a Persian date inside an English log line or UI label.
+    ///
+    /// # Arguments
+    ///
+    /// * `style_or_pattern`: A predefined style or a custom pattern, exactly as accepted by [`format`](#method.format).
+    /// * `isolate`: The [`BidiIsolate`] kind used to open the isolated run.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the formatted date surrounded by the isolate controls.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{BidiIsolate, ParsiDate};
+    ///
+    /// let date = ParsiDate::new(1403, 5, 2).unwrap();
+    ///
+    /// assert_eq!(
+    ///     date.format_bidi_isolated("short", BidiIsolate::LeftToRight),
+    ///     "\u{2066}1403/05/02\u{2069}"
+    /// );
+    /// assert_eq!(
+    ///     date.format_bidi_isolated("%d %B %Y", BidiIsolate::FirstStrong),
+    ///     "\u{2068}02 مرداد 1403\u{2069}"
+    /// );
+    /// ```
+    pub fn format_bidi_isolated(&self, style_or_pattern: &str, isolate: BidiIsolate) -> String {
+        let formatted = self.format(style_or_pattern);
+        let mut result = String::with_capacity(formatted.len() + 6); // Two 3-byte control characters
+        result.push(isolate.opening_char());
+        result.push_str(&formatted);
+        result.push(isolate.closing_char());
+        result
+    }
+
+    /// Formats the `ParsiDate` using a template with readable `{name}` placeholders.
+    ///
+    /// This is an alternative to the `%` specifiers of [`format_strftime`](#method.format_strftime),
+    /// friendlier for templates edited by non-programmers (e.g., in configuration files). Each
+    /// placeholder maps to an existing specifier, so the output is identical to the equivalent
+    /// `strftime` pattern.
+    ///
+    /// # Placeholders
+    ///
+    /// | Placeholder    | Equivalent | Example (for 1403/05/02) |
+    /// | :------------- | :--------- | :----------------------- |
+    /// | `{year}`       | `%Y`       | `1403`                   |
+    /// | `{year_words}` | `%EY`      | `هزار و چهارصد و سه`     |
+    /// | `{month}`      | `%m`       | `05`                     |
+    /// | `{month_name}` | `%B`       | `مرداد`                  |
+    /// | `{day}`        | `%d`       | `02`                     |
+    /// | `{weekday}`    | `%A`       | `سه‌شنبه`                 |
+    /// | `{weekday_num}`| `%w`       | `3`                      |
+    /// | `{season}`     | `%K`       | `تابستان`                |
+    /// | `{ordinal}`    | `%j`       | `126`                    |
+    /// | `{week}`       | `%W`       | `19`                     |
+    ///
+    /// Use `{{` and `}}` for literal braces. Unknown placeholders (e.g., `{foo}`) and unmatched
+    /// braces are emitted unchanged. All other characters, including `%` and `'`, are literal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let date = ParsiDate::new(1403, 5, 2).unwrap();
+    /// assert_eq!(date.format_named("{weekday}، {day} {month_name} {year}"), "سه‌شنبه، 02 مرداد 1403");
+    /// assert_eq!(date.format_named("{year}-{month}-{day} (day {ordinal})"), "1403-05-02 (day 126)");
+    /// assert_eq!(date.format_named("{{year}} = {year}, 100%"), "{year} = 1403, 100%");
+    /// assert_eq!(date.format_named("{unknown}"), "{unknown}");
+    /// ```
+    pub fn format_named(&self, template: &str) -> String {
+        // Translate the template into an equivalent strftime pattern, escaping literal `%` and
+        // quote characters so they are not interpreted, then reuse the regular formatter.
+        let mut pattern = String::with_capacity(template.len() + 8);
+        let mut rest = template;
+        while let Some(c) = rest.chars().next() {
+            if rest.starts_with("{{") {
+                pattern.push('{');
+                rest = &rest[2..];
+                continue;
+            }
+            if rest.starts_with("}}") {
+                pattern.push('}');
+                rest = &rest[2..];
+                continue;
+            }
+            if c == '{' {
+                if let Some(end) = rest.find('}') {
+                    let specifier = match &rest[1..end] {
+                        "year" => Some("%Y"),
+                        "year_words" => Some("%EY"),
+                        "month" => Some("%m"),
+                        "month_name" => Some("%B"),
+                        "day" => Some("%d"),
+                        "weekday" => Some("%A"),
+                        "weekday_num" => Some("%w"),
+                        "season" => Some("%K"),
+                        "ordinal" => Some("%j"),
+                        "week" => Some("%W"),
+                        _ => None,
+                    };
+                    if let Some(specifier) = specifier {
+                        pattern.push_str(specifier);
+                        rest = &rest[end + 1..];
+                        continue;
+                    }
+                }
+            }
+            match c {
+                '%' => pattern.push_str("%%"),
+                '\'' => pattern.push_str("''"),
+                other => pattern.push(other),
+            }
+            rest = &rest[c.len_utf8()..];
+        }
+        self.format_strftime(&pattern)
+    }
+
     // --- Parsing ---
 
     /// Parses a string representation of a Persian date into a `ParsiDate` instance,
@@ -1241,10 +2799,19 @@ impl ParsiDate {
     ///
     /// # Supported Format Specifiers for Parsing:
     ///
-    /// *   `%Y`: Parses exactly 4 digits as the Persian year.
+    /// *   `%Y`: Parses exactly 4 digits as the Persian year. Years below 1000 must be zero-padded
+    ///     (e.g., `"0050"`), as produced by the `%0Y` format specifier.
+    /// *   `%4Y`: Same as `%Y`, spelling out the fixed width. Neither form ever reads a fifth digit,
+    ///     so adjacent numeric fields without separators (`"%Y%m%d"` for `"14030502"`) are
+    ///     unambiguous, and a 5-digit year such as `"14030/05/02"` is rejected.
     /// *   `%m`: Parses exactly 2 digits as the Persian month (01-12).
     /// *   `%d`: Parses exactly 2 digits as the Persian day (01-31).
+    /// *   `%-m`, `%-d`: Parse a month or day written with 1 or 2 digits (e.g., `"1403/5/2"`).
+    ///     Digits are consumed greedily up to two, so a separator should follow these fields.
+    /// *   `%-Y`: Parses a year written with 1 to 4 digits (e.g., `"50"` for year 50).
     /// *   `%B`: Parses a full Persian month name (case-sensitive, must match one of the names in `MONTH_NAMES_PERSIAN`, e.g., "فروردین", "مرداد").
+    ///     The longest matching name wins, and only as many input bytes as the longest name are inspected.
+    /// *   `%Ee`: Matches the era marker `ه.ش`. Literal multi-byte text such as `"%Y ه.ش"` also works.
     /// *   `%%`: Matches a literal percent sign (`%`) character in the input string.
     ///
     /// **Unsupported Specifiers:** Specifiers representing calculated values like `%A` (weekday name),
@@ -1253,47 +2820,463 @@ impl ParsiDate {
     ///
     /// # Arguments
     ///
-    /// * `s`: The input string slice (`&str`) containing the date representation to be parsed.
-    /// * `format`: The format string slice (`&str`) describing the expected structure and specifiers of the input `s`.
+    /// * `s`: The input string slice (`&str`) containing the date representation to be parsed.
+    /// * `format`: The format string slice (`&str`) describing the expected structure and specifiers of the input `s`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::ParseError(kind))` if parsing fails. The `kind` ([`ParseErrorKind`]) provides details:
+    /// *   `ParseErrorKind::FormatMismatch`: The input string `s` does not structurally match the `format` string (e.g., wrong separators, missing components, extra trailing characters).
+    /// *   `ParseErrorKind::InvalidNumber`: A numeric component (`%Y`, `%m`, `%d`) could not be parsed as a number, or it did not contain the required number of digits (4 for `%Y`, 2 for `%m`/`%d`).
+    /// *   `ParseErrorKind::InvalidMonthName`: The input string did not contain a valid, recognized Persian month name where `%B` was expected in the format.
+    /// *   `ParseErrorKind::UnsupportedSpecifier`: The `format` string included a specifier not supported for parsing (e.g., `%A`, `%j`, `%K`).
+    /// *   `ParseErrorKind::InvalidDateValue`: The year, month, and day values were successfully extracted according to the format, but they do not form a logically valid Persian date (e.g., "1404/12/30" where 1404 is not a leap year; "1403/07/31" where Mehr has only 30 days). This is checked by the final internal call to `ParsiDate::new`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, DateError, ParseErrorKind};
+    ///
+    /// // --- Success Cases ---
+    /// assert_eq!(ParsiDate::parse("1403/05/02", "%Y/%m/%d"), Ok(ParsiDate::new(1403, 5, 2).unwrap()));
+    /// assert_eq!(ParsiDate::parse("1399-12-30", "%Y-%m-%d"), Ok(ParsiDate::new(1399, 12, 30).unwrap()));
+    /// assert_eq!(ParsiDate::parse("02 مرداد 1403", "%d %B %Y"), Ok(ParsiDate::new(1403, 5, 2).unwrap()));
+    /// assert_eq!(ParsiDate::parse("1403/5/2", "%Y/%-m/%-d"), Ok(ParsiDate::new(1403, 5, 2).unwrap()));
+    ///
+    /// // --- Error Cases ---
+    /// assert_eq!(ParsiDate::parse("1403-05-02", "%Y/%m/%d"), Err(DateError::ParseError(ParseErrorKind::FormatMismatch)));
+    /// assert_eq!(ParsiDate::parse("1403/05/02 extra", "%Y/%m/%d"), Err(DateError::ParseError(ParseErrorKind::FormatMismatch)));
+    /// assert_eq!(ParsiDate::parse("1403/05/2", "%Y/%m/%d"), Err(DateError::ParseError(ParseErrorKind::InvalidNumber)));
+    /// assert_eq!(ParsiDate::parse("abcd/05/02", "%Y/%m/%d"), Err(DateError::ParseError(ParseErrorKind::InvalidNumber)));
+    /// assert_eq!(ParsiDate::parse("1404/12/30", "%Y/%m/%d"), Err(DateError::ParseError(ParseErrorKind::InvalidDateValue)));
+    /// assert_eq!(ParsiDate::parse("Tuesday 1403", "%A %Y"), Err(DateError::ParseError(ParseErrorKind::UnsupportedSpecifier)));
+    /// assert_eq!(ParsiDate::parse("Summer 1403", "%K %Y"), Err(DateError::ParseError(ParseErrorKind::UnsupportedSpecifier))); // %K not supported for parsing
+    /// ```
+    pub fn parse(s: &str, format: &str) -> Result<Self, DateError> {
+        // Options to store the parsed components. They start as None.
+        let mut parsed_year: Option<i32> = None;
+        let mut parsed_month: Option<u32> = None;
+        let mut parsed_day: Option<u32> = None;
+
+        Self::scan_components(
+            s,
+            format,
+            &mut parsed_year,
+            &mut parsed_month,
+            &mut parsed_day,
+        )?;
+        Self::from_scanned_components(parsed_year, parsed_month, parsed_day)
+    }
+
+    /// Parses a date string like [`parse`](#method.parse) after trimming surrounding whitespace.
+    ///
+    /// Leading and trailing whitespace (ASCII and Unicode, as defined by [`str::trim`], e.g.
+    /// spaces, tabs, newlines and the no-break space U+00A0) is removed from `s` before
+    /// parsing. This accommodates input from web form fields and CSV cells. The `format` string is
+    /// not trimmed, and whitespace *inside* the date must still match `format` exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The input string slice containing the date, possibly surrounded by whitespace.
+    /// * `format`: The format string, with the same specifiers as [`parse`](#method.parse).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`parse`](#method.parse) for the trimmed input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParseErrorKind, ParsiDate};
+    ///
+    /// let expected = ParsiDate::new(1403, 5, 2).unwrap();
+    /// assert_eq!(ParsiDate::parse_trimmed(" 1403/05/02 ", "%Y/%m/%d"), Ok(expected));
+    /// assert_eq!(ParsiDate::parse_trimmed("\t1403/05/02\r\n", "%Y/%m/%d"), Ok(expected));
+    ///
+    /// // The strict parser rejects the surrounding spaces.
+    /// assert!(ParsiDate::parse(" 1403/05/02 ", "%Y/%m/%d").is_err());
+    /// assert_eq!(
+    ///     ParsiDate::parse("1403/05/02 ", "%Y/%m/%d"),
+    ///     Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    /// );
+    /// ```
+    pub fn parse_trimmed(s: &str, format: &str) -> Result<Self, DateError> {
+        Self::parse(s.trim(), format)
+    }
+
+    /// Parses a date string like [`parse`](#method.parse), also accepting Persian and Arabic-Indic
+    /// digits.
+    ///
+    /// Input from Iranian web forms often uses the Persian digits `۰`-`۹` (U+06F0 to U+06F9) or
+    /// the Arabic-Indic digits `٠`-`٩` (U+0660 to U+0669), e.g. `"۱۴۰۳/۰۵/۰۲"`. These are
+    /// normalized to ASCII (in both `s` and `format`) before parsing, so each numeric specifier
+    /// accepts any mix of the three digit sets with the usual width rules. [`parse`](#method.parse)
+    /// itself stays strict and only accepts ASCII digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The input string slice containing the date to be parsed.
+    /// * `format`: The format string, with the same specifiers as [`parse`](#method.parse).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`parse`](#method.parse) for the normalized input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DigitStyle, ParsiDate};
+    ///
+    /// let expected = ParsiDate::new(1403, 5, 2).unwrap();
+    /// assert_eq!(ParsiDate::parse_localized("۱۴۰۳/۰۵/۰۲", "%Y/%m/%d"), Ok(expected));
+    /// assert_eq!(ParsiDate::parse_localized("١٤٠٣/٠٥/٠٢", "%Y/%m/%d"), Ok(expected)); // Arabic-Indic
+    /// assert_eq!(ParsiDate::parse_localized("۰۲ مرداد 1403", "%d %B %Y"), Ok(expected));
+    /// assert!(ParsiDate::parse("۱۴۰۳/۰۵/۰۲", "%Y/%m/%d").is_err());
+    ///
+    /// // Round trip with localized formatting
+    /// let text = expected.format_strftime_localized("%Y/%m/%d", DigitStyle::Persian);
+    /// assert_eq!(ParsiDate::parse_localized(&text, "%Y/%m/%d"), Ok(expected));
+    /// ```
+    pub fn parse_localized(s: &str, format: &str) -> Result<Self, DateError> {
+        Self::parse(&normalize_digits(s), &normalize_digits(format))
+    }
+
+    /// Parses a Persian date and its claimed Gregorian equivalent, and checks that they agree.
+    ///
+    /// This is a data-integrity helper for migrations where records carry both calendars. The
+    /// Persian string is parsed with [`parse`](#method.parse), the Gregorian string with
+    /// chrono's [`NaiveDate::parse_from_str`] (so `gregorian_fmt` uses chrono's specifiers), and
+    /// the Persian date is returned only if both denote the same day.
+    ///
+    /// # Arguments
+    ///
+    /// * `persian_s`: The Persian date string.
+    /// * `persian_fmt`: The format of `persian_s`, as accepted by [`parse`](#method.parse).
+    /// * `gregorian_s`: The Gregorian date string.
+    /// * `gregorian_fmt`: The chrono format of `gregorian_s` (e.g., `"%Y-%m-%d"`).
+    ///
+    /// # Errors
+    ///
+    /// *   `DateError::ParseError(_)` if `persian_s` cannot be parsed.
+    /// *   `DateError::GregorianConversionError` if `gregorian_s` cannot be parsed with
+    ///     `gregorian_fmt`, or the Persian date cannot be converted to Gregorian.
+    /// *   `DateError::ConsistencyMismatch` if the two dates are different days.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDate};
+    ///
+    /// assert_eq!(
+    ///     ParsiDate::parse_and_verify_gregorian("1403/05/02", "%Y/%m/%d", "2024-07-23", "%Y-%m-%d"),
+    ///     Ok(ParsiDate::new(1403, 5, 2).unwrap())
+    /// );
+    /// // Off by one day
+    /// assert_eq!(
+    ///     ParsiDate::parse_and_verify_gregorian("1403/05/02", "%Y/%m/%d", "2024-07-24", "%Y-%m-%d"),
+    ///     Err(DateError::ConsistencyMismatch)
+    /// );
+    /// // Malformed Gregorian input
+    /// assert_eq!(
+    ///     ParsiDate::parse_and_verify_gregorian("1403/05/02", "%Y/%m/%d", "2024/07/23", "%Y-%m-%d"),
+    ///     Err(DateError::GregorianConversionError)
+    /// );
+    /// ```
+    pub fn parse_and_verify_gregorian(
+        persian_s: &str,
+        persian_fmt: &str,
+        gregorian_s: &str,
+        gregorian_fmt: &str,
+    ) -> Result<Self, DateError> {
+        let persian = Self::parse(persian_s, persian_fmt)?;
+        let gregorian = NaiveDate::parse_from_str(gregorian_s, gregorian_fmt)
+            .map_err(|_| DateError::GregorianConversionError)?;
+        if persian.to_gregorian_internal()? != gregorian {
+            return Err(DateError::ConsistencyMismatch);
+        }
+        Ok(persian)
+    }
+
+    /// Parses a column of date strings with a single format, returning one result per row.
+    ///
+    /// Each value is parsed independently with [`parse`](#method.parse), so a malformed row does
+    /// not stop the others. The returned vector has the same length and order as `values`, which
+    /// lets an import tool report failures by row number.
+    ///
+    /// # Arguments
+    ///
+    /// * `values`: The date strings, one per row.
+    /// * `format`: The format string, with the same specifiers as [`parse`](#method.parse).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParseErrorKind, ParsiDate};
+    ///
+    /// let rows = ["1403/05/02", "1403/13/01", "1404/01/01"];
+    /// let results = ParsiDate::parse_column(&rows, "%Y/%m/%d");
+    /// assert_eq!(results.len(), 3);
+    /// assert_eq!(results[0], Ok(ParsiDate::new(1403, 5, 2).unwrap()));
+    /// assert_eq!(
+    ///     results[1],
+    ///     Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    /// );
+    ///
+    /// let failed_rows: Vec<usize> = results
+    ///     .iter()
+    ///     .enumerate()
+    ///     .filter(|(_, r)| r.is_err())
+    ///     .map(|(i, _)| i + 1)
+    ///     .collect();
+    /// assert_eq!(failed_rows, vec![2]);
+    /// ```
+    pub fn parse_column(values: &[&str], format: &str) -> Vec<Result<Self, DateError>> {
+        values.iter().map(|s| Self::parse(s, format)).collect()
+    }
+
+    /// Parses a column of date strings with a single format, stopping at the first failure.
+    ///
+    /// This is the all-or-nothing counterpart of [`parse_column`](#method.parse_column).
+    ///
+    /// # Arguments
+    ///
+    /// * `values`: The date strings, one per row.
+    /// * `format`: The format string, with the same specifiers as [`parse`](#method.parse).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first value that [`parse`](#method.parse) rejects; later values
+    /// are not parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParseErrorKind, ParsiDate};
+    ///
+    /// assert_eq!(
+    ///     ParsiDate::parse_column_strict(&["1403/05/02", "1404/01/01"], "%Y/%m/%d"),
+    ///     Ok(vec![ParsiDate::new(1403, 5, 2).unwrap(), ParsiDate::new(1404, 1, 1).unwrap()])
+    /// );
+    /// assert_eq!(
+    ///     ParsiDate::parse_column_strict(&["1403/05/02", "bad", "1403/13/01"], "%Y/%m/%d"),
+    ///     Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    /// );
+    /// ```
+    pub fn parse_column_strict(values: &[&str], format: &str) -> Result<Vec<Self>, DateError> {
+        values.iter().map(|s| Self::parse(s, format)).collect()
+    }
+
+    /// Parses a date string like [`parse`](#method.parse), but also reports the components that
+    /// were extracted before any failure.
+    ///
+    /// This is a diagnostic aid for bulk imports: it makes it possible to report, for instance,
+    /// that the year and month of a row parsed fine but the day was malformed. The strict
+    /// [`parse`](#method.parse) should be used to obtain the actual `ParsiDate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The input string slice containing the date to be parsed.
+    /// * `format`: The format string, with the same specifiers as [`parse`](#method.parse).
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(year, month, day, error)`:
+    /// *   `year`, `month`, `day`: The components extracted before scanning stopped. A component is
+    ///     `None` if it was not reached (or is not present in `format`).
+    /// *   `error`: `None` if `s` parses into a valid date (exactly when [`parse`](#method.parse)
+    ///     succeeds), otherwise the same error that [`parse`](#method.parse) would return.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, DateError, ParseErrorKind};
+    ///
+    /// // The day is malformed, but the year and month were extracted.
+    /// assert_eq!(
+    ///     ParsiDate::parse_partial_report("1403/05/x2", "%Y/%m/%d"),
+    ///     (Some(1403), Some(5), None, Some(DateError::ParseError(ParseErrorKind::InvalidNumber)))
+    /// );
+    ///
+    /// // All components parsed, but they do not form a valid date.
+    /// assert_eq!(
+    ///     ParsiDate::parse_partial_report("1404/12/30", "%Y/%m/%d"),
+    ///     (Some(1404), Some(12), Some(30), Some(DateError::ParseError(ParseErrorKind::InvalidDateValue)))
+    /// );
+    ///
+    /// // Success: no error is reported.
+    /// assert_eq!(
+    ///     ParsiDate::parse_partial_report("1403/05/02", "%Y/%m/%d"),
+    ///     (Some(1403), Some(5), Some(2), None)
+    /// );
+    /// ```
+    pub fn parse_partial_report(
+        s: &str,
+        format: &str,
+    ) -> (Option<i32>, Option<u32>, Option<u32>, Option<DateError>) {
+        let mut parsed_year: Option<i32> = None;
+        let mut parsed_month: Option<u32> = None;
+        let mut parsed_day: Option<u32> = None;
+
+        let error = Self::scan_components(
+            s,
+            format,
+            &mut parsed_year,
+            &mut parsed_month,
+            &mut parsed_day,
+        )
+        .map_err(DateError::from)
+        .and_then(|()| Self::from_scanned_components(parsed_year, parsed_month, parsed_day))
+        .err();
+        (parsed_year, parsed_month, parsed_day, error)
+    }
+
+    /// Parses a date string like [`parse`](#method.parse), reporting where in the input matching
+    /// failed.
+    ///
+    /// This is intended for rich error reporting in editors and forms: the returned position
+    /// makes it possible to underline the offending part of the input rather than only naming
+    /// the category of the failure.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The input string slice containing the date to be parsed.
+    /// * `format`: The format string, with the same specifiers as [`parse`](#method.parse).
     ///
     /// # Errors
     ///
-    /// Returns `Err(DateError::ParseError(kind))` if parsing fails. The `kind` ([`ParseErrorKind`]) provides details:
-    /// *   `ParseErrorKind::FormatMismatch`: The input string `s` does not structurally match the `format` string (e.g., wrong separators, missing components, extra trailing characters).
-    /// *   `ParseErrorKind::InvalidNumber`: A numeric component (`%Y`, `%m`, `%d`) could not be parsed as a number, or it did not contain the required number of digits (4 for `%Y`, 2 for `%m`/`%d`).
-    /// *   `ParseErrorKind::InvalidMonthName`: The input string did not contain a valid, recognized Persian month name where `%B` was expected in the format.
-    /// *   `ParseErrorKind::UnsupportedSpecifier`: The `format` string included a specifier not supported for parsing (e.g., `%A`, `%j`, `%K`).
-    /// *   `ParseErrorKind::InvalidDateValue`: The year, month, and day values were successfully extracted according to the format, but they do not form a logically valid Persian date (e.g., "1404/12/30" where 1404 is not a leap year; "1403/07/31" where Mehr has only 30 days). This is checked by the final internal call to `ParsiDate::new`.
+    /// Returns a [`LocatedParseError`] whose `kind` is the [`ParseErrorKind`] that
+    /// [`parse`](#method.parse) would report and whose `position` is the byte offset into `s` at
+    /// which matching failed. For `ParseErrorKind::InvalidDateValue` the position is `0`, since
+    /// the problem lies in the combination of the components. If `format` lacks one of the year,
+    /// month or day, the error is `FormatMismatch` at the end of the input.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use parsidate::{ParsiDate, DateError, ParseErrorKind};
+    /// use parsidate::{LocatedParseError, ParseErrorKind, ParsiDate};
+    ///
+    /// assert_eq!(ParsiDate::parse_located("1403/05/02", "%Y/%m/%d"), Ok(ParsiDate::new(1403, 5, 2).unwrap()));
+    ///
+    /// // The day starts at byte 8.
+    /// assert_eq!(
+    ///     ParsiDate::parse_located("1403/05/x2", "%Y/%m/%d"),
+    ///     Err(LocatedParseError { kind: ParseErrorKind::InvalidNumber, position: 8 })
+    /// );
+    /// // Wrong separator at byte 4.
+    /// assert_eq!(
+    ///     ParsiDate::parse_located("1403-05-02", "%Y/%m/%d"),
+    ///     Err(LocatedParseError { kind: ParseErrorKind::FormatMismatch, position: 4 })
+    /// );
+    /// // Positions are byte offsets, so a Persian month name (10 bytes) moves them accordingly.
+    /// assert_eq!(
+    ///     ParsiDate::parse_located("02 مرداد 14x3", "%d %B %Y"),
+    ///     Err(LocatedParseError { kind: ParseErrorKind::InvalidNumber, position: 14 })
+    /// );
+    /// ```
+    pub fn parse_located(s: &str, format: &str) -> Result<Self, LocatedParseError> {
+        let mut parsed_year: Option<i32> = None;
+        let mut parsed_month: Option<u32> = None;
+        let mut parsed_day: Option<u32> = None;
+
+        Self::scan_components(
+            s,
+            format,
+            &mut parsed_year,
+            &mut parsed_month,
+            &mut parsed_day,
+        )?;
+        Self::from_scanned_components(parsed_year, parsed_month, parsed_day).map_err(|e| match e {
+            // A component missing from the format: the input ended without providing it.
+            DateError::ParseError(ParseErrorKind::FormatMismatch) => LocatedParseError {
+                kind: ParseErrorKind::FormatMismatch,
+                position: s.len(),
+            },
+            _ => LocatedParseError {
+                kind: ParseErrorKind::InvalidDateValue,
+                position: 0,
+            },
+        })
+    }
+
+    /// Parses `s` and updates only the components of `self` present in `format`.
     ///
-    /// // --- Success Cases ---
-    /// assert_eq!(ParsiDate::parse("1403/05/02", "%Y/%m/%d"), Ok(ParsiDate::new(1403, 5, 2).unwrap()));
-    /// assert_eq!(ParsiDate::parse("1399-12-30", "%Y-%m-%d"), Ok(ParsiDate::new(1399, 12, 30).unwrap()));
-    /// assert_eq!(ParsiDate::parse("02 مرداد 1403", "%d %B %Y"), Ok(ParsiDate::new(1403, 5, 2).unwrap()));
+    /// Components missing from `format` keep their current values, so starting from some date,
+    /// parsing `"05"` with `"%m"` changes only the month. This supports date-field editing where
+    /// fields arrive separately. The combined date is validated before anything is changed: on
+    /// error, `self` is left untouched.
     ///
-    /// // --- Error Cases ---
-    /// assert_eq!(ParsiDate::parse("1403-05-02", "%Y/%m/%d"), Err(DateError::ParseError(ParseErrorKind::FormatMismatch)));
-    /// assert_eq!(ParsiDate::parse("1403/05/02 extra", "%Y/%m/%d"), Err(DateError::ParseError(ParseErrorKind::FormatMismatch)));
-    /// assert_eq!(ParsiDate::parse("1403/05/2", "%Y/%m/%d"), Err(DateError::ParseError(ParseErrorKind::InvalidNumber)));
-    /// assert_eq!(ParsiDate::parse("abcd/05/02", "%Y/%m/%d"), Err(DateError::ParseError(ParseErrorKind::InvalidNumber)));
-    /// assert_eq!(ParsiDate::parse("1404/12/30", "%Y/%m/%d"), Err(DateError::ParseError(ParseErrorKind::InvalidDateValue)));
-    /// assert_eq!(ParsiDate::parse("Tuesday 1403", "%A %Y"), Err(DateError::ParseError(ParseErrorKind::UnsupportedSpecifier)));
-    /// assert_eq!(ParsiDate::parse("Summer 1403", "%K %Y"), Err(DateError::ParseError(ParseErrorKind::UnsupportedSpecifier))); // %K not supported for parsing
+    /// # Arguments
+    ///
+    /// * `s`: The input string slice containing the components to apply.
+    /// * `format`: The format string, with the same specifiers as [`parse`](#method.parse). It may
+    ///   contain any subset of the year, month and day.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same parse errors as [`parse`](#method.parse) if `s` does not match `format`,
+    /// or `Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))` if the updated
+    /// components do not form a valid date (e.g., setting day 31 in Mehr).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParseErrorKind, ParsiDate};
+    ///
+    /// let mut date = ParsiDate::new(1403, 1, 31).unwrap();
+    /// date.parse_into("05", "%m").unwrap();
+    /// assert_eq!(date, ParsiDate::new(1403, 5, 31).unwrap());
+    ///
+    /// date.parse_into("1404/02", "%Y/%d").unwrap();
+    /// assert_eq!(date, ParsiDate::new(1404, 5, 2).unwrap());
+    ///
+    /// // Mehr has 30 days: the update is rejected and the date is unchanged.
+    /// let mut date = ParsiDate::new(1403, 1, 31).unwrap();
+    /// assert_eq!(
+    ///     date.parse_into("07", "%m"),
+    ///     Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    /// );
+    /// assert_eq!(date, ParsiDate::new(1403, 1, 31).unwrap());
     /// ```
-    pub fn parse(s: &str, format: &str) -> Result<Self, DateError> {
-        // Options to store the parsed components. They start as None.
+    pub fn parse_into(&mut self, s: &str, format: &str) -> Result<(), DateError> {
         let mut parsed_year: Option<i32> = None;
         let mut parsed_month: Option<u32> = None;
         let mut parsed_day: Option<u32> = None;
 
+        Self::scan_components(
+            s,
+            format,
+            &mut parsed_year,
+            &mut parsed_month,
+            &mut parsed_day,
+        )?;
+        // Fill in the components not present in the format from the current date.
+        *self = Self::from_scanned_components(
+            parsed_year.or(Some(self.year)),
+            parsed_month.or(Some(self.month)),
+            parsed_day.or(Some(self.day)),
+        )?;
+        Ok(())
+    }
+
+    /// **Internal**: Scans `s` against `format`, storing each component in its `Option` as soon as
+    /// it is extracted, so the caller still sees the extracted components when an error is returned.
+    /// Errors carry the byte offset into `s` at which matching failed.
+    fn scan_components(
+        s: &str,
+        format: &str,
+        parsed_year: &mut Option<i32>,
+        parsed_month: &mut Option<u32>,
+        parsed_day: &mut Option<u32>,
+    ) -> Result<(), LocatedParseError> {
         // Use byte slices for efficient processing where possible (ASCII parts).
         // We need to handle the input string `s` as potentially UTF-8 when parsing %B.
         let mut s_bytes = s.as_bytes();
         let mut fmt_bytes = format.as_bytes();
+        // Builds an error located at the start of the unconsumed input `rest`.
+        let error_at = |kind, rest: &[u8]| LocatedParseError {
+            kind,
+            position: s.len() - rest.len(),
+        };
 
         // Iterate through the format string bytes
         while !fmt_bytes.is_empty() {
@@ -1301,7 +3284,7 @@ impl ParsiDate {
             if fmt_bytes[0] == b'%' {
                 // Ensure there's a character after '%'
                 if fmt_bytes.len() < 2 {
-                    return Err(DateError::ParseError(ParseErrorKind::FormatMismatch));
+                    return Err(error_at(ParseErrorKind::FormatMismatch, s_bytes));
                     // Dangling %
                 }
 
@@ -1311,102 +3294,104 @@ impl ParsiDate {
                     b'%' => {
                         // Input must also start with '%'
                         if s_bytes.is_empty() || s_bytes[0] != b'%' {
-                            return Err(DateError::ParseError(ParseErrorKind::FormatMismatch));
+                            return Err(error_at(ParseErrorKind::FormatMismatch, s_bytes));
                         }
                         // Consume '%' from input and '%%' from format
                         s_bytes = &s_bytes[1..];
                         fmt_bytes = &fmt_bytes[2..];
                     }
-                    // --- Year '%Y' (expects 4 digits) ---
-                    b'Y' => {
+                    // --- Year '%Y' or '%4Y' (expects exactly 4 digits) ---
+                    b'Y' | b'4' if fmt_bytes[1] == b'Y' || fmt_bytes.get(2) == Some(&b'Y') => {
                         // Check for 4 ASCII digits
                         if s_bytes.len() < 4 || !s_bytes[0..4].iter().all(|b| b.is_ascii_digit()) {
-                            return Err(DateError::ParseError(ParseErrorKind::InvalidNumber));
+                            return Err(error_at(ParseErrorKind::InvalidNumber, s_bytes));
                         }
                         // Parse the 4 digits (unsafe from_utf8 is safe here)
                         let year_str = unsafe { std::str::from_utf8_unchecked(&s_bytes[0..4]) };
-                        parsed_year = Some(year_str.parse().map_err(|_| {
-                            DateError::ParseError(ParseErrorKind::InvalidNumber)
+                        *parsed_year = Some(year_str.parse().map_err(|_| {
+                            error_at(ParseErrorKind::InvalidNumber, s_bytes)
                             // Should not fail, but handle defensively
                         })?);
-                        // Consume 4 digits from input and '%Y' from format
+                        // Consume 4 digits from input and '%Y' (or '%4Y') from format
                         s_bytes = &s_bytes[4..];
-                        fmt_bytes = &fmt_bytes[2..];
+                        fmt_bytes = &fmt_bytes[if fmt_bytes[1] == b'4' { 3 } else { 2 }..];
                     }
                     // --- Month '%m' or Day '%d' (expects 2 digits) ---
                     b'm' | b'd' => {
                         // Check for 2 ASCII digits
                         if s_bytes.len() < 2 || !s_bytes[0..2].iter().all(|b| b.is_ascii_digit()) {
-                            return Err(DateError::ParseError(ParseErrorKind::InvalidNumber));
+                            return Err(error_at(ParseErrorKind::InvalidNumber, s_bytes));
                         }
                         // Parse the 2 digits (unsafe from_utf8 is safe)
                         let num_str = unsafe { std::str::from_utf8_unchecked(&s_bytes[0..2]) };
                         let val: u32 = num_str
                             .parse()
-                            .map_err(|_| DateError::ParseError(ParseErrorKind::InvalidNumber))?;
+                            .map_err(|_| error_at(ParseErrorKind::InvalidNumber, s_bytes))?;
 
                         // Store in the correct Option based on the specifier
                         if fmt_bytes[1] == b'm' {
-                            parsed_month = Some(val);
+                            *parsed_month = Some(val);
                         } else {
                             // fmt_bytes[1] == b'd'
-                            parsed_day = Some(val);
+                            *parsed_day = Some(val);
                         }
                         // Consume 2 digits from input and '%m' or '%d' from format
                         s_bytes = &s_bytes[2..];
                         fmt_bytes = &fmt_bytes[2..];
                     }
-                    // --- Month Name '%B' (expects Persian name) ---
-                    b'B' => {
-                        // Consume '%B' from format first
-                        fmt_bytes = &fmt_bytes[2..];
-                        let mut found_month = false;
-                        let mut best_match_len = 0; // Length in *bytes* of the matched name
-                        let mut matched_month_idx = 0; // 0-based index
-
-                        // Need to compare against the input string slice `s` for UTF-8 names.
-                        // Convert the *remaining* input bytes slice `s_bytes` to `&str` for matching.
-                        let current_s_str = match std::str::from_utf8(s_bytes) {
-                            Ok(s_str) => s_str,
-                            // If remaining input isn't valid UTF-8, it can't match a Persian name.
-                            Err(_) => {
-                                return Err(DateError::ParseError(
-                                    ParseErrorKind::InvalidMonthName,
-                                ));
+                    // --- Non-padded '%-Y' (1-4 digits), '%-m' and '%-d' (1-2 digits) ---
+                    b'-' => {
+                        let (field, max_digits) = match fmt_bytes.get(2) {
+                            Some(b'Y') => (b'Y', 4),
+                            Some(&f @ (b'm' | b'd')) => (f, 2),
+                            Some(_) => {
+                                return Err(error_at(ParseErrorKind::UnsupportedSpecifier, s_bytes))
                             }
+                            None => return Err(error_at(ParseErrorKind::FormatMismatch, s_bytes)),
                         };
-
-                        // Iterate through the known Persian month names
-                        for (idx, month_name) in MONTH_NAMES_PERSIAN.iter().enumerate() {
-                            // Check if the input string starts with this month name (case-sensitive)
-                            if current_s_str.starts_with(month_name) {
-                                // Found a match. Store its details.
-                                best_match_len = month_name.len(); // Get byte length for slicing
-                                matched_month_idx = idx;
-                                found_month = true;
-                                break; // Stop searching after the first match
-                            }
+                        let (val, consumed) =
+                            Self::parse_variable_width_number(s_bytes, 1, max_digits)
+                                .map_err(|_| error_at(ParseErrorKind::InvalidNumber, s_bytes))?;
+                        match field {
+                            b'Y' => *parsed_year = Some(val as i32),
+                            b'm' => *parsed_month = Some(val),
+                            _ => *parsed_day = Some(val),
                         }
-
-                        if !found_month {
-                            // No month name matched at the current input position.
-                            return Err(DateError::ParseError(ParseErrorKind::InvalidMonthName));
+                        s_bytes = &s_bytes[consumed..];
+                        fmt_bytes = &fmt_bytes[3..];
+                    }
+                    // --- Era Marker '%Ee' (expects "ه.ش") ---
+                    b'E' if fmt_bytes.get(2) == Some(&b'e') => {
+                        if !s_bytes.starts_with(ERA_MARKER_PERSIAN.as_bytes()) {
+                            return Err(error_at(ParseErrorKind::FormatMismatch, s_bytes));
                         }
+                        s_bytes = &s_bytes[ERA_MARKER_PERSIAN.len()..];
+                        fmt_bytes = &fmt_bytes[3..];
+                    }
+                    // --- Month Name '%B' (expects Persian name) ---
+                    b'B' => {
+                        // Longest-prefix match against the known month names. Only the bytes
+                        // that can belong to a name are inspected, so the scan is bounded
+                        // regardless of how long the remaining input is.
+                        let (matched_month_idx, matched_len) =
+                            Self::match_name_prefix(s_bytes, &MONTH_NAMES_PERSIAN)
+                                .ok_or(error_at(ParseErrorKind::InvalidMonthName, s_bytes))?;
 
                         // Store the parsed month number (1-based index)
-                        parsed_month = Some((matched_month_idx + 1) as u32);
-                        // Consume the matched month name (by its byte length) from the input byte slice.
-                        s_bytes = &s_bytes[best_match_len..];
-                        // `fmt_bytes` was already advanced past '%B'.
+                        *parsed_month = Some((matched_month_idx + 1) as u32);
+                        // Consume the matched month name (by its byte length) from the input
+                        // and '%B' from the format.
+                        s_bytes = &s_bytes[matched_len..];
+                        fmt_bytes = &fmt_bytes[2..];
                     }
                     // --- Unsupported Specifiers for Parsing ---
                     b'A' | b'w' | b'j' | b'K' | b'W' => {
                         // Includes any other byte
                         // Specifiers like weekday, ordinal day, season are not supported for parsing.
-                        return Err(DateError::ParseError(ParseErrorKind::UnsupportedSpecifier));
+                        return Err(error_at(ParseErrorKind::UnsupportedSpecifier, s_bytes));
                     }
                     _ => {
-                        return Err(DateError::ParseError(ParseErrorKind::UnsupportedSpecifier));
+                        return Err(error_at(ParseErrorKind::UnsupportedSpecifier, s_bytes));
                     }
                 }
             } else {
@@ -1414,7 +3399,7 @@ impl ParsiDate {
                 // Input must have the same literal character at the current position.
                 if s_bytes.is_empty() || s_bytes[0] != fmt_bytes[0] {
                     // Input is shorter, or characters don't match.
-                    return Err(DateError::ParseError(ParseErrorKind::FormatMismatch));
+                    return Err(error_at(ParseErrorKind::FormatMismatch, s_bytes));
                 }
                 // Consume the matching literal character from both input and format.
                 s_bytes = &s_bytes[1..];
@@ -1425,9 +3410,17 @@ impl ParsiDate {
         // After processing the entire format string, check if there are any unconsumed characters left in the input.
         if !s_bytes.is_empty() {
             // Input string has extra characters not accounted for by the format.
-            return Err(DateError::ParseError(ParseErrorKind::FormatMismatch));
+            return Err(error_at(ParseErrorKind::FormatMismatch, s_bytes));
         }
+        Ok(())
+    }
 
+    /// **Internal**: Builds the final date from scanned components, validating it logically.
+    fn from_scanned_components(
+        parsed_year: Option<i32>,
+        parsed_month: Option<u32>,
+        parsed_day: Option<u32>,
+    ) -> Result<Self, DateError> {
         // Check if all necessary components (year, month, day) were successfully parsed from the input.
         match (parsed_year, parsed_month, parsed_day) {
             (Some(y), Some(m), Some(d)) => {
@@ -1449,6 +3442,309 @@ impl ParsiDate {
         }
     }
 
+    /// Parses a date given either as a relative keyword or as an explicit date string.
+    ///
+    /// The following keywords are recognized (surrounding whitespace is ignored, and the
+    /// English keywords are matched case-insensitively):
+    ///
+    /// | Keyword               | Meaning                     |
+    /// | :-------------------- | :-------------------------- |
+    /// | `امروز` / `today`     | [`ParsiDate::today()`]      |
+    /// | `دیروز` / `yesterday` | One day before today        |
+    /// | `فردا` / `tomorrow`   | One day after today         |
+    ///
+    /// Any other input is treated as an explicit date and parsed with [`parse`](#method.parse),
+    /// trying the formats `"%Y/%m/%d"`, `"%Y-%m-%d"` and `"%d %B %Y"` in that order.
+    ///
+    /// **Note:** Keyword results depend on the system clock and local timezone, exactly like
+    /// [`ParsiDate::today()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The keyword or date string to parse.
+    ///
+    /// # Errors
+    ///
+    /// *   Errors from [`ParsiDate::today()`] or [`add_days`](#method.add_days) when resolving a keyword.
+    /// *   `DateError::ParseError(kind)` if `s` is neither a keyword nor a date in one of the accepted
+    ///     formats. The first error other than `FormatMismatch` is reported (e.g., `InvalidDateValue`
+    ///     for "1404-12-30"), so `FormatMismatch` is only returned if every format failed structurally.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, DateError, ParseErrorKind};
+    ///
+    /// let today = ParsiDate::today().unwrap();
+    /// assert_eq!(ParsiDate::parse_relative("امروز"), Ok(today));
+    /// assert_eq!(ParsiDate::parse_relative(" Tomorrow "), today.add_days(1));
+    /// assert_eq!(ParsiDate::parse_relative("دیروز"), today.sub_days(1));
+    ///
+    /// // Explicit dates in any of the accepted formats
+    /// let expected = ParsiDate::new(1403, 5, 2).unwrap();
+    /// assert_eq!(ParsiDate::parse_relative("1403/05/02"), Ok(expected));
+    /// assert_eq!(ParsiDate::parse_relative("1403-05-02"), Ok(expected));
+    /// assert_eq!(ParsiDate::parse_relative("02 مرداد 1403"), Ok(expected));
+    ///
+    /// assert_eq!(
+    ///     ParsiDate::parse_relative("1404-12-30"),
+    ///     Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    /// );
+    /// assert_eq!(
+    ///     ParsiDate::parse_relative("next week"),
+    ///     Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    /// );
+    /// ```
+    pub fn parse_relative(s: &str) -> Result<Self, DateError> {
+        let trimmed = s.trim();
+        let offset_days = match trimmed {
+            "امروز" => Some(0),
+            "دیروز" => Some(-1),
+            "فردا" => Some(1),
+            _ if trimmed.eq_ignore_ascii_case("today") => Some(0),
+            _ if trimmed.eq_ignore_ascii_case("yesterday") => Some(-1),
+            _ if trimmed.eq_ignore_ascii_case("tomorrow") => Some(1),
+            _ => None,
+        };
+        if let Some(days) = offset_days {
+            return Self::today()?.add_days(days);
+        }
+
+        // Not a keyword: try the explicit formats in order, keeping the most specific error.
+        let mut error = DateError::ParseError(ParseErrorKind::FormatMismatch);
+        for format in ["%Y/%m/%d", "%Y-%m-%d", "%d %B %Y"] {
+            match Self::parse(trimmed, format) {
+                Ok(date) => return Ok(date),
+                Err(e) => {
+                    if error == DateError::ParseError(ParseErrorKind::FormatMismatch) {
+                        error = e;
+                    }
+                }
+            }
+        }
+        Err(error)
+    }
+
+    /// The smallest year at which [`parse_auto_calendar`](#method.parse_auto_calendar) treats
+    /// an input as Gregorian rather than Persian.
+    ///
+    /// Present-day Persian years are in the 1300s-1400s while Gregorian years are in the
+    /// 1900s-2000s, so any year from 1700 upwards is considered Gregorian.
+    pub const AUTO_CALENDAR_GREGORIAN_THRESHOLD: i32 = 1700;
+
+    /// Parses a numeric date that may be written in either the Persian or the Gregorian calendar.
+    ///
+    /// This is a convenience wrapper around [`parse_auto_calendar_typed`](#method.parse_auto_calendar_typed)
+    /// that discards the detected calendar. See that method for the accepted input and detection rule.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`parse_auto_calendar_typed`](#method.parse_auto_calendar_typed).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let expected = ParsiDate::new(1403, 5, 2).unwrap();
+    /// assert_eq!(ParsiDate::parse_auto_calendar("1403/05/02"), Ok(expected));
+    /// assert_eq!(ParsiDate::parse_auto_calendar("2024-07-23"), Ok(expected));
+    /// ```
+    pub fn parse_auto_calendar(s: &str) -> Result<Self, DateError> {
+        Self::parse_auto_calendar_typed(s).map(|(date, _)| date)
+    }
+
+    /// Parses a numeric date that may be written in either calendar and reports which one was detected.
+    ///
+    /// The input must have the form `YYYY/MM/DD` or `YYYY-MM-DD` (surrounding whitespace is ignored).
+    /// The calendar is detected from the year: years below
+    /// [`AUTO_CALENDAR_GREGORIAN_THRESHOLD`](#associatedconstant.AUTO_CALENDAR_GREGORIAN_THRESHOLD) (1700)
+    /// are read as Persian, and years at or above it are read as Gregorian and converted.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The date string to parse.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `ParsiDate` together with the [`DetectedCalendar`] used to interpret the input.
+    ///
+    /// # Errors
+    ///
+    /// *   `DateError::ParseError(ParseErrorKind::FormatMismatch)`: The input does not have the expected shape.
+    /// *   `DateError::ParseError(ParseErrorKind::InvalidNumber)`: A component is not made of ASCII digits.
+    /// *   `DateError::ParseError(ParseErrorKind::InvalidDateValue)`: The components do not form a valid date
+    ///     in the detected calendar.
+    /// *   Errors from [`from_gregorian`](#method.from_gregorian) for Gregorian inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, DetectedCalendar, ParseErrorKind, ParsiDate};
+    ///
+    /// let expected = ParsiDate::new(1403, 5, 2).unwrap();
+    /// assert_eq!(
+    ///     ParsiDate::parse_auto_calendar_typed("1403/05/02"),
+    ///     Ok((expected, DetectedCalendar::Persian))
+    /// );
+    /// assert_eq!(
+    ///     ParsiDate::parse_auto_calendar_typed("2024/07/23"),
+    ///     Ok((expected, DetectedCalendar::Gregorian))
+    /// );
+    ///
+    /// // February 30th is not a valid Gregorian date
+    /// assert_eq!(
+    ///     ParsiDate::parse_auto_calendar_typed("2024-02-30"),
+    ///     Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    /// );
+    /// ```
+    pub fn parse_auto_calendar_typed(s: &str) -> Result<(Self, DetectedCalendar), DateError> {
+        let bytes = s.trim().as_bytes();
+        // Expected shape: YYYY?MM?DD where '?' is the same separator ('/' or '-') twice.
+        if bytes.len() != 10 || !matches!(bytes[4], b'/' | b'-') || bytes[7] != bytes[4] {
+            return Err(DateError::ParseError(ParseErrorKind::FormatMismatch));
+        }
+        // Each field must consist of exactly `width` ASCII digits.
+        let number = |start: usize, width: usize| -> Result<u32, DateError> {
+            Self::parse_variable_width_number(&bytes[start..start + width], width, width)
+                .map(|(value, _)| value)
+        };
+        let year = number(0, 4)? as i32;
+        let month = number(5, 2)?;
+        let day = number(8, 2)?;
+
+        if year < Self::AUTO_CALENDAR_GREGORIAN_THRESHOLD {
+            let date = ParsiDate::new(year, month, day)
+                .map_err(|_| DateError::ParseError(ParseErrorKind::InvalidDateValue))?;
+            Ok((date, DetectedCalendar::Persian))
+        } else {
+            let gregorian = NaiveDate::from_ymd_opt(year, month, day)
+                .ok_or(DateError::ParseError(ParseErrorKind::InvalidDateValue))?;
+            Ok((
+                Self::from_gregorian(gregorian)?,
+                DetectedCalendar::Gregorian,
+            ))
+        }
+    }
+
+    /// Infers whether a column of numeric date strings is year-first or day-first.
+    ///
+    /// Data exported from spreadsheets with a Persian locale sometimes uses `DD/MM/YYYY` instead of
+    /// `YYYY/MM/DD`. Each sample is split into three numeric fields on its separator (`/` or `-`);
+    /// a leading field greater than 31 marks the sample as year-first, and a trailing field greater
+    /// than 31 marks it as day-first. Samples that are ambiguous (no field exceeds 31) or not
+    /// numeric are ignored.
+    ///
+    /// The returned format string can be passed directly to [`parse`](#method.parse).
+    ///
+    /// # Arguments
+    ///
+    /// * `samples`: A sample of date strings from the same column.
+    ///
+    /// # Returns
+    ///
+    /// *   `Some("%Y/%m/%d")`, `Some("%Y-%m-%d")`, `Some("%d/%m/%Y")` or `Some("%d-%m-%Y")` if all
+    ///     conclusive samples agree on both the field order and the separator.
+    /// *   `None` if no sample is conclusive, or if the conclusive samples disagree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let column = ["02/05/1403", "15/11/1402", "not a date"];
+    /// let format = ParsiDate::detect_field_order(&column).unwrap();
+    /// assert_eq!(format, "%d/%m/%Y");
+    /// assert_eq!(ParsiDate::parse(column[0], format), Ok(ParsiDate::new(1403, 5, 2).unwrap()));
+    ///
+    /// assert_eq!(ParsiDate::detect_field_order(&["1403-05-02"]), Some("%Y-%m-%d"));
+    /// assert_eq!(ParsiDate::detect_field_order(&["1403/05/02", "02/05/1403"]), None); // Conflicting
+    /// assert_eq!(ParsiDate::detect_field_order(&["01/02/03"]), None); // Ambiguous
+    /// ```
+    pub fn detect_field_order(samples: &[&str]) -> Option<&'static str> {
+        let mut detected: Option<&'static str> = None;
+        for sample in samples {
+            let sample = sample.trim();
+            let separator = match sample.find(['/', '-']) {
+                Some(idx) => &sample[idx..idx + 1],
+                None => continue,
+            };
+            let fields: Vec<u32> = match sample
+                .split(separator)
+                .map(|field| field.parse::<u32>().ok())
+                .collect::<Option<Vec<u32>>>()
+            {
+                Some(fields) if fields.len() == 3 => fields,
+                _ => continue,
+            };
+            let format = match (fields[0] > 31, fields[2] > 31, separator) {
+                (true, false, "/") => "%Y/%m/%d",
+                (true, false, _) => "%Y-%m-%d",
+                (false, true, "/") => "%d/%m/%Y",
+                (false, true, _) => "%d-%m-%Y",
+                _ => continue, // Ambiguous sample
+            };
+            match detected {
+                Some(previous) if previous != format => return None,
+                _ => detected = Some(format),
+            }
+        }
+        detected
+    }
+
+    /// **Internal**: Finds the longest name in `names` that is a prefix of `s_bytes`.
+    ///
+    /// Used by the `%B` parser. Comparison is done on raw bytes, so at most the length of the
+    /// longest candidate name is inspected and the remaining input never needs to be validated
+    /// as UTF-8. Choosing the longest match ensures that a name which happens to be a prefix of
+    /// another (e.g., a short form and a long form) does not shadow the longer one.
+    ///
+    /// Returns the 0-based index of the matched name and its length in bytes, or `None` if no
+    /// name matches.
+    pub(crate) fn match_name_prefix(s_bytes: &[u8], names: &[&str]) -> Option<(usize, usize)> {
+        names
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| !name.is_empty() && s_bytes.starts_with(name.as_bytes()))
+            .map(|(idx, name)| (idx, name.len()))
+            // On equal lengths (identical names) keep the first entry.
+            .fold(None, |best: Option<(usize, usize)>, candidate| match best {
+                Some((_, best_len)) if best_len >= candidate.1 => best,
+                _ => Some(candidate),
+            })
+    }
+
+    /// **Internal**: Consumes a run of ASCII digits from the start of `s_bytes` for non-padded
+    /// parsing specifiers (e.g., `%-H`).
+    ///
+    /// Reads at least `min_digits` and at most `max_digits` leading ASCII digits, stopping at the
+    /// first non-digit byte. Returns the parsed value together with the number of bytes consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::ParseError(ParseErrorKind::InvalidNumber))` if fewer than
+    /// `min_digits` digits are present or the digits cannot be parsed as a `u32`.
+    pub(crate) fn parse_variable_width_number(
+        s_bytes: &[u8],
+        min_digits: usize,
+        max_digits: usize,
+    ) -> Result<(u32, usize), DateError> {
+        let digit_count = s_bytes
+            .iter()
+            .take(max_digits)
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if digit_count < min_digits {
+            return Err(DateError::ParseError(ParseErrorKind::InvalidNumber));
+        }
+        // The slice contains only ASCII digits, so it is valid UTF-8.
+        let num_str = unsafe { std::str::from_utf8_unchecked(&s_bytes[..digit_count]) };
+        let value = num_str
+            .parse()
+            .map_err(|_| DateError::ParseError(ParseErrorKind::InvalidNumber))?;
+        Ok((value, digit_count))
+    }
+
     // --- Date Information ---
 
     /// Returns the full Persian name of the weekday for this date (e.g., "شنبه", "یکشنبه", "دوشنبه", ...).
@@ -1490,6 +3786,85 @@ impl ParsiDate {
         self.weekday_internal()
     }
 
+    /// Returns the weekday of this date as a [`ParsiWeekday`], for type-safe matching.
+    ///
+    /// [`weekday`](#method.weekday) returns the Persian name of the same day, i.e.
+    /// `weekday_enum()?.name_persian()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`weekday`](#method.weekday).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, ParsiWeekday};
+    ///
+    /// let date = ParsiDate::new(1403, 5, 2).unwrap(); // 2024-07-23, a Tuesday
+    /// assert_eq!(date.weekday_enum(), Ok(ParsiWeekday::Seshanbeh));
+    ///
+    /// match date.weekday_enum().unwrap() {
+    ///     ParsiWeekday::Panjshanbeh | ParsiWeekday::Jomeh => println!("weekend"),
+    ///     day => println!("{} is a working day", day.name_english()),
+    /// }
+    ///
+    /// let invalid_date = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    /// assert!(invalid_date.weekday_enum().is_err());
+    /// ```
+    pub fn weekday_enum(&self) -> Result<ParsiWeekday, DateError> {
+        let day_num_sat_0 = self.weekday_num_sat_0()?; // Validates the date
+        ParsiWeekday::from_num_sat_0(day_num_sat_0).ok_or(DateError::GregorianConversionError)
+    }
+
+    /// Returns `true` if this date falls on Friday (جمعه), the weekly holiday in Iran.
+    ///
+    /// Use [`is_weekend_with`](#method.is_weekend_with) for other conventions, such as treating
+    /// Thursday and Friday as the weekend.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`weekday`](#method.weekday).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// assert_eq!(ParsiDate::new(1403, 1, 10).unwrap().is_weekend(), Ok(true)); // Friday
+    /// assert_eq!(ParsiDate::new(1403, 1, 9).unwrap().is_weekend(), Ok(false)); // Thursday
+    ///
+    /// let invalid_date = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    /// assert!(invalid_date.is_weekend().is_err());
+    /// ```
+    pub fn is_weekend(&self) -> Result<bool, DateError> {
+        self.is_weekend_with(&[ParsiWeekday::Jomeh])
+    }
+
+    /// Returns `true` if this date falls on one of the given weekdays.
+    ///
+    /// # Arguments
+    ///
+    /// * `days`: The weekdays counted as the weekend. An empty slice never matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`weekday`](#method.weekday).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, ParsiWeekday};
+    ///
+    /// let thursday_and_friday = [ParsiWeekday::Panjshanbeh, ParsiWeekday::Jomeh];
+    /// let thursday = ParsiDate::new(1403, 1, 9).unwrap();
+    /// assert_eq!(thursday.is_weekend_with(&thursday_and_friday), Ok(true));
+    /// assert_eq!(thursday.is_weekend_with(&[ParsiWeekday::Jomeh]), Ok(false));
+    /// ```
+    pub fn is_weekend_with(&self, days: &[ParsiWeekday]) -> Result<bool, DateError> {
+        let weekday = self.weekday_num_sat_0()?;
+        Ok(days.iter().any(|day| day.num_sat_0() == weekday))
+    }
+
     /// **Internal**: Calculates and returns the Persian weekday name. Includes validation.
     ///
     /// This helper exists to share logic and ensures validation occurs before calculation.
@@ -1500,17 +3875,9 @@ impl ParsiDate {
         if !self.is_valid() {
             return Err(DateError::InvalidDate);
         }
-        // 2. Calculate the numerical weekday (Saturday=0, ..., Friday=6).
-        // This can return an error if Gregorian conversion fails.
-        let day_num_sat_0 = self.weekday_num_sat_0()?;
-        // 3. Get the name from the constant array using the calculated index.
-        // This indexing should be safe (0-6) if weekday_num_sat_0 returned Ok.
-        WEEKDAY_NAMES_PERSIAN
-            .get(day_num_sat_0 as usize) // Convert u32 index to usize
-            .map(|s| s.to_string()) // Convert the found &str to String
-            // If `get` somehow fails (e.g., index out of bounds, which shouldn't happen here),
-            // map it to a relevant error type. GregorianConversionError implies something went wrong in the process.
-            .ok_or(DateError::GregorianConversionError)
+        // 2. Calculate the typed weekday and take its Persian name.
+        self.weekday_enum()
+            .map(|day| day.name_persian().to_string())
     }
 
     /// **Internal**: Calculates the weekday as a number (Saturday=0, ..., Friday=6). Includes validation.
@@ -1600,31 +3967,149 @@ impl ParsiDate {
             return Err(DateError::InvalidDate);
         }
 
-        // 2. Get the lengths of all months for the current year.
-        let month_lengths = Self::month_lengths(self.year);
-        let mut accumulated_days: u32 = 0;
-
-        // 3. Sum the lengths of all full months *preceding* the current month.
-        // `self.month` is 1-based. Loop from index 0 up to `self.month - 2`.
-        if self.month > 1 {
-            // Iterate through the lengths of months from Farvardin up to the one before self.month.
-            // Example: If self.month is 3 (Khordad), loop runs for indices 0 (Far) and 1 (Ord).
-            for days in month_lengths.iter().take((self.month - 1) as usize) {
-                // Use checked_add for safety against potential u32 overflow (very unlikely here).
-                accumulated_days = accumulated_days
-                    .checked_add(*days)
-                    .ok_or(DateError::ArithmeticOverflow)?;
-            }
+        // 2. Look up the number of days in the months *preceding* the current month.
+        // `self.month` is 1-based and within 1..=12 after validation.
+        let month_offset = Self::month_start_offsets(self.year)[(self.month - 1) as usize];
+
+        // 3. Add the day of the current month. `self.day` is 1-based, so adding it directly
+        // gives the correct 1-based ordinal day.
+        month_offset
+            .checked_add(self.day)
+            .ok_or(DateError::ArithmeticOverflow) // Safety check
+    }
+
+    /// Returns the fraction of the Persian year that has elapsed by the end of this date.
+    ///
+    /// Computed as `ordinal / days_in_year`, where the denominator is 366 for leap years and 365
+    /// otherwise. The result lies in the range `(0.0, 1.0]`: Farvardin 1st yields `1/365` (or `1/366`)
+    /// and the last day of the year yields exactly `1.0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the `ParsiDate` instance is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// assert_eq!(ParsiDate::new(1403, 12, 30).unwrap().year_progress(), Ok(1.0)); // Leap year end
+    /// assert_eq!(ParsiDate::new(1404, 12, 29).unwrap().year_progress(), Ok(1.0)); // Common year end
+    ///
+    /// let mid = ParsiDate::new(1404, 6, 31).unwrap(); // Ordinal 186 of 365
+    /// assert!((mid.year_progress().unwrap() - 186.0 / 365.0).abs() < f64::EPSILON);
+    /// ```
+    pub fn year_progress(&self) -> Result<f64, DateError> {
+        let ordinal = self.ordinal()?; // Validates self
+        Ok(f64::from(ordinal) / f64::from(Self::days_in_year(self.year)))
+    }
+
+    /// Returns the number of days left in the Persian year after this date.
+    ///
+    /// Computed as `days_in_year - ordinal`, so the date itself is not counted: the last day of
+    /// the year (Esfand 29th, or Esfand 30th in leap years) yields `0`. Together with
+    /// [`days_elapsed_in_year`](#method.days_elapsed_in_year), `elapsed + 1 + remaining` always
+    /// equals the length of the year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the `ParsiDate` instance is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// assert_eq!(ParsiDate::new(1403, 1, 1).unwrap().days_remaining_in_year(), Ok(365)); // Leap year
+    /// assert_eq!(ParsiDate::new(1404, 1, 1).unwrap().days_remaining_in_year(), Ok(364)); // Common year
+    /// assert_eq!(ParsiDate::new(1403, 12, 30).unwrap().days_remaining_in_year(), Ok(0));
+    /// ```
+    pub fn days_remaining_in_year(&self) -> Result<u32, DateError> {
+        let ordinal = self.ordinal()?; // Validates self
+        Ok(Self::days_in_year(self.year) - ordinal)
+    }
+
+    /// Returns the number of days of the Persian year that have fully elapsed before this date.
+    ///
+    /// Computed as `ordinal - 1`, so the date itself is not counted: Farvardin 1st yields `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the `ParsiDate` instance is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// assert_eq!(ParsiDate::new(1403, 1, 1).unwrap().days_elapsed_in_year(), Ok(0));
+    /// assert_eq!(ParsiDate::new(1403, 2, 1).unwrap().days_elapsed_in_year(), Ok(31));
+    /// assert_eq!(ParsiDate::new(1403, 12, 30).unwrap().days_elapsed_in_year(), Ok(365));
+    /// ```
+    pub fn days_elapsed_in_year(&self) -> Result<u32, DateError> {
+        Ok(self.ordinal()? - 1)
+    }
+
+    /// **Internal**: Returns the number of days in the given Persian year (365 or 366).
+    pub(crate) fn days_in_year(year: i32) -> u32 {
+        if Self::is_persian_leap_year(year) {
+            366
+        } else {
+            365
+        }
+    }
+
+    /// Returns the year of this date spelled out in Persian words.
+    ///
+    /// Intended for formal documents, where years are often written in words
+    /// (e.g., 1403 → "هزار و چهارصد و سه"). Components are joined with the connector " و ",
+    /// and a bare thousand is written as "هزار" (not "یک هزار"). The same text is produced by
+    /// the `%EY` format specifier.
+    ///
+    /// The conversion covers the supported year range 1-9999. For a year outside that range
+    /// (only possible via [`new_unchecked`](#method.new_unchecked)), the plain decimal digits are
+    /// returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// assert_eq!(ParsiDate::new(1403, 1, 1).unwrap().year_in_words_persian(), "هزار و چهارصد و سه");
+    /// assert_eq!(ParsiDate::new(1400, 1, 1).unwrap().year_in_words_persian(), "هزار و چهارصد");
+    /// assert_eq!(ParsiDate::new(2000, 1, 1).unwrap().year_in_words_persian(), "دو هزار");
+    /// assert_eq!(ParsiDate::new(1403, 1, 1).unwrap().format("سال %EY"), "سال هزار و چهارصد و سه");
+    /// ```
+    pub fn year_in_words_persian(&self) -> String {
+        if !(1..=9999).contains(&self.year) {
+            return self.year.to_string();
+        }
+        let year = self.year as usize;
+        let mut parts: Vec<String> = Vec::with_capacity(4);
+
+        let thousands = year / 1000;
+        if thousands == 1 {
+            parts.push("هزار".to_string());
+        } else if thousands > 1 {
+            parts.push(format!("{} هزار", PERSIAN_WORDS_BELOW_20[thousands]));
         }
 
-        // 4. Add the day of the current month to the accumulated total.
-        // `self.day` is 1-based, so adding it directly gives the correct 1-based ordinal day.
-        accumulated_days = accumulated_days
-            .checked_add(self.day)
-            .ok_or(DateError::ArithmeticOverflow)?; // Safety check
+        let hundreds = (year % 1000) / 100;
+        if hundreds > 0 {
+            parts.push(PERSIAN_WORDS_HUNDREDS[hundreds].to_string());
+        }
+
+        let below_100 = year % 100;
+        if below_100 >= 20 {
+            parts.push(PERSIAN_WORDS_TENS[below_100 / 10].to_string());
+            if below_100 % 10 > 0 {
+                parts.push(PERSIAN_WORDS_BELOW_20[below_100 % 10].to_string());
+            }
+        } else if below_100 > 0 {
+            parts.push(PERSIAN_WORDS_BELOW_20[below_100].to_string());
+        }
 
-        // The result is the 1-based ordinal day number.
-        Ok(accumulated_days)
+        parts.join(" و ")
     }
 
     // --- Season Information ---
@@ -1670,6 +4155,73 @@ impl ParsiDate {
         }
     }
 
+    /// Returns the calendar quarter (1-4) this date falls into.
+    ///
+    /// Quarters follow the seasons: 1 for months 1-3, 2 for 4-6, 3 for 7-9 and 4 for 10-12.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the `ParsiDate` instance holds invalid data.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use parsidate::{ParsiDate, DateError};
+    ///
+    /// assert_eq!(ParsiDate::new(1403, 3, 31).unwrap().quarter(), Ok(1));
+    /// assert_eq!(ParsiDate::new(1403, 4, 1).unwrap().quarter(), Ok(2));
+    /// assert_eq!(ParsiDate::new(1403, 12, 30).unwrap().quarter(), Ok(4));
+    ///
+    /// let invalid_date = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
+    /// assert_eq!(invalid_date.quarter(), Err(DateError::InvalidDate));
+    /// ```
+    pub fn quarter(&self) -> Result<u32, DateError> {
+        if !self.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        Ok((self.month - 1) / 3 + 1)
+    }
+
+    /// Creates a [`ParsiDateView`] caching the weekday, ordinal day, season and week number.
+    ///
+    /// The date is converted to the Gregorian calendar only once, here; the accessors of the
+    /// returned view are then plain field reads. Prefer this over calling
+    /// [`weekday`](#method.weekday), [`ordinal`](#method.ordinal) and
+    /// [`week_of_year`](#method.week_of_year) repeatedly for the same date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self` is invalid, or
+    /// `Err(DateError::GregorianConversionError)` if the Gregorian conversion fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, Season};
+    ///
+    /// let date = ParsiDate::new(1403, 12, 30).unwrap();
+    /// let view = date.view().unwrap();
+    /// assert_eq!(view.date(), date);
+    /// assert_eq!(view.weekday().name_persian(), date.weekday().unwrap());
+    /// assert_eq!(view.ordinal(), 366);
+    /// assert_eq!(view.season(), Season::Zemestan);
+    /// assert_eq!(Ok(view.week_of_year()), date.week_of_year());
+    /// ```
+    pub fn view(&self) -> Result<ParsiDateView, DateError> {
+        let season = self.season()?; // Validates the date
+        let gregorian = self.to_gregorian_internal()?;
+        // chrono's Sunday = 0 remapped to Saturday = 0.
+        let weekday_num = (gregorian.weekday().num_days_from_sunday() + 1) % 7;
+        let weekday =
+            ParsiWeekday::from_num_sat_0(weekday_num).ok_or(DateError::GregorianConversionError)?;
+        Ok(ParsiDateView::new(
+            *self,
+            gregorian,
+            weekday,
+            self.ordinal_internal()?,
+            season,
+        ))
+    }
+
     // --- Arithmetic ---
 
     /// Adds a specified number of days to this `ParsiDate`, returning a new `ParsiDate`.
@@ -1679,6 +4231,10 @@ impl ParsiDate {
     /// performing the day addition using `chrono`'s reliable arithmetic, and then converting
     /// the resulting Gregorian date back to `ParsiDate`.
     ///
+    /// When the result stays within the same month (e.g., `+1` or `-1` away from a month
+    /// boundary), the day is computed directly and the Gregorian round-trip is skipped; the
+    /// result is identical either way.
+    ///
     /// The input `days` can be positive to move forward in time or negative to move backward.
     ///
     /// # Arguments
@@ -1729,7 +4285,16 @@ impl ParsiDate {
             return Err(DateError::InvalidDate);
         }
 
-        // 2. Convert the ParsiDate to its Gregorian equivalent. Use internal method for efficiency.
+        // 2. Fast path: the result stays within the current month, so no conversion is needed.
+        let target_day = i64::from(self.day) + days.clamp(-31, 31);
+        if (1..=i64::from(Self::days_in_month(self.year, self.month))).contains(&target_day) {
+            return Ok(ParsiDate {
+                day: target_day as u32,
+                ..*self
+            });
+        }
+
+        // Convert the ParsiDate to its Gregorian equivalent. Use internal method for efficiency.
         let gregorian_equiv = self.to_gregorian_internal()?;
 
         // 3. Perform the day addition/subtraction using chrono's checked arithmetic.
@@ -1795,6 +4360,75 @@ impl ParsiDate {
         self.add_days(days_as_neg_i64)
     }
 
+    /// Adds a specified number of weeks to this `ParsiDate`, returning a new `ParsiDate`.
+    ///
+    /// This is `add_days(7 * weeks)`, so leap years and year boundaries are handled exactly as
+    /// in [`add_days`](#method.add_days). A negative `weeks` moves backward.
+    ///
+    /// # Arguments
+    ///
+    /// * `weeks`: The number of weeks to add.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if:
+    /// *   `DateError::InvalidDate`: The starting `ParsiDate` (`self`) is invalid.
+    /// *   `DateError::ArithmeticOverflow`: `7 * weeks` overflows an `i64`, or the result falls
+    ///     outside the supported range [`MIN_PARSI_DATE`](crate::MIN_PARSI_DATE) to
+    ///     [`MAX_PARSI_DATE`](crate::MAX_PARSI_DATE).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDate};
+    ///
+    /// let date = ParsiDate::new(1403, 12, 25).unwrap(); // 1403 is a leap year
+    /// assert_eq!(date.add_weeks(1), Ok(ParsiDate::new(1404, 1, 2).unwrap()));
+    /// assert_eq!(date.add_weeks(-2), Ok(ParsiDate::new(1403, 12, 11).unwrap()));
+    ///
+    /// assert_eq!(date.add_weeks(i64::MAX), Err(DateError::ArithmeticOverflow));
+    /// assert_eq!(ParsiDate::new(1, 1, 3).unwrap().add_weeks(-1), Err(DateError::ArithmeticOverflow));
+    /// ```
+    pub fn add_weeks(&self, weeks: i64) -> Result<Self, DateError> {
+        if !self.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        let days = weeks.checked_mul(7).ok_or(DateError::ArithmeticOverflow)?;
+        // `self` is valid, so any failure here means the result is out of range.
+        self.add_days(days)
+            .map_err(|_| DateError::ArithmeticOverflow)
+    }
+
+    /// Subtracts a specified number of weeks from this `ParsiDate`, returning a new `ParsiDate`.
+    ///
+    /// This is `sub_days(7 * weeks)`; see [`add_weeks`](#method.add_weeks).
+    ///
+    /// # Arguments
+    ///
+    /// * `weeks`: The non-negative number of weeks to subtract.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` under the same conditions as [`add_weeks`](#method.add_weeks).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let date = ParsiDate::new(1404, 1, 5).unwrap();
+    /// assert_eq!(date.sub_weeks(1), Ok(ParsiDate::new(1403, 12, 28).unwrap()));
+    /// assert!(ParsiDate::new(1, 1, 1).unwrap().sub_weeks(1).is_err());
+    /// ```
+    pub fn sub_weeks(&self, weeks: u64) -> Result<Self, DateError> {
+        if !self.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        let days = weeks.checked_mul(7).ok_or(DateError::ArithmeticOverflow)?;
+        self.sub_days(days)
+            .map_err(|_| DateError::ArithmeticOverflow)
+    }
+
     /// Adds a specified number of months to this `ParsiDate`, returning a new `ParsiDate`.
     ///
     /// This operation adjusts the month and, if necessary, the year. A crucial aspect is
@@ -1867,8 +4501,40 @@ impl ParsiDate {
         // 5. Clamp the day
         let target_day = self.day.min(max_days_in_target_month);
 
-        // 6. Use ParsiDate::new for final validation.
-        ParsiDate::new(target_year, target_month, target_day)
+        // 6. Final validation; the target year was range-checked above.
+        Self::new_validated_components(target_year, target_month, target_day)
+    }
+
+    /// Adds months like [`add_months`](#method.add_months) and also reports whether the day was clamped.
+    ///
+    /// `add_months` silently clamps the day when the target month is shorter (e.g., Shahrivar 31st
+    /// plus one month becomes Mehr 30th). This variant returns that information so callers can warn
+    /// the user or record it for auditing.
+    ///
+    /// # Arguments
+    ///
+    /// * `months_to_add`: The number of months to add (can be negative).
+    ///
+    /// # Returns
+    ///
+    /// `Ok((date, clamped))`, where `clamped` is `true` if the resulting day differs from `self.day()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` under the same conditions as [`add_months`](#method.add_months).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let date = ParsiDate::new(1403, 6, 31).unwrap(); // Shahrivar 31st
+    /// assert_eq!(date.add_months_overflowing(1), Ok((ParsiDate::new(1403, 7, 30).unwrap(), true)));
+    /// assert_eq!(date.add_months_overflowing(-1), Ok((ParsiDate::new(1403, 5, 31).unwrap(), false)));
+    /// ```
+    pub fn add_months_overflowing(&self, months_to_add: i32) -> Result<(Self, bool), DateError> {
+        let result = self.add_months(months_to_add)?;
+        Ok((result, result.day != self.day))
     }
 
     /// Subtracts a specified number of months from this `ParsiDate`, returning a new `ParsiDate`.
@@ -1956,8 +4622,8 @@ impl ParsiDate {
             target_day = 29;
         }
 
-        // 5. Use ParsiDate::new for final construction and validation.
-        ParsiDate::new(target_year, self.month, target_day)
+        // 5. Final construction and validation; the target year was range-checked above.
+        Self::new_validated_components(target_year, self.month, target_day)
     }
 
     /// Subtracts a specified number of years from this `ParsiDate`, returning a new `ParsiDate`.
@@ -1979,66 +4645,384 @@ impl ParsiDate {
     ///
     /// let leap_day = ParsiDate::new(1403, 12, 30).unwrap(); // Esfand 30th in leap year 1403
     ///
-    /// // Subtract 1 year -> 1402 (common year). Day clamped from 30 to 29. -> 1402/12/29
-    /// assert_eq!(leap_day.sub_years(1), Ok(ParsiDate::new(1402, 12, 29).unwrap()));
+    /// // Subtract 1 year -> 1402 (common year). Day clamped from 30 to 29. -> 1402/12/29
+    /// assert_eq!(leap_day.sub_years(1), Ok(ParsiDate::new(1402, 12, 29).unwrap()));
+    /// ```
+    pub fn sub_years(&self, years_to_sub: u32) -> Result<Self, DateError> {
+        if years_to_sub > i32::MAX as u32 {
+            return Err(DateError::ArithmeticOverflow);
+        }
+        let years_as_neg_i32 = -(years_to_sub as i32);
+        self.add_years(years_as_neg_i32)
+    }
+
+    /// Calculates the absolute difference in days between this `ParsiDate` and another `ParsiDate`.
+    ///
+    /// This method determines the number of days separating the two dates, regardless of which
+    /// date comes first. It is the absolute value of
+    /// [`signed_days_between`](#method.signed_days_between); use that method when the direction
+    /// matters.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: A reference to the other `ParsiDate` instance to compare against.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if:
+    /// *   `DateError::InvalidDate`: Either `self` or `other` represents an invalid date.
+    /// *   `DateError::GregorianConversionError`: The conversion of either `self` or `other`
+    ///     to `NaiveDate` fails. Conversion errors are always propagated, never turned into a
+    ///     day count.
+    ///
+    /// # Returns
+    ///
+    /// The absolute difference between the two dates, measured in days, as a non-negative `i64`.
+    /// Returns `Ok(0)` if both dates are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let d1 = ParsiDate::new(1403, 1, 1).unwrap();
+    /// let d2 = ParsiDate::new(1403, 1, 11).unwrap();
+    /// let d3 = ParsiDate::new(1404, 1, 1).unwrap(); // Next year (1403 is leap, 366 days)
+    ///
+    /// assert_eq!(d1.days_between(&d2), Ok(10));
+    /// assert_eq!(d2.days_between(&d1), Ok(10));
+    /// assert_eq!(d1.days_between(&d3), Ok(366));
+    /// assert_eq!(d1.days_between(&d1), Ok(0));
+    /// ```
+    pub fn days_between(&self, other: &ParsiDate) -> Result<i64, DateError> {
+        Ok(self.signed_days_between(other)?.abs())
+    }
+
+    /// Calculates the signed difference in days `self - other`.
+    ///
+    /// The result is positive if `self` is later than `other`, negative if it is earlier, and
+    /// zero if the dates are equal, following the argument order of chrono's
+    /// `NaiveDate::signed_duration_since`. The magnitude always equals
+    /// [`days_between`](#method.days_between).
+    ///
+    /// Across the whole supported range (`MIN_PARSI_DATE` to `MAX_PARSI_DATE`) the difference is
+    /// below four million days, so the `i64` result cannot overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The date to subtract from `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if either date is invalid, or
+    /// `Err(DateError::GregorianConversionError)` if converting either date to Gregorian fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let nowruz = ParsiDate::new(1403, 1, 1).unwrap();
+    /// let later = ParsiDate::new(1403, 1, 11).unwrap();
+    ///
+    /// assert_eq!(later.signed_days_between(&nowruz), Ok(10));
+    /// assert_eq!(nowruz.signed_days_between(&later), Ok(-10));
+    /// assert_eq!(nowruz.signed_days_between(&nowruz), Ok(0));
+    /// ```
+    pub fn signed_days_between(&self, other: &ParsiDate) -> Result<i64, DateError> {
+        // 1. Validate both input dates first.
+        if !self.is_valid() || !other.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        // 2. Convert both dates to Gregorian using internal helpers (avoids re-validation).
+        // Any conversion failure is propagated as-is.
+        let gregorian_self = self.to_gregorian_internal()?;
+        let gregorian_other = other.to_gregorian_internal()?;
+
+        // 3. `self - other` in whole days, keeping the sign.
+        Ok(gregorian_self
+            .signed_duration_since(gregorian_other)
+            .num_days())
+    }
+
+    // --- Business Days ---
+
+    /// Returns the date `n` business days after `self`, or before it for a negative `n`.
+    ///
+    /// Business days are all days except Fridays (جمعه), the weekly holiday in Iran, and the dates
+    /// listed in `holidays`. `self` is never counted and need not be a business day itself; the
+    /// result always is one, except for `n == 0`, which returns `self` unchanged. Holidays may be
+    /// given in any order, duplicates and Fridays among them are harmless, and the list may span
+    /// several years.
+    ///
+    /// Whole weeks are skipped at once, so the cost grows with the number of holidays rather than
+    /// with `n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n`: The number of business days to move, forward if positive and backward if negative.
+    /// * `holidays`: Additional non-working dates, such as official holidays.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self` or any of `holidays` is invalid, or
+    /// `Err(DateError::ArithmeticOverflow)` if the result would fall outside the supported range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let wednesday = ParsiDate::new(1403, 12, 29).unwrap();
+    /// let nowruz: Vec<ParsiDate> = (1..=4).map(|d| ParsiDate::new(1404, 1, d).unwrap()).collect();
+    ///
+    /// // Thursday Esfand 30th is the only business day before the holidays and the Friday.
+    /// assert_eq!(wednesday.add_business_days(1, &nowruz), Ok(ParsiDate::new(1403, 12, 30).unwrap()));
+    /// assert_eq!(wednesday.add_business_days(2, &nowruz), Ok(ParsiDate::new(1404, 1, 5).unwrap()));
+    /// // Without the holiday list only the Friday (1404/01/01) is skipped.
+    /// assert_eq!(wednesday.add_business_days(2, &[]), Ok(ParsiDate::new(1404, 1, 2).unwrap()));
+    ///
+    /// // Going backward from Saturday skips the Friday before it.
+    /// let saturday = ParsiDate::new(1403, 5, 6).unwrap();
+    /// assert_eq!(saturday.add_business_days(-1, &[]), Ok(ParsiDate::new(1403, 5, 4).unwrap()));
+    /// ```
+    pub fn add_business_days(&self, n: i64, holidays: &[ParsiDate]) -> Result<Self, DateError> {
+        if !self.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        let holidays = Self::business_holidays(holidays)?;
+        let step = n.signum();
+        let mut remaining = n.unsigned_abs();
+        let mut current = *self;
+
+        // A week holds at most six business days, so jumping `(remaining - 1) / 6` weeks leaves at
+        // least one business day to find by stepping, which makes the result a business day.
+        while remaining > 6 {
+            let days = i64::try_from((remaining - 1) / 6 * 7)
+                .map_err(|_| DateError::ArithmeticOverflow)?;
+            let next = current.add_days(step * days)?;
+            let counted = if step > 0 {
+                current.business_days_after(&next, &holidays)?
+            } else {
+                // Business days in [next, current) rather than (next, current].
+                next.business_days_after(&current, &holidays)?
+                    + u64::from(next.is_business_day(&holidays)?)
+                    - u64::from(current.is_business_day(&holidays)?)
+            };
+            remaining -= counted;
+            current = next;
+        }
+        while remaining > 0 {
+            current = current.add_days(step)?;
+            if current.is_business_day(&holidays)? {
+                remaining -= 1;
+            }
+        }
+        Ok(current)
+    }
+
+    /// Counts the business days from `self` to `other`, as defined by
+    /// [`add_business_days`](#method.add_business_days): every day except Fridays and `holidays`.
+    ///
+    /// The count covers the days after `self` up to and including `other`, so it is the inverse
+    /// of `add_business_days`: if `other` is a business day,
+    /// `self.add_business_days(self.business_days_between(&other, holidays)?, holidays)` returns
+    /// `other`. The result is negative if `other` is earlier than `self`, and counts the days
+    /// from `other` up to but excluding `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The end of the range.
+    /// * `holidays`: Additional non-working dates, such as official holidays.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self`, `other` or any of `holidays` is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let start = ParsiDate::new(1403, 5, 1).unwrap(); // Monday
+    /// let end = ParsiDate::new(1403, 5, 31).unwrap();
+    /// // 30 days, of which 4 are Fridays (5/5, 5/12, 5/19, 5/26)
+    /// assert_eq!(start.business_days_between(&end, &[]), Ok(26));
+    /// assert_eq!(end.business_days_between(&start, &[]), Ok(-26));
+    ///
+    /// let holiday = [ParsiDate::new(1403, 5, 10).unwrap()];
+    /// assert_eq!(start.business_days_between(&end, &holiday), Ok(25));
+    /// ```
+    pub fn business_days_between(
+        &self,
+        other: &ParsiDate,
+        holidays: &[ParsiDate],
+    ) -> Result<i64, DateError> {
+        if !self.is_valid() || !other.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        let holidays = Self::business_holidays(holidays)?;
+        // The supported range holds fewer than four million days, so the casts cannot overflow.
+        if self <= other {
+            Ok(self.business_days_after(other, &holidays)? as i64)
+        } else {
+            // Business days in [other, self).
+            let counted = other.business_days_after(self, &holidays)?
+                + u64::from(other.is_business_day(&holidays)?)
+                - u64::from(self.is_business_day(&holidays)?);
+            Ok(-(counted as i64))
+        }
+    }
+
+    /// **Internal**: Validates `holidays` and returns those not falling on a Friday, sorted and
+    /// deduplicated, as expected by the other business-day helpers.
+    fn business_holidays(holidays: &[ParsiDate]) -> Result<Vec<ParsiDate>, DateError> {
+        let mut result = Vec::with_capacity(holidays.len());
+        for holiday in holidays {
+            // `is_weekend` also rejects invalid dates.
+            if !holiday.is_weekend()? {
+                result.push(*holiday);
+            }
+        }
+        result.sort_unstable();
+        result.dedup();
+        Ok(result)
+    }
+
+    /// **Internal**: Returns `true` if `self` is neither a Friday nor in the prepared `holidays`.
+    fn is_business_day(&self, holidays: &[ParsiDate]) -> Result<bool, DateError> {
+        Ok(!self.is_weekend()? && holidays.binary_search(self).is_err())
+    }
+
+    /// **Internal**: Counts the business days in `(self, end]`, for valid dates with
+    /// `self <= end` and `holidays` prepared by `business_holidays`.
+    fn business_days_after(
+        &self,
+        end: &ParsiDate,
+        holidays: &[ParsiDate],
+    ) -> Result<u64, DateError> {
+        let days = end.signed_days_between(self)? as u64;
+        // Days from `self` to the next Friday (1-7), and the Fridays within the range.
+        let first_friday = u64::from(7 - (self.weekday_num_sat_0()? + 1) % 7);
+        let fridays = if days >= first_friday {
+            (days - first_friday) / 7 + 1
+        } else {
+            0
+        };
+        let in_range =
+            holidays.partition_point(|h| h <= end) - holidays.partition_point(|h| h <= self);
+        Ok(days - fridays - in_range as u64)
+    }
+
+    /// Calculates the number of days from `self` to `other` as a plain difference, i.e. counting
+    /// only one of the two endpoints.
+    ///
+    /// This is an alias for [`days_between`](#method.days_between) whose name states the
+    /// counting convention explicitly, to contrast it with
+    /// [`calendar_days_inclusive`](#method.calendar_days_inclusive). The same date gives `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` under the same conditions as [`days_between`](#method.days_between).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let start = ParsiDate::new(1403, 1, 1).unwrap();
+    /// let end = ParsiDate::new(1403, 1, 31).unwrap();
+    /// assert_eq!(start.days_between_exclusive(&end), Ok(30));
+    /// assert_eq!(start.days_between_exclusive(&start), Ok(0));
+    /// ```
+    #[inline]
+    pub fn days_between_exclusive(&self, other: &ParsiDate) -> Result<i64, DateError> {
+        self.days_between(other)
+    }
+
+    /// Calculates the number of calendar days in the period from `self` to `other`, counting
+    /// **both** endpoints.
+    ///
+    /// This is `days_between + 1`, the count usually wanted for billing and leave periods
+    /// ("from Farvardin 1st to Farvardin 31st" is 31 days). The order of the arguments does not
+    /// matter, and the same date gives `1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` under the same conditions as [`days_between`](#method.days_between).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let start = ParsiDate::new(1403, 1, 1).unwrap();
+    /// let end = ParsiDate::new(1403, 1, 31).unwrap();
+    /// assert_eq!(start.calendar_days_inclusive(&end), Ok(31));
+    /// assert_eq!(end.calendar_days_inclusive(&start), Ok(31));
+    /// assert_eq!(start.calendar_days_inclusive(&start), Ok(1));
     /// ```
-    pub fn sub_years(&self, years_to_sub: u32) -> Result<Self, DateError> {
-        if years_to_sub > i32::MAX as u32 {
-            return Err(DateError::ArithmeticOverflow);
-        }
-        let years_as_neg_i32 = -(years_to_sub as i32);
-        self.add_years(years_as_neg_i32)
+    pub fn calendar_days_inclusive(&self, other: &ParsiDate) -> Result<i64, DateError> {
+        Ok(self.days_between(other)? + 1)
     }
 
-    /// Calculates the absolute difference in days between this `ParsiDate` and another `ParsiDate`.
-    ///
-    /// This method determines the number of days separating the two dates, regardless of which
-    /// date comes first. The calculation is performed by converting both `ParsiDate` instances
-    /// to their Gregorian `NaiveDate` equivalents and then using `chrono`'s duration calculation.
+    /// Describes the calendar difference between two dates as Persian text, e.g., "۲ سال و ۳ ماه و ۵ روز".
     ///
-    /// # Arguments
+    /// This is the human-facing counterpart of subtracting dates (`self - other`, which yields a
+    /// normalized [`ParsiInterval`]), suitable for displaying ages, tenures and other durations.
+    /// The magnitude of the interval is described, so the order of the two dates does not matter.
     ///
-    /// * `other`: A reference to the other `ParsiDate` instance to compare against.
+    /// Numbers are written with Persian digits, zero components are omitted, and the remaining
+    /// components are joined with the connector " و ". Persian nouns are not inflected for number,
+    /// so "سال", "ماه" and "روز" are used for every count. Equal dates produce "همان روز"
+    /// ("the same day").
     ///
-    /// # Errors
+    /// # Arguments
     ///
-    /// Returns `Err` if:
-    /// *   `DateError::InvalidDate`: Either `self` or `other` represents an invalid date.
-    /// *   `DateError::GregorianConversionError`: The conversion of either `self` or `other`
-    ///     to `NaiveDate` fails.
+    /// * `other`: The date to compare against.
     ///
     /// # Returns
     ///
-    /// The absolute difference between the two dates, measured in days, as an `i64`. Returns
-    /// `Ok(0)` if both dates are the same.
+    /// The description as a `String`. If either date is invalid (only possible via
+    /// `unsafe new_unchecked`), the placeholder `"?InvalidDate?"` is returned instead, in the same
+    /// way [`format`](#method.format) reports errors inline.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use parsidate::ParsiDate;
     ///
-    /// let d1 = ParsiDate::new(1403, 1, 1).unwrap();
-    /// let d2 = ParsiDate::new(1403, 1, 11).unwrap();
-    /// let d3 = ParsiDate::new(1404, 1, 1).unwrap(); // Next year (1403 is leap, 366 days)
+    /// let start = ParsiDate::new(1400, 2, 10).unwrap();
+    /// let end = ParsiDate::new(1402, 5, 15).unwrap();
+    /// assert_eq!(end.humanize_difference(&start), "۲ سال و ۳ ماه و ۵ روز");
+    /// assert_eq!(start.humanize_difference(&end), "۲ سال و ۳ ماه و ۵ روز");
     ///
-    /// assert_eq!(d1.days_between(&d2), Ok(10));
-    /// assert_eq!(d1.days_between(&d3), Ok(366));
-    /// assert_eq!(d1.days_between(&d1), Ok(0));
+    /// // Zero components are omitted.
+    /// let later = ParsiDate::new(1401, 2, 10).unwrap();
+    /// assert_eq!(later.humanize_difference(&start), "۱ سال");
+    /// assert_eq!(ParsiDate::new(1400, 2, 17).unwrap().humanize_difference(&start), "۷ روز");
+    ///
+    /// assert_eq!(start.humanize_difference(&start), "همان روز");
     /// ```
-    pub fn days_between(&self, other: &ParsiDate) -> Result<i64, DateError> {
-        // 1. Validate both input dates first.
-        if !self.is_valid() || !other.is_valid() {
-            return Err(DateError::InvalidDate);
+    pub fn humanize_difference(&self, other: &ParsiDate) -> String {
+        let interval = match *self - *other {
+            Ok(interval) => interval,
+            Err(_) => return "?InvalidDate?".to_string(),
+        };
+        if interval.is_zero() {
+            return "همان روز".to_string();
         }
-        // 2. Convert both dates to Gregorian using internal helpers (avoids re-validation).
-        let gregorian_self = self.to_gregorian_internal()?;
-        let gregorian_other = other.to_gregorian_internal()?;
-
-        // 3. Calculate the signed duration between the Gregorian dates using chrono.
-        let duration = gregorian_self.signed_duration_since(gregorian_other);
-
-        // 4. Return the absolute number of days from the duration.
-        Ok(duration.num_days().abs())
+        // All components share the same sign, so describing the magnitude is enough.
+        [
+            (interval.years.unsigned_abs(), "سال"),
+            (interval.months.unsigned_abs(), "ماه"),
+            (interval.days.unsigned_abs(), "روز"),
+        ]
+        .iter()
+        .filter(|(count, _)| *count != 0)
+        .map(|(count, unit)| format!("{} {}", to_persian_digits(*count), unit))
+        .collect::<Vec<_>>()
+        .join(" و ")
     }
 
     // --- Helper Methods ---
@@ -2112,6 +5096,106 @@ impl ParsiDate {
         ParsiDate::new(year, self.month, day)
     }
 
+    /// Creates a new `ParsiDate` with the year changed, clamping `year` into the supported range.
+    ///
+    /// This is the non-failing counterpart of [`with_year`](#method.with_year), intended for
+    /// UI controls such as year steppers that should stop at the boundary instead of erroring.
+    ///
+    /// The operations are applied in this order:
+    /// 1.  `year` is clamped into the supported range [1, 9999].
+    /// 2.  The Esfand 30th adjustment from `with_year` is applied to the *clamped* year. Since
+    ///     9999 is a common year, clamping Esfand 30th to the upper bound yields Esfand 29th, 9999.
+    ///
+    /// # Arguments
+    ///
+    /// * `year`: The desired year; values outside 1-9999 are clamped.
+    ///
+    /// # Returns
+    ///
+    /// The adjusted `ParsiDate`. If `self` is invalid (only possible via `unsafe new_unchecked`),
+    /// there is no meaningful result and `self` is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let date = ParsiDate::new(1403, 5, 2).unwrap();
+    /// assert_eq!(date.with_year_saturating(1404), ParsiDate::new(1404, 5, 2).unwrap());
+    /// assert_eq!(date.with_year_saturating(12000), ParsiDate::new(9999, 5, 2).unwrap());
+    /// assert_eq!(date.with_year_saturating(-5), ParsiDate::new(1, 5, 2).unwrap());
+    ///
+    /// // Clamping happens first, then the leap-day adjustment (9999 is a common year).
+    /// let leap_day = ParsiDate::new(1403, 12, 30).unwrap();
+    /// assert_eq!(leap_day.with_year_saturating(i32::MAX), ParsiDate::new(9999, 12, 29).unwrap());
+    /// ```
+    pub fn with_year_saturating(&self, year: i32) -> Self {
+        let year = year.clamp(MIN_PARSI_DATE.year, MAX_PARSI_DATE.year);
+        self.with_year(year).unwrap_or(*self)
+    }
+
+    /// Returns the anniversary of this date in the given `year`.
+    ///
+    /// The anniversary has the same month and day as `self`, except for the leap day: an event on
+    /// Esfand 30th is observed on Esfand 29th in common years. This is the same adjustment
+    /// [`with_year`](#method.with_year) applies, so the policy is defined in one place.
+    ///
+    /// # Arguments
+    ///
+    /// * `year`: The year in which to find the anniversary (1-9999).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self` is invalid or `year` is outside the
+    /// supported range [1, 9999].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDate};
+    ///
+    /// let wedding = ParsiDate::new(1399, 12, 30).unwrap(); // 1399 is leap
+    /// assert_eq!(wedding.anniversary_in_year(1404), Ok(ParsiDate::new(1404, 12, 29).unwrap())); // Common
+    /// assert_eq!(wedding.anniversary_in_year(1408), Ok(ParsiDate::new(1408, 12, 30).unwrap())); // Leap
+    ///
+    /// let birthday = ParsiDate::new(1370, 6, 31).unwrap();
+    /// assert_eq!(birthday.anniversary_in_year(1403), Ok(ParsiDate::new(1403, 6, 31).unwrap()));
+    /// assert_eq!(birthday.anniversary_in_year(0), Err(DateError::InvalidDate));
+    /// ```
+    pub fn anniversary_in_year(&self, year: i32) -> Result<ParsiDate, DateError> {
+        self.with_year(year)
+    }
+
+    /// Checks whether this date is an anniversary of `original`.
+    ///
+    /// Returns `true` if `self` falls in a later year than `original` and equals
+    /// [`original.anniversary_in_year(self.year())`](#method.anniversary_in_year). In particular,
+    /// Esfand 29th of a common year is an anniversary of an Esfand 30th event, while `original`
+    /// itself (year difference zero) is not considered an anniversary.
+    ///
+    /// Returns `false` if either date is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `original`: The date of the original event.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let wedding = ParsiDate::new(1399, 12, 30).unwrap();
+    /// assert!(ParsiDate::new(1404, 12, 29).unwrap().is_anniversary_of(&wedding)); // 1404 is common
+    /// assert!(ParsiDate::new(1408, 12, 30).unwrap().is_anniversary_of(&wedding)); // 1408 is leap
+    /// assert!(!ParsiDate::new(1408, 12, 29).unwrap().is_anniversary_of(&wedding));
+    /// assert!(!wedding.is_anniversary_of(&wedding));
+    /// ```
+    pub fn is_anniversary_of(&self, original: &ParsiDate) -> bool {
+        self.year > original.year
+            && self.is_valid()
+            && original.anniversary_in_year(self.year) == Ok(*self)
+    }
+
     /// Creates a new `ParsiDate` instance with only the month component changed.
     ///
     /// This method sets the month to the specified `month` value, keeping the original `year`
@@ -2186,8 +5270,119 @@ impl ParsiDate {
         // 4. Clamp the original day to the maximum allowed day of the target month.
         let day = self.day.min(max_days);
 
-        // 5. Use the safe ParsiDate::new constructor for final validation.
-        ParsiDate::new(self.year, month, day)
+        // 5. Final validation; the year comes from the valid starting date.
+        Self::new_validated_components(self.year, month, day)
+    }
+
+    /// Sets the month like [`with_month`](#method.with_month) and also reports whether the day was clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `month`: The target month (1-12).
+    ///
+    /// # Returns
+    ///
+    /// `Ok((date, clamped))`, where `clamped` is `true` if the day had to be reduced to fit the target month.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` under the same conditions as [`with_month`](#method.with_month).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let date = ParsiDate::new(1404, 1, 31).unwrap(); // 1404 is a common year
+    /// assert_eq!(date.with_month_overflowing(12), Ok((ParsiDate::new(1404, 12, 29).unwrap(), true)));
+    /// assert_eq!(date.with_month_overflowing(2), Ok((ParsiDate::new(1404, 2, 31).unwrap(), false)));
+    /// ```
+    pub fn with_month_overflowing(&self, month: u32) -> Result<(Self, bool), DateError> {
+        let result = self.with_month(month)?;
+        Ok((result, result.day != self.day))
+    }
+
+    /// Returns the next day within the same month, or `None` at the end of the month.
+    ///
+    /// Unlike [`add_days`](#method.add_days), this never rolls over into the following month,
+    /// which makes it convenient for iterating over the cells of a single month. No Gregorian
+    /// conversion is involved.
+    ///
+    /// # Returns
+    ///
+    /// `Some(next_day)`, or `None` if `self` is the last day of its month or is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let date = ParsiDate::new(1403, 7, 29).unwrap(); // Mehr has 30 days
+    /// assert_eq!(date.next_in_month(), Some(ParsiDate::new(1403, 7, 30).unwrap()));
+    /// assert_eq!(ParsiDate::new(1403, 7, 30).unwrap().next_in_month(), None);
+    ///
+    /// // Iterate over all days of Esfand 1404 (29 days)
+    /// let days: Vec<_> = std::iter::successors(Some(ParsiDate::new(1404, 12, 1).unwrap()), |d| d.next_in_month()).collect();
+    /// assert_eq!(days.len(), 29);
+    /// ```
+    pub fn next_in_month(&self) -> Option<Self> {
+        if !self.is_valid() || self.day >= Self::days_in_month(self.year, self.month) {
+            return None;
+        }
+        Some(ParsiDate {
+            day: self.day + 1,
+            ..*self
+        })
+    }
+
+    /// **Internal**: Returns the following day, rolling over into the next month and year, by
+    /// stepping through the Persian calendar without a Gregorian round-trip.
+    ///
+    /// Assumes `self` is valid; the year is not range-checked, so the day after
+    /// `MAX_PARSI_DATE` is an invalid date.
+    pub(crate) fn following_day_unchecked(&self) -> Self {
+        if let Some(next_day) = self.next_in_month() {
+            next_day
+        } else if self.month < 12 {
+            ParsiDate {
+                month: self.month + 1,
+                day: 1,
+                ..*self
+            }
+        } else {
+            ParsiDate {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+            }
+        }
+    }
+
+    /// Returns the previous day within the same month, or `None` on the first day of the month.
+    ///
+    /// This is the counterpart of [`next_in_month`](#method.next_in_month) and never rolls back
+    /// into the preceding month.
+    ///
+    /// # Returns
+    ///
+    /// `Some(previous_day)`, or `None` if `self` is day 1 of its month or is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// assert_eq!(ParsiDate::new(1403, 5, 2).unwrap().prev_in_month(), Some(ParsiDate::new(1403, 5, 1).unwrap()));
+    /// assert_eq!(ParsiDate::new(1403, 5, 1).unwrap().prev_in_month(), None);
+    /// ```
+    pub fn prev_in_month(&self) -> Option<Self> {
+        if !self.is_valid() || self.day <= 1 {
+            return None;
+        }
+        Some(ParsiDate {
+            day: self.day - 1,
+            ..*self
+        })
     }
 
     /// Creates a new `ParsiDate` instance with only the day component changed.
@@ -2254,8 +5449,8 @@ impl ParsiDate {
             return Err(DateError::InvalidDate);
         }
 
-        // 3. Use the safe ParsiDate::new constructor.
-        ParsiDate::new(self.year, self.month, day)
+        // 3. Validate the day; the year and month come from the valid starting date.
+        Self::new_validated_components(self.year, self.month, day)
     }
 
     /// Returns the date of the first day of the month for the current date's year and month.
@@ -2391,52 +5586,240 @@ impl ParsiDate {
         unsafe { ParsiDate::new_unchecked(self.year, 1, 1) }
     }
 
-    /// Returns the date of the last day of the year for the current date's year.
-    ///
-    /// This will be Esfand 30th if `self.year` is a leap year, or Esfand 29th if it's a common year.
-    /// Creates a new `ParsiDate` instance representing that last day.
-    /// Assumes that the `self` instance on which it's called is already a valid `ParsiDate`.
+    /// Returns the date of the last day of the year for the current date's year.
+    ///
+    /// This will be Esfand 30th if `self.year` is a leap year, or Esfand 29th if it's a common year.
+    /// Creates a new `ParsiDate` instance representing that last day.
+    /// Assumes that the `self` instance on which it's called is already a valid `ParsiDate`.
+    ///
+    /// # Safety & Performance
+    ///
+    /// Uses `unsafe { ParsiDate::new_unchecked }` for performance. This is safe because:
+    /// 1.  It assumes `self` is valid (checked by `debug_assert!`), meaning `self.year` is valid [1, 9999].
+    /// 2.  `\[`is_persian_leap_year`\]` correctly determines if the last day is 29 or 30.
+    /// 3.  Month 12 (Esfand) and the calculated last day (29 or 30) always form a valid date
+    ///     for the given `self.year`.
+    ///     A `debug_assert!(self.is_valid())` is included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// // Date within a leap year (1403)
+    /// let date_in_leap = ParsiDate::new(1403, 5, 15).unwrap();
+    /// let last_day_leap = date_in_leap.last_day_of_year();
+    /// assert_eq!(last_day_leap, ParsiDate::new(1403, 12, 30).unwrap()); // Esfand 30th
+    ///
+    /// // Date within a common year (1404)
+    /// let date_in_common = ParsiDate::new(1404, 7, 10).unwrap();
+    /// let last_day_common = date_in_common.last_day_of_year();
+    /// assert_eq!(last_day_common, ParsiDate::new(1404, 12, 29).unwrap()); // Esfand 29th
+    /// ```
+    #[inline]
+    pub fn last_day_of_year(&self) -> Self {
+        // Debug assertion for validity precondition.
+        debug_assert!(
+            self.is_valid(),
+            "Precondition failed: last_day_of_year called on an invalid ParsiDate instance."
+        );
+        // Determine the correct last day number (29 or 30) for Esfand of self.year.
+        let last_day_num = if Self::is_persian_leap_year(self.year) {
+            30
+        } else {
+            29
+        };
+        // Safety justification: Month 12 is valid, and last_day_num (29 or 30) is guaranteed
+        // to be the valid last day for month 12 in the assumed-valid self.year.
+        unsafe { ParsiDate::new_unchecked(self.year, 12, last_day_num) }
+    }
+
+    /// Returns the first and last day of the month containing this date, as a pair.
+    ///
+    /// Equivalent to `(self.first_day_of_month(), self.last_day_of_month())`, convenient for
+    /// month-range filters: `let (start, end) = date.month_bounds();`.
+    /// Like those methods, it assumes that `self` is a valid `ParsiDate`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let (start, end) = ParsiDate::new(1403, 12, 10).unwrap().month_bounds();
+    /// assert_eq!(start, ParsiDate::new(1403, 12, 1).unwrap());
+    /// assert_eq!(end, ParsiDate::new(1403, 12, 30).unwrap()); // Leap year Esfand
+    /// ```
+    #[inline]
+    pub fn month_bounds(&self) -> (Self, Self) {
+        (self.first_day_of_month(), self.last_day_of_month())
+    }
+
+    /// Returns the first and last day of the year containing this date, as a pair.
+    ///
+    /// Equivalent to `(self.first_day_of_year(), self.last_day_of_year())`.
+    /// Like those methods, it assumes that `self` is a valid `ParsiDate`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let (start, end) = ParsiDate::new(1404, 5, 2).unwrap().year_bounds();
+    /// assert_eq!(start, ParsiDate::new(1404, 1, 1).unwrap());
+    /// assert_eq!(end, ParsiDate::new(1404, 12, 29).unwrap()); // Common year
+    /// ```
+    #[inline]
+    pub fn year_bounds(&self) -> (Self, Self) {
+        (self.first_day_of_year(), self.last_day_of_year())
+    }
+
+    /// Returns the first day of the month following the one containing this date.
+    ///
+    /// After Esfand, this rolls over to Farvardin 1st of the next year. This is a shorthand for
+    /// `add_months(1)?.first_day_of_month()` that never needs day clamping.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self` is invalid, or
+    /// `Err(DateError::ArithmeticOverflow)` if the result would be after year 9999.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDate};
+    ///
+    /// let date = ParsiDate::new(1403, 5, 20).unwrap();
+    /// assert_eq!(date.start_of_next_month(), Ok(ParsiDate::new(1403, 6, 1).unwrap()));
+    ///
+    /// let esfand = ParsiDate::new(1403, 12, 30).unwrap();
+    /// assert_eq!(esfand.start_of_next_month(), Ok(ParsiDate::new(1404, 1, 1).unwrap()));
+    ///
+    /// let last = ParsiDate::new(9999, 12, 5).unwrap();
+    /// assert_eq!(last.start_of_next_month(), Err(DateError::ArithmeticOverflow));
+    /// ```
+    pub fn start_of_next_month(&self) -> Result<Self, DateError> {
+        if !self.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        let (year, month) = if self.month == 12 {
+            (self.year + 1, 1)
+        } else {
+            (self.year, self.month + 1)
+        };
+        if year > MAX_PARSI_DATE.year {
+            return Err(DateError::ArithmeticOverflow);
+        }
+        Ok(ParsiDate {
+            year,
+            month,
+            day: 1,
+        })
+    }
+
+    /// Returns the first day of the month preceding the one containing this date.
+    ///
+    /// Before Farvardin, this rolls back to Esfand 1st of the previous year.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self` is invalid, or
+    /// `Err(DateError::ArithmeticOverflow)` if the result would be before year 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDate};
+    ///
+    /// let date = ParsiDate::new(1403, 5, 20).unwrap();
+    /// assert_eq!(date.start_of_previous_month(), Ok(ParsiDate::new(1403, 4, 1).unwrap()));
+    ///
+    /// let farvardin = ParsiDate::new(1404, 1, 10).unwrap();
+    /// assert_eq!(farvardin.start_of_previous_month(), Ok(ParsiDate::new(1403, 12, 1).unwrap()));
+    ///
+    /// let first = ParsiDate::new(1, 1, 15).unwrap();
+    /// assert_eq!(first.start_of_previous_month(), Err(DateError::ArithmeticOverflow));
+    /// ```
+    pub fn start_of_previous_month(&self) -> Result<Self, DateError> {
+        if !self.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        let (year, month) = if self.month == 1 {
+            (self.year - 1, 12)
+        } else {
+            (self.year, self.month - 1)
+        };
+        if year < MIN_PARSI_DATE.year {
+            return Err(DateError::ArithmeticOverflow);
+        }
+        Ok(ParsiDate {
+            year,
+            month,
+            day: 1,
+        })
+    }
+
+    /// Returns Farvardin 1st (Nowruz) of the year following the one containing this date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self` is invalid, or
+    /// `Err(DateError::ArithmeticOverflow)` if `self` is in year 9999.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDate};
+    ///
+    /// let date = ParsiDate::new(1403, 5, 20).unwrap();
+    /// assert_eq!(date.start_of_next_year(), Ok(ParsiDate::new(1404, 1, 1).unwrap()));
+    ///
+    /// let last = ParsiDate::new(9999, 1, 1).unwrap();
+    /// assert_eq!(last.start_of_next_year(), Err(DateError::ArithmeticOverflow));
+    /// ```
+    pub fn start_of_next_year(&self) -> Result<Self, DateError> {
+        if !self.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        if self.year >= MAX_PARSI_DATE.year {
+            return Err(DateError::ArithmeticOverflow);
+        }
+        Ok(ParsiDate {
+            year: self.year + 1,
+            month: 1,
+            day: 1,
+        })
+    }
+
+    /// Returns Farvardin 1st (Nowruz) of the year preceding the one containing this date.
     ///
-    /// # Safety & Performance
+    /// # Errors
     ///
-    /// Uses `unsafe { ParsiDate::new_unchecked }` for performance. This is safe because:
-    /// 1.  It assumes `self` is valid (checked by `debug_assert!`), meaning `self.year` is valid [1, 9999].
-    /// 2.  `\[`is_persian_leap_year`\]` correctly determines if the last day is 29 or 30.
-    /// 3.  Month 12 (Esfand) and the calculated last day (29 or 30) always form a valid date
-    ///     for the given `self.year`.
-    ///     A `debug_assert!(self.is_valid())` is included.
+    /// Returns `Err(DateError::InvalidDate)` if `self` is invalid, or
+    /// `Err(DateError::ArithmeticOverflow)` if `self` is in year 1.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use parsidate::ParsiDate;
+    /// use parsidate::{DateError, ParsiDate};
     ///
-    /// // Date within a leap year (1403)
-    /// let date_in_leap = ParsiDate::new(1403, 5, 15).unwrap();
-    /// let last_day_leap = date_in_leap.last_day_of_year();
-    /// assert_eq!(last_day_leap, ParsiDate::new(1403, 12, 30).unwrap()); // Esfand 30th
+    /// let date = ParsiDate::new(1403, 5, 20).unwrap();
+    /// assert_eq!(date.start_of_previous_year(), Ok(ParsiDate::new(1402, 1, 1).unwrap()));
     ///
-    /// // Date within a common year (1404)
-    /// let date_in_common = ParsiDate::new(1404, 7, 10).unwrap();
-    /// let last_day_common = date_in_common.last_day_of_year();
-    /// assert_eq!(last_day_common, ParsiDate::new(1404, 12, 29).unwrap()); // Esfand 29th
+    /// let first = ParsiDate::new(1, 12, 29).unwrap();
+    /// assert_eq!(first.start_of_previous_year(), Err(DateError::ArithmeticOverflow));
     /// ```
-    #[inline]
-    pub fn last_day_of_year(&self) -> Self {
-        // Debug assertion for validity precondition.
-        debug_assert!(
-            self.is_valid(),
-            "Precondition failed: last_day_of_year called on an invalid ParsiDate instance."
-        );
-        // Determine the correct last day number (29 or 30) for Esfand of self.year.
-        let last_day_num = if Self::is_persian_leap_year(self.year) {
-            30
-        } else {
-            29
-        };
-        // Safety justification: Month 12 is valid, and last_day_num (29 or 30) is guaranteed
-        // to be the valid last day for month 12 in the assumed-valid self.year.
-        unsafe { ParsiDate::new_unchecked(self.year, 12, last_day_num) }
+    pub fn start_of_previous_year(&self) -> Result<Self, DateError> {
+        if !self.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        if self.year <= MIN_PARSI_DATE.year {
+            return Err(DateError::ArithmeticOverflow);
+        }
+        Ok(ParsiDate {
+            year: self.year - 1,
+            month: 1,
+            day: 1,
+        })
     }
 
     // --- Season Boundaries --- // <-- NEW SECTION
@@ -2517,6 +5900,234 @@ impl ParsiDate {
         // Faster (assumes `new` won't fail if `self` was valid and calculation is correct):
         // Ok(unsafe { ParsiDate::new_unchecked(self.year, end_month, end_day) })
     }
+
+    /// Returns the first and last day of the season containing this date, as a pair.
+    ///
+    /// Equivalent to `(self.start_of_season()?, self.end_of_season()?)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the `ParsiDate` instance is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let (start, end) = ParsiDate::new(1403, 8, 20).unwrap().season_bounds().unwrap();
+    /// assert_eq!(start, ParsiDate::new(1403, 7, 1).unwrap()); // Paeez starts on Mehr 1st
+    /// assert_eq!(end, ParsiDate::new(1403, 9, 30).unwrap()); // ... and ends on Azar 30th
+    /// ```
+    pub fn season_bounds(&self) -> Result<(Self, Self), DateError> {
+        Ok((self.start_of_season()?, self.end_of_season()?))
+    }
+
+    /// Returns the date of the first day of the quarter this date falls into.
+    ///
+    /// The day is always 1, and the month is 1, 4, 7 or 10. The year remains the same.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the original `ParsiDate` instance is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let date = ParsiDate::new(1403, 8, 20).unwrap();
+    /// assert_eq!(date.start_of_quarter(), Ok(ParsiDate::new(1403, 7, 1).unwrap()));
+    /// ```
+    pub fn start_of_quarter(&self) -> Result<Self, DateError> {
+        let quarter = self.quarter()?;
+        ParsiDate::new(self.year, (quarter - 1) * 3 + 1, 1)
+    }
+
+    /// Returns the date of the last day of the quarter this date falls into.
+    ///
+    /// The month is 3, 6, 9 or 12, and the day is the last day of that month; for the fourth
+    /// quarter this is Esfand 30th in leap years and Esfand 29th otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the original `ParsiDate` instance is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let date = ParsiDate::new(1403, 8, 20).unwrap();
+    /// assert_eq!(date.end_of_quarter(), Ok(ParsiDate::new(1403, 9, 30).unwrap()));
+    ///
+    /// // Esfand has 30 days in the leap year 1403 and 29 in 1404
+    /// let q4_leap = ParsiDate::new(1403, 10, 1).unwrap();
+    /// assert_eq!(q4_leap.end_of_quarter(), Ok(ParsiDate::new(1403, 12, 30).unwrap()));
+    /// let q4_common = ParsiDate::new(1404, 11, 15).unwrap();
+    /// assert_eq!(q4_common.end_of_quarter(), Ok(ParsiDate::new(1404, 12, 29).unwrap()));
+    /// ```
+    pub fn end_of_quarter(&self) -> Result<Self, DateError> {
+        let end_month = self.quarter()? * 3;
+        ParsiDate::new(
+            self.year,
+            end_month,
+            Self::days_in_month(self.year, end_month),
+        )
+    }
+
+    // --- Fixed Observances ---
+
+    /// Returns the date that falls a given number of days after Nowruz (Farvardin 1st) of `year`.
+    ///
+    /// Many solar-calendar observances are defined as a fixed offset from Nowruz; this method
+    /// computes such anchors directly. An offset of `0` is Nowruz itself, and offsets beyond the
+    /// end of the year continue into the following year.
+    ///
+    /// # Arguments
+    ///
+    /// * `year`: The Persian year whose Nowruz is the anchor (1-9999).
+    /// * `days`: The number of days after Farvardin 1st.
+    ///
+    /// # Errors
+    ///
+    /// *   `DateError::InvalidDate` if `year` is outside the supported range.
+    /// *   Any error returned by [`add_days`](#method.add_days) if the resulting date falls after
+    ///     the supported range (e.g., `ParsiDate::nowruz_plus(9999, 365)`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// assert_eq!(ParsiDate::nowruz_plus(1403, 0), Ok(ParsiDate::new(1403, 1, 1).unwrap()));
+    /// assert_eq!(ParsiDate::nowruz_plus(1403, 12), Ok(ParsiDate::new(1403, 1, 13).unwrap()));
+    /// // Offsets past the end of the (leap) year roll into the next year
+    /// assert_eq!(ParsiDate::nowruz_plus(1403, 366), Ok(ParsiDate::new(1404, 1, 1).unwrap()));
+    /// assert!(ParsiDate::nowruz_plus(0, 1).is_err());
+    /// ```
+    pub fn nowruz_plus(year: i32, days: u32) -> Result<Self, DateError> {
+        ParsiDate::new(year, 1, 1)?.add_days(i64::from(days))
+    }
+
+    /// Returns the date of Sizdah Bedar (Nature Day, Farvardin 13th) for the given Persian year.
+    ///
+    /// Equivalent to `ParsiDate::nowruz_plus(year, 12)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DateError::InvalidDate` if `year` is outside the supported range (1-9999).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let sizdah = ParsiDate::sizdah_bedar(1403).unwrap();
+    /// assert_eq!(sizdah, ParsiDate::new(1403, 1, 13).unwrap());
+    /// assert_eq!(sizdah.to_gregorian().unwrap().to_string(), "2024-04-01");
+    /// ```
+    pub fn sizdah_bedar(year: i32) -> Result<Self, DateError> {
+        Self::nowruz_plus(year, 12)
+    }
+
+    // --- Sorted Slice Lookups ---
+
+    /// Searches a sorted slice of `ParsiDate`s for `target` using binary search.
+    ///
+    /// This is a thin wrapper around [`slice::binary_search`] that relies on the derived
+    /// chronological `Ord` implementation of `ParsiDate` (year, then month, then day).
+    /// The slice **must** be sorted in ascending order; otherwise the result is unspecified.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted`: A slice of dates sorted in ascending order (e.g., a holiday table).
+    /// * `target`: The date to search for.
+    ///
+    /// # Returns
+    ///
+    /// *   `Ok(index)` if `target` is found at `index`.
+    /// *   `Err(index)` if `target` is not present; `index` is where it could be inserted to keep the slice sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let holidays = [
+    ///     ParsiDate::new(1403, 1, 1).unwrap(),
+    ///     ParsiDate::new(1403, 1, 13).unwrap(),
+    ///     ParsiDate::new(1403, 3, 14).unwrap(),
+    /// ];
+    ///
+    /// let sizdah = ParsiDate::new(1403, 1, 13).unwrap();
+    /// assert_eq!(ParsiDate::binary_search_in(&holidays, &sizdah), Ok(1));
+    ///
+    /// let ordinary_day = ParsiDate::new(1403, 2, 1).unwrap();
+    /// assert_eq!(ParsiDate::binary_search_in(&holidays, &ordinary_day), Err(2));
+    /// ```
+    #[inline]
+    pub fn binary_search_in(sorted: &[ParsiDate], target: &ParsiDate) -> Result<usize, usize> {
+        sorted.binary_search(target)
+    }
+
+    /// Finds the date in a sorted slice that is closest to this date, in O(log n).
+    ///
+    /// Uses [`binary_search_in`](#method.binary_search_in) to locate the insertion point of `self`
+    /// and then compares the neighbouring entries by their distance in days. If `self` is present
+    /// in the slice, that entry is returned. When two entries are equally distant (one before and
+    /// one after), the **earlier** one is returned.
+    ///
+    /// The slice **must** be sorted in ascending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `sorted`: A slice of dates sorted in ascending order.
+    ///
+    /// # Returns
+    ///
+    /// `Some(&date)` referencing the nearest entry, or `None` if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let holidays = [
+    ///     ParsiDate::new(1403, 1, 1).unwrap(),
+    ///     ParsiDate::new(1403, 1, 13).unwrap(),
+    ///     ParsiDate::new(1403, 3, 14).unwrap(),
+    /// ];
+    ///
+    /// let date = ParsiDate::new(1403, 1, 10).unwrap();
+    /// assert_eq!(date.nearest_in_sorted(&holidays), Some(&holidays[1])); // 3 days away
+    ///
+    /// // Exactly between Farvardin 1st and 13th: the earlier entry wins
+    /// let midpoint = ParsiDate::new(1403, 1, 7).unwrap();
+    /// assert_eq!(midpoint.nearest_in_sorted(&holidays), Some(&holidays[0]));
+    ///
+    /// assert_eq!(date.nearest_in_sorted(&[]), None);
+    /// ```
+    pub fn nearest_in_sorted<'a>(&self, sorted: &'a [ParsiDate]) -> Option<&'a ParsiDate> {
+        let idx = match Self::binary_search_in(sorted, self) {
+            Ok(idx) => return sorted.get(idx),
+            Err(idx) => idx,
+        };
+        // Candidates are the entries immediately before and after the insertion point.
+        let before = idx.checked_sub(1).and_then(|i| sorted.get(i));
+        let after = sorted.get(idx);
+        match (before, after) {
+            (Some(b), Some(a)) => {
+                // Distances that cannot be computed (invalid dates) are treated as infinitely far.
+                let dist_before = self.days_between(b).unwrap_or(i64::MAX);
+                let dist_after = self.days_between(a).unwrap_or(i64::MAX);
+                if dist_after < dist_before {
+                    Some(a)
+                } else {
+                    Some(b)
+                }
+            }
+            (Some(b), None) => Some(b),
+            (None, after) => after,
+        }
+    }
 } // End impl ParsiDate
 
 // --- Trait Implementations ---
@@ -2529,6 +6140,14 @@ impl ParsiDate {
 /// The default format follows the `"short"` style: `"YYYY/MM/DD"`, with zero-padding for
 /// the month and day components (e.g., "1403/05/02", "1399/12/30").
 ///
+/// The alternate flag (`{:#}`) selects the verbose `"full"` style instead:
+/// `"WeekdayName، D MonthName YYYY"` (e.g., "سه‌شنبه، 2 مرداد 1403"), exactly as produced by
+/// [`format("full")`](ParsiDate::format).
+///
+/// The standard width, fill and alignment flags apply to the whole formatted date, so dates line
+/// up in tables: `format!("{:>12}", date)` right-aligns `"1403/05/02"` in 12 columns. A
+/// precision (e.g., `{:.4}`) is ignored rather than truncating the date.
+///
 /// **Note:** If this trait method is called on an invalid `ParsiDate` instance (e.g., one
 /// created using `unsafe new_unchecked` with invalid data like month 13), the output will
 /// likely display those invalid components directly (e.g., "1403/13/01"), as `Display` usually
@@ -2548,11 +6167,224 @@ impl ParsiDate {
 ///
 /// let date3 = ParsiDate::new(1400, 1, 1).unwrap(); // Single digit month
 /// assert_eq!(date3.to_string(), "1400/01/01"); // Month is zero-padded
+///
+/// // Alternate form: the verbose "full" style
+/// assert_eq!(format!("{:#}", date1), "سه‌شنبه، 2 مرداد 1403");
+///
+/// // Width, fill and alignment
+/// assert_eq!(format!("{:>12}|", date1), "  1403/05/02|");
+/// assert_eq!(format!("{:<12}|", date1), "1403/05/02  |");
+/// assert_eq!(format!("{:*^14}", date1), "**1403/05/02**");
+/// assert_eq!(format!("{:.4}", date1), "1403/05/02"); // Precision does not truncate
 /// ```
 impl fmt::Display for ParsiDate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return pad_display(f, &self.format("full"));
+        }
         // Format using the "short" style: YYYY/MM/DD.
         // Use :02 format specifier to ensure month and day are zero-padded to two digits.
-        write!(f, "{}/{:02}/{:02}", self.year, self.month, self.day)
+        // Apply any width, fill and alignment to the whole date.
+        pad_display(
+            f,
+            &format!("{}/{:02}/{:02}", self.year, self.month, self.day),
+        )
+    }
+}
+
+/// Parses a `ParsiDate` from its `Display` form, `"YYYY/MM/DD"`, or the ISO-like `"YYYY-MM-DD"`.
+///
+/// This delegates to [`ParsiDate::parse`], so the month and day must be two digits. The year may
+/// have fewer than four digits, as `Display` writes it for years below 1000, which makes
+/// `date.to_string().parse::<ParsiDate>()` round-trip for every valid date.
+///
+/// # Errors
+///
+/// Returns the `DateError::ParseError` produced by [`ParsiDate::parse`] for the detected form.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::{DateError, ParseErrorKind, ParsiDate};
+///
+/// let date: ParsiDate = "1403/05/02".parse().unwrap();
+/// assert_eq!(date, ParsiDate::new(1403, 5, 2).unwrap());
+/// assert_eq!("1403-05-02".parse::<ParsiDate>(), Ok(date));
+///
+/// let early = ParsiDate::new(50, 1, 1).unwrap();
+/// assert_eq!(early.to_string().parse::<ParsiDate>(), Ok(early));
+///
+/// assert_eq!(
+///     "1404/12/30".parse::<ParsiDate>(),
+///     Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+/// );
+/// ```
+impl FromStr for ParsiDate {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (input, format) = prepare_display_date(s);
+        Self::parse(&input, format)
+    }
+}
+
+/// Converts a Gregorian `NaiveDate` with `.try_into()`, delegating to
+/// [`ParsiDate::from_gregorian`] and failing under exactly the same conditions.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use parsidate::ParsiDate;
+///
+/// let gregorian = NaiveDate::from_ymd_opt(2024, 7, 23).unwrap();
+/// let date: ParsiDate = gregorian.try_into().unwrap();
+/// assert_eq!(date, ParsiDate::new(1403, 5, 2).unwrap());
+/// ```
+impl TryFrom<NaiveDate> for ParsiDate {
+    type Error = DateError;
+
+    fn try_from(gregorian: NaiveDate) -> Result<Self, Self::Error> {
+        Self::from_gregorian(gregorian)
+    }
+}
+
+/// Converts a `ParsiDate` to a Gregorian `NaiveDate` with `.try_into()`, delegating to
+/// [`ParsiDate::to_gregorian`] and failing under exactly the same conditions.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use parsidate::{DateError, ParsiDate};
+///
+/// let gregorian: NaiveDate = ParsiDate::new(1403, 5, 2).unwrap().try_into().unwrap();
+/// assert_eq!(gregorian, NaiveDate::from_ymd_opt(2024, 7, 23).unwrap());
+///
+/// let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+/// assert_eq!(NaiveDate::try_from(invalid), Err(DateError::InvalidDate));
+/// ```
+impl TryFrom<ParsiDate> for NaiveDate {
+    type Error = DateError;
+
+    fn try_from(date: ParsiDate) -> Result<Self, Self::Error> {
+        date.to_gregorian()
+    }
+}
+
+/// Implements the `Debug` trait for `ParsiDate`.
+///
+/// The output is `ParsiDate(YYYY-MM-DD [Jalali])`, which identifies the calendar system in logs
+/// where Persian and Gregorian dates may appear side by side. If the instance is invalid (only
+/// possible via `unsafe new_unchecked`), the marker reads `[Jalali, INVALID]`. `Display` and
+/// serialization are unaffected.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::ParsiDate;
+///
+/// let date = ParsiDate::new(1403, 5, 2).unwrap();
+/// assert_eq!(format!("{:?}", date), "ParsiDate(1403-05-02 [Jalali])");
+///
+/// let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) }; // 1404 is a common year
+/// assert_eq!(format!("{:?}", invalid), "ParsiDate(1404-12-30 [Jalali, INVALID])");
+/// ```
+impl fmt::Debug for ParsiDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let validity = if self.is_valid() { "" } else { ", INVALID" };
+        write!(
+            f,
+            "ParsiDate({:04}-{:02}-{:02} [Jalali{}])",
+            self.year, self.month, self.day, validity
+        )
+    }
+}
+
+// --- Operator Overloads ---
+
+/// Implements the `Sub` trait for subtracting one `ParsiDate` from another.
+///
+/// This allows using the `-` operator between two `ParsiDate` instances to obtain the
+/// calendar difference (`self` - `other`) as a normalized [`ParsiInterval`] of years, months
+/// and days. For a plain day count, use [`ParsiDate::days_between`].
+///
+/// # Normalization
+///
+/// The difference is computed from the earlier date to the later one, component by component
+/// (`later.year - earlier.year`, and so on), and then normalized:
+/// 1. If the day difference is negative, one month is borrowed: the month count is decreased
+///    by one and the length of the month *preceding* the later date's month is added to the days.
+///    If the earlier date's day does not exist in that month (e.g., the 31st when borrowing from
+///    a 30-day month), it is clamped to the month's last day first, consistent with
+///    [`add_months`](ParsiDate::add_months).
+/// 2. If the month difference is then negative, one year is borrowed: the year count is decreased
+///    by one and 12 is added to the months.
+///
+/// As a result, adding `years`, then `months` (with day clamping), then `days` to the earlier
+/// date always yields the later date.
+///
+/// If `self` is earlier than `other`, every component of the result is negated, so
+/// `b - a` is always the exact negation of `a - b`.
+///
+/// # Errors
+///
+/// Returns `Err(DateError::InvalidDate)` if either `self` or `other` is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::{ParsiDate, ParsiInterval};
+///
+/// let d1 = ParsiDate::new(1403, 1, 5).unwrap();
+/// let d2 = ParsiDate::new(1402, 11, 20).unwrap();
+/// // Days borrow from Esfand 1402 (29 days): 1 month and 14 days.
+/// assert_eq!(d1 - d2, Ok(ParsiInterval { years: 0, months: 1, days: 14 }));
+///
+/// let birth = ParsiDate::new(1370, 6, 31).unwrap();
+/// let today = ParsiDate::new(1403, 6, 30).unwrap();
+/// assert_eq!(today - birth, Ok(ParsiInterval { years: 32, months: 11, days: 30 }));
+/// ```
+impl Sub<ParsiDate> for ParsiDate {
+    /// The result type of the subtraction: a `ParsiInterval` or a `DateError`.
+    type Output = Result<ParsiInterval, DateError>;
+
+    fn sub(self, other: ParsiDate) -> Self::Output {
+        if !self.is_valid() || !other.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        // Always compute `later - earlier`, then apply the sign.
+        let (later, earlier, sign) = if self >= other {
+            (self, other, 1)
+        } else {
+            (other, self, -1)
+        };
+
+        let mut years = later.year - earlier.year;
+        let mut months = later.month as i32 - earlier.month as i32;
+        let mut days = later.day as i32 - earlier.day as i32;
+
+        if days < 0 {
+            // Borrow the length of the month preceding `later`'s month, clamping `earlier`'s
+            // day to that month (as `add_months` would).
+            let (borrow_year, borrow_month) = if later.month == 1 {
+                (later.year - 1, 12)
+            } else {
+                (later.year, later.month - 1)
+            };
+            let borrowed_length = ParsiDate::days_in_month(borrow_year, borrow_month) as i32;
+            days = borrowed_length - (earlier.day as i32).min(borrowed_length) + later.day as i32;
+            months -= 1;
+        }
+        if months < 0 {
+            months += 12;
+            years -= 1;
+        }
+
+        Ok(ParsiInterval {
+            years: sign * years,
+            months: sign * months,
+            days: sign * days,
+        })
     }
 }
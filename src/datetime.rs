@@ -10,13 +10,28 @@
 //! Contains the `ParsiDateTime` struct definition and its implementation for handling
 //! date and time within the Persian (Jalali or Shamsi) calendar system.
 
-use crate::constants::MONTH_NAMES_PERSIAN;
-use crate::date::ParsiDate;
+use crate::constants::{
+    AM_PM_MARKERS, ERA_MARKER_PERSIAN, MONTH_NAMES_FINGLISH, MONTH_NAMES_PERSIAN,
+    WEEKDAY_NAMES_FINGLISH,
+};
+use crate::date::{english_weekday_name, pad_display, prepare_display_date, ParsiDate};
+use crate::digits::{normalize_digits, DigitStyle};
 use crate::error::{DateError, ParseErrorKind};
 use crate::season::Season;
-use chrono::{Duration, Local, NaiveDateTime, Timelike};
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime, Timelike};
 use std::fmt;
 use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+/// Nanoseconds in one second, for the Unix-epoch nanosecond conversions.
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// The Unix epoch, 1970-01-01 00:00:00, as a `NaiveDateTime`.
+fn unix_epoch() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(1970, 1, 1)
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .expect("1970-01-01 00:00:00 is a valid date and time")
+}
 
 // --- Data Structures ---
 
@@ -27,16 +42,19 @@ use std::ops::{Add, Sub};
 /// such as creation, validation, conversion to/from Gregorian [`NaiveDateTime`], formatting, parsing,
 /// and date/time arithmetic.
 ///
-/// **Nanosecond Precision:** Note that while conversions from `chrono::NaiveDateTime` might involve
-/// nanoseconds, this struct currently only stores precision up to the second. Nanoseconds are effectively
-/// truncated during conversion to `ParsiDateTime` but are preserved during calculations involving `Duration`
-/// by converting to Gregorian, performing the operation, and converting back.
+/// **Nanosecond Precision:** Besides the whole seconds, a `ParsiDateTime` stores a nanosecond
+/// fraction (0-999,999,999), accessible via [`nanosecond`](ParsiDateTime::nanosecond). It is
+/// preserved by the Gregorian conversions and by `Duration` arithmetic. Constructors that take
+/// only hour, minute and second (such as [`new`](ParsiDateTime::new)) set it to zero; use
+/// [`new_with_nano`](ParsiDateTime::new_with_nano) to provide it.
 ///
 /// **Supported Range:** The valid range for the date component is the same as [`ParsiDate`], typically
 /// Persian years 1 through 9999. Time components must be valid according to a standard 24-hour clock
 /// (Hour: 0-23, Minute: 0-59, Second: 0-59).
 ///
 /// **Serialization:** If the `serde` feature is enabled, this struct derives `Serialize` and `Deserialize`.
+/// The `nanosecond` field defaults to zero when it is absent, so data serialized before the field
+/// existed still deserializes.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParsiDateTime {
@@ -48,9 +66,9 @@ pub struct ParsiDateTime {
     minute: u32,
     /// The second component (0-59).
     second: u32,
-    // Nanoseconds are not stored directly to keep the struct simple and focused
-    // on common use cases. Conversions involving chrono::Duration handle them
-    // implicitly during the Gregorian conversion round-trip.
+    /// The fraction of the second in nanoseconds (0-999,999,999).
+    #[cfg_attr(feature = "serde", serde(default))]
+    nanosecond: u32,
 }
 
 // --- Core Implementation ---
@@ -140,9 +158,52 @@ impl ParsiDateTime {
             hour,
             minute,
             second,
+            nanosecond: 0,
         })
     }
 
+    /// Creates a new `ParsiDateTime` from date and time components, including a nanosecond
+    /// fraction of the second.
+    ///
+    /// This is [`new`](#method.new) with an additional `nano` argument.
+    ///
+    /// # Arguments
+    ///
+    /// * `year`, `month`, `day`, `hour`, `minute`, `second`: As for [`new`](#method.new).
+    /// * `nano`: The fraction of the second in nanoseconds (0-999,999,999).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the date components are invalid, and
+    /// `Err(DateError::InvalidTime)` if a time component, including `nano`, is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDateTime};
+    ///
+    /// let dt = ParsiDateTime::new_with_nano(1403, 5, 2, 15, 30, 45, 123_456_789).unwrap();
+    /// assert_eq!(dt.second(), 45);
+    /// assert_eq!(dt.nanosecond(), 123_456_789);
+    /// assert_eq!(dt.format("%T.%f"), "15:30:45.123456789");
+    ///
+    /// assert_eq!(
+    ///     ParsiDateTime::new_with_nano(1403, 5, 2, 15, 30, 45, 1_000_000_000),
+    ///     Err(DateError::InvalidTime)
+    /// );
+    /// ```
+    pub fn new_with_nano(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nano: u32,
+    ) -> Result<Self, DateError> {
+        Self::new(year, month, day, hour, minute, second)?.with_nanosecond(nano)
+    }
+
     /// Creates a `ParsiDateTime` from components without performing any validation checks.
     ///
     /// **Warning:** This function is marked `unsafe` because it bypasses all validity checks
@@ -207,6 +268,7 @@ impl ParsiDateTime {
             hour,
             minute,
             second,
+            nanosecond: 0,
         }
     }
 
@@ -275,6 +337,7 @@ impl ParsiDateTime {
             hour,
             minute,
             second,
+            nanosecond: 0,
         })
     }
 
@@ -282,14 +345,20 @@ impl ParsiDateTime {
     ///
     /// This function first converts the date part (`NaiveDate`) to `ParsiDate` using
     /// [`ParsiDate::from_gregorian`] and then combines it with the time components
-    /// (hour, minute, second) from the `NaiveDateTime`. Nanoseconds from the input
-    /// `NaiveDateTime` are ignored.
+    /// (hour, minute, second and nanosecond) from the `NaiveDateTime`.
     ///
     /// # Arguments
     ///
     /// * `gregorian_dt`: The `chrono::NaiveDateTime` instance to convert. This represents a
     ///   Gregorian date and time without any timezone information.
     ///
+    /// # Precision
+    ///
+    /// The nanoseconds of `gregorian_dt` are kept, so `from_gregorian(x)?.to_gregorian()` returns
+    /// `x` unchanged. The only exception is a leap second, which chrono represents as a nanosecond
+    /// value of 1,000,000,000 or more: `ParsiDateTime` does not model leap seconds, so it is
+    /// clamped to the last nanosecond of the preceding second (`:59.999999999`).
+    ///
     /// # Errors
     ///
     /// Returns `Err(DateError::GregorianConversionError)` if the date part conversion fails.
@@ -318,12 +387,14 @@ impl ParsiDateTime {
     /// assert_eq!(pd_dt.minute(), 30);
     /// assert_eq!(pd_dt.second(), 45);
     ///
-    /// // Example with nanoseconds (they get truncated)
+    /// // Example with nanoseconds (they are preserved)
     /// let g_dt_nano = NaiveDate::from_ymd_opt(2023, 3, 21).unwrap() // Start of 1402
     ///                  .and_hms_nano_opt(0, 0, 1, 123456789).unwrap();
     /// let pd_dt_nano = ParsiDateTime::from_gregorian(g_dt_nano).unwrap();
     /// assert_eq!(pd_dt_nano.date(), ParsiDate::new(1402, 1, 1).unwrap());
-    /// assert_eq!(pd_dt_nano.second(), 1); // Nanoseconds are lost
+    /// assert_eq!(pd_dt_nano.second(), 1);
+    /// assert_eq!(pd_dt_nano.nanosecond(), 123456789);
+    /// assert_eq!(pd_dt_nano.to_gregorian(), Ok(g_dt_nano));
     ///
     /// // Example of a date before the Persian epoch (likely to fail)
     /// let g_dt_early = NaiveDate::from_ymd_opt(600, 1, 1).unwrap().and_hms_opt(0,0,0).unwrap();
@@ -337,7 +408,8 @@ impl ParsiDateTime {
         let hour = gregorian_dt.hour();
         let minute = gregorian_dt.minute();
         let second = gregorian_dt.second();
-        // Nanoseconds present in gregorian_dt.nanosecond() are ignored
+        // A leap second is reported as nanoseconds >= 1e9; clamp it into the current second.
+        let nanosecond = gregorian_dt.nanosecond().min(999_999_999);
 
         // Since the time components from chrono::NaiveDateTime are guaranteed to be
         // valid (0-23, 0-59, 0-59), we can construct directly without re-validating time.
@@ -347,6 +419,7 @@ impl ParsiDateTime {
             hour,
             minute,
             second,
+            nanosecond,
         })
     }
 
@@ -354,7 +427,12 @@ impl ParsiDateTime {
     ///
     /// This function first checks if the `ParsiDateTime` itself is valid. If it is, it converts
     /// the `ParsiDate` component to a `chrono::NaiveDate` and then combines it with the
-    /// stored `hour`, `minute`, and `second` to create the `NaiveDateTime`.
+    /// stored `hour`, `minute`, `second` and `nanosecond` to create the `NaiveDateTime`.
+    ///
+    /// # Precision
+    ///
+    /// The nanosecond fraction is carried over exactly; see
+    /// [`from_gregorian`](#method.from_gregorian) for the round-trip contract.
     ///
     /// # Errors
     ///
@@ -365,7 +443,7 @@ impl ParsiDateTime {
     ///     of this `ParsiDateTime` is invalid (should ideally be caught earlier, but checked here for safety).
     /// *   `DateError::GregorianConversionError`: If the conversion of the valid `ParsiDate` component
     ///     to `NaiveDate` fails internally (this is unexpected for valid Persian dates within the supported range).
-    ///     It might also theoretically occur if `NaiveDate::and_hms_opt` fails, but this shouldn't happen
+    ///     It might also theoretically occur if `NaiveDate::and_hms_nano_opt` fails, but this shouldn't happen
     ///     if the time components (0-23, 0-59, 0-59) are valid.
     ///
     /// # Examples
@@ -413,10 +491,157 @@ impl ParsiDateTime {
 
         // Combine the resulting NaiveDate with the (now known to be valid) time components.
         gregorian_date
-            .and_hms_opt(self.hour, self.minute, self.second)
+            .and_hms_nano_opt(self.hour, self.minute, self.second, self.nanosecond)
             .ok_or(DateError::GregorianConversionError)
     }
 
+    /// Returns the number of nanoseconds since the Unix epoch (1970-01-01 00:00:00 UTC),
+    /// treating this date and time as UTC.
+    ///
+    /// The result is a single sortable integer key; `i128` covers the whole supported range
+    /// (years 1 to 9999) at nanosecond resolution without overflow, including the
+    /// [`nanosecond`](#method.nanosecond) fraction. Dates before 1970 give negative values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` under the same conditions as [`to_gregorian`](#method.to_gregorian).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// // 1348/10/11 00:00:00 is 1970-01-01 00:00:00
+    /// let epoch = ParsiDateTime::new(1348, 10, 11, 0, 0, 0).unwrap();
+    /// assert_eq!(epoch.nanos_since_unix_epoch(), Ok(0));
+    ///
+    /// let dt = ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap(); // 2024-07-23 15:30:45
+    /// assert_eq!(dt.nanos_since_unix_epoch(), Ok(1_721_748_645_000_000_000));
+    ///
+    /// let precise = ParsiDateTime::new_with_nano(1403, 5, 2, 15, 30, 45, 7).unwrap();
+    /// assert_eq!(precise.nanos_since_unix_epoch(), Ok(1_721_748_645_000_000_007));
+    /// ```
+    pub fn nanos_since_unix_epoch(&self) -> Result<i128, DateError> {
+        let gregorian = self.to_gregorian()?;
+        // Count whole seconds from the date and time of day, so that the fraction is always added
+        // (a `Duration` would round negative values towards zero).
+        let days = gregorian
+            .date()
+            .signed_duration_since(unix_epoch().date())
+            .num_days();
+        let seconds = i128::from(days) * 86_400 + i128::from(self.num_seconds_from_midnight());
+        Ok(seconds * NANOS_PER_SECOND + i128::from(self.nanosecond))
+    }
+
+    /// Creates a `ParsiDateTime` from a number of nanoseconds since the Unix epoch
+    /// (1970-01-01 00:00:00 UTC), as returned by
+    /// [`nanos_since_unix_epoch`](#method.nanos_since_unix_epoch).
+    ///
+    /// The sub-second part becomes the [`nanosecond`](#method.nanosecond) fraction, so this is the
+    /// exact inverse of `nanos_since_unix_epoch`. For negative values the second is still taken
+    /// towards the earlier instant (floor), with a positive fraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::ArithmeticOverflow)` if the instant lies outside the range
+    /// representable by `chrono`, or `Err(DateError::GregorianConversionError)` if it lies outside
+    /// the supported Persian range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let dt = ParsiDateTime::from_nanos_since_unix_epoch(1_721_748_645_999_999_999).unwrap();
+    /// assert_eq!(dt, ParsiDateTime::new_with_nano(1403, 5, 2, 15, 30, 45, 999_999_999).unwrap());
+    ///
+    /// // Half a second before the epoch is the previous second plus half a second.
+    /// let before = ParsiDateTime::from_nanos_since_unix_epoch(-500_000_000).unwrap();
+    /// assert_eq!(before, ParsiDateTime::new_with_nano(1348, 10, 10, 23, 59, 59, 500_000_000).unwrap());
+    /// ```
+    pub fn from_nanos_since_unix_epoch(nanos: i128) -> Result<Self, DateError> {
+        let seconds = nanos.div_euclid(NANOS_PER_SECOND);
+        let nanosecond = nanos.rem_euclid(NANOS_PER_SECOND) as u32;
+        let days = seconds.div_euclid(86_400);
+        let seconds_of_day = seconds.rem_euclid(86_400) as u32;
+        let days = i64::try_from(days).map_err(|_| DateError::ArithmeticOverflow)?;
+
+        let epoch_date = unix_epoch().date();
+        let date = if days >= 0 {
+            epoch_date.checked_add_days(chrono::Days::new(days as u64))
+        } else {
+            epoch_date.checked_sub_days(chrono::Days::new(days.unsigned_abs()))
+        }
+        .ok_or(DateError::ArithmeticOverflow)?;
+        let gregorian = date
+            .and_hms_nano_opt(
+                seconds_of_day / 3600,
+                seconds_of_day % 3600 / 60,
+                seconds_of_day % 60,
+                nanosecond,
+            )
+            .ok_or(DateError::ArithmeticOverflow)?;
+        Self::from_gregorian(gregorian)
+    }
+
+    /// Creates a `ParsiDateTime` from a Unix timestamp in whole seconds, interpreting it as UTC.
+    ///
+    /// `ParsiDateTime` carries no time zone, so the result is the UTC wall-clock time of the
+    /// instant; use [`with_fixed_offset`](#method.with_fixed_offset) or the `timezone` feature for
+    /// local times. The nanosecond fraction of the result is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::GregorianConversionError)` if the instant lies before the Persian
+    /// epoch or outside the range representable by `chrono`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDateTime};
+    ///
+    /// let dt = ParsiDateTime::from_timestamp(1_721_748_645).unwrap();
+    /// assert_eq!(dt, ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap());
+    ///
+    /// assert_eq!(ParsiDateTime::from_timestamp(0).unwrap().to_string(), "1348/10/11 00:00:00");
+    /// assert_eq!(ParsiDateTime::from_timestamp(i64::MIN), Err(DateError::GregorianConversionError));
+    /// ```
+    pub fn from_timestamp(secs: i64) -> Result<Self, DateError> {
+        Self::from_nanos_since_unix_epoch(i128::from(secs) * NANOS_PER_SECOND).map_err(
+            |e| match e {
+                DateError::ArithmeticOverflow => DateError::GregorianConversionError,
+                other => other,
+            },
+        )
+    }
+
+    /// Returns the Unix timestamp in whole seconds, treating this date and time as UTC.
+    ///
+    /// This is the inverse of [`from_timestamp`](#method.from_timestamp). The nanosecond fraction
+    /// is dropped, so the result is the start of the second even before 1970 (like
+    /// `chrono`'s `timestamp`). Use [`nanos_since_unix_epoch`](#method.nanos_since_unix_epoch) to
+    /// keep the fraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` under the same conditions as [`to_gregorian`](#method.to_gregorian).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let dt = ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap(); // 2024-07-23 15:30:45 UTC
+    /// assert_eq!(dt.timestamp(), Ok(1_721_748_645));
+    ///
+    /// let before_epoch = ParsiDateTime::new_with_nano(1348, 10, 10, 23, 59, 59, 500).unwrap();
+    /// assert_eq!(before_epoch.timestamp(), Ok(-1));
+    /// ```
+    pub fn timestamp(&self) -> Result<i64, DateError> {
+        let seconds = self.nanos_since_unix_epoch()?.div_euclid(NANOS_PER_SECOND);
+        i64::try_from(seconds).map_err(|_| DateError::ArithmeticOverflow)
+    }
+
     /// Returns the current system date and time, converted to `ParsiDateTime`.
     ///
     /// This function obtains the current local date and time from the operating system
@@ -459,6 +684,126 @@ impl ParsiDateTime {
         Self::from_gregorian(naive_local)
     }
 
+    /// Checks whether the date part of this `ParsiDateTime` is today's date (system clock).
+    ///
+    /// The time-of-day is ignored. Errors while determining today's date are treated as `false`;
+    /// see [`ParsiDate::is_today_result`] for the `Result`-returning variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let now = ParsiDateTime::now().unwrap();
+    /// assert_eq!(now.is_today(), now.date().is_today()); // Only the date part matters
+    /// assert!(!ParsiDateTime::new(1300, 1, 1, 12, 0, 0).unwrap().is_today());
+    /// ```
+    pub fn is_today(&self) -> bool {
+        self.date.is_today()
+    }
+
+    /// Checks whether the date part of this `ParsiDateTime` is today's date, propagating errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by [`ParsiDate::today()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let past = ParsiDateTime::new(1300, 1, 1, 12, 0, 0).unwrap();
+    /// assert_eq!(past.is_today_result(), Ok(false));
+    /// ```
+    pub fn is_today_result(&self) -> Result<bool, DateError> {
+        self.date.is_today_result()
+    }
+
+    /// Returns `true` if this date-time is strictly before `other`.
+    ///
+    /// A named form of `self < other`. Together with [`is_after`](#method.is_after),
+    /// [`is_same_or_before`](#method.is_same_or_before) and
+    /// [`is_same_or_after`](#method.is_same_or_after), it makes an inclusive or exclusive
+    /// boundary explicit at the call site.
+    /// Both the date and the time-of-day take part in the comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let earlier = ParsiDateTime::new(1403, 5, 2, 23, 59, 59).unwrap();
+    /// let later = ParsiDateTime::new(1403, 5, 3, 0, 0, 0).unwrap();
+    /// assert!(earlier.is_before(&later));
+    /// assert!(!later.is_before(&earlier));
+    /// assert!(!earlier.is_before(&earlier));
+    /// ```
+    #[inline]
+    pub fn is_before(&self, other: &Self) -> bool {
+        self < other
+    }
+
+    /// Returns `true` if this date-time is strictly after `other`.
+    ///
+    /// A named form of `self > other`; see [`is_before`](#method.is_before).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let earlier = ParsiDateTime::new(1403, 5, 2, 23, 59, 59).unwrap();
+    /// let later = ParsiDateTime::new(1403, 5, 3, 0, 0, 0).unwrap();
+    /// assert!(later.is_after(&earlier));
+    /// assert!(!earlier.is_after(&later));
+    /// assert!(!later.is_after(&later));
+    /// ```
+    #[inline]
+    pub fn is_after(&self, other: &Self) -> bool {
+        self > other
+    }
+
+    /// Returns `true` if this date-time is the same as or before `other`.
+    ///
+    /// A named form of `self <= other`; see [`is_before`](#method.is_before).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let earlier = ParsiDateTime::new(1403, 5, 2, 23, 59, 59).unwrap();
+    /// let later = ParsiDateTime::new(1403, 5, 3, 0, 0, 0).unwrap();
+    /// assert!(earlier.is_same_or_before(&later));
+    /// assert!(earlier.is_same_or_before(&earlier));
+    /// assert!(!later.is_same_or_before(&earlier));
+    /// ```
+    #[inline]
+    pub fn is_same_or_before(&self, other: &Self) -> bool {
+        self <= other
+    }
+
+    /// Returns `true` if this date-time is the same as or after `other`.
+    ///
+    /// A named form of `self >= other`; see [`is_before`](#method.is_before).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let earlier = ParsiDateTime::new(1403, 5, 2, 23, 59, 59).unwrap();
+    /// let later = ParsiDateTime::new(1403, 5, 3, 0, 0, 0).unwrap();
+    /// assert!(later.is_same_or_after(&earlier));
+    /// assert!(later.is_same_or_after(&later));
+    /// assert!(!earlier.is_same_or_after(&later));
+    /// ```
+    #[inline]
+    pub fn is_same_or_after(&self, other: &Self) -> bool {
+        self >= other
+    }
+
     // --- Accessors ---
 
     /// Returns the [`ParsiDate`] component of this `ParsiDateTime`.
@@ -590,6 +935,24 @@ impl ParsiDateTime {
         self.second
     }
 
+    /// Returns the fraction of the second in nanoseconds (0-999,999,999).
+    ///
+    /// This is zero for values created from whole seconds, e.g. with [`new`](#method.new).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let dt = ParsiDateTime::new_with_nano(1403, 5, 2, 15, 30, 45, 500_000_000).unwrap();
+    /// assert_eq!(dt.nanosecond(), 500_000_000);
+    /// assert_eq!(ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap().nanosecond(), 0);
+    /// ```
+    #[inline]
+    pub const fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
     /// Returns the time components as a tuple `(hour, minute, second)`.
     ///
     /// # Examples
@@ -609,6 +972,94 @@ impl ParsiDateTime {
         (self.hour, self.minute, self.second)
     }
 
+    /// Returns the number of seconds elapsed since midnight (0-86399).
+    ///
+    /// This is `hour * 3600 + minute * 60 + second`, useful for bucketing by time of day.
+    /// The inverse is [`with_seconds_from_midnight`](#method.with_seconds_from_midnight).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let dt = ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap();
+    /// assert_eq!(dt.num_seconds_from_midnight(), 55_845);
+    /// assert_eq!(ParsiDateTime::new(1403, 5, 2, 0, 0, 0).unwrap().num_seconds_from_midnight(), 0);
+    /// ```
+    #[inline]
+    pub const fn num_seconds_from_midnight(&self) -> u32 {
+        self.hour * 3600 + self.minute * 60 + self.second
+    }
+
+    /// Returns the date and time components as a pair `(date, (hour, minute, second))`.
+    ///
+    /// This is the borrowing counterpart of [`into_parts`](#method.into_parts) and the inverse of
+    /// [`from_date_and_time`](#method.from_date_and_time).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, ParsiDateTime};
+    ///
+    /// let dt = ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap();
+    /// let (date, (h, m, s)) = dt.parts();
+    /// assert_eq!(date, ParsiDate::new(1403, 5, 2).unwrap());
+    /// assert_eq!((h, m, s), (15, 30, 45));
+    /// ```
+    #[inline]
+    pub const fn parts(&self) -> (ParsiDate, (u32, u32, u32)) {
+        (self.date, (self.hour, self.minute, self.second))
+    }
+
+    /// Consumes the `ParsiDateTime` and splits it into its date and `(hour, minute, second)` time.
+    ///
+    /// Useful when the date and time are processed or stored separately (e.g., in separate
+    /// database columns). The original value can be rebuilt with
+    /// [`from_date_and_time`](#method.from_date_and_time).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let dt = ParsiDateTime::new(1403, 12, 30, 23, 59, 59).unwrap();
+    /// let (date, (hour, minute, second)) = dt.into_parts();
+    /// assert_eq!(date.format("iso"), "1403-12-30");
+    ///
+    /// let rebuilt = ParsiDateTime::from_date_and_time(date, hour, minute, second).unwrap();
+    /// assert_eq!(rebuilt, dt);
+    /// ```
+    #[inline]
+    pub const fn into_parts(self) -> (ParsiDate, (u32, u32, u32)) {
+        self.parts()
+    }
+
+    /// Returns all components as a tuple `(year, month, day, hour, minute, second)`.
+    ///
+    /// Convenient for destructuring, logging, or passing the components to other APIs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let dt = ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap();
+    /// let (year, month, day, hour, minute, second) = dt.as_tuple();
+    /// assert_eq!((year, month, day), (1403, 5, 2));
+    /// assert_eq!((hour, minute, second), (15, 30, 45));
+    /// ```
+    #[inline]
+    pub const fn as_tuple(&self) -> (i32, u32, u32, u32, u32, u32) {
+        (
+            self.date.year(),
+            self.date.month(),
+            self.date.day(),
+            self.hour,
+            self.minute,
+            self.second,
+        )
+    }
+
     // --- Season Accessor --- //
 
     /// Returns the Persian season this `ParsiDateTime`'s date falls into.
@@ -638,8 +1089,8 @@ impl ParsiDateTime {
     /// This method checks two conditions:
     /// 1.  Whether the internal `ParsiDate` component is valid (using [`ParsiDate::is_valid`]).
     ///     This verifies the year, month, and day combination (e.g., day is within month bounds, considers leap years).
-    /// 2.  Whether the time components (`hour`, `minute`, `second`) are within their standard ranges
-    ///     (H: 0-23, M: 0-59, S: 0-59).
+    /// 2.  Whether the time components (`hour`, `minute`, `second`, `nanosecond`) are within their
+    ///     standard ranges (H: 0-23, M: 0-59, S: 0-59, nanoseconds below 1,000,000,000).
     ///
     /// This is particularly useful after creating an instance using `unsafe fn new_unchecked` or
     /// if the validity is otherwise uncertain. Instances created with `new`, `from_date_and_time`,
@@ -677,6 +1128,7 @@ impl ParsiDateTime {
             && self.hour <= 23
             && self.minute <= 59
             && self.second <= 59
+            && self.nanosecond <= 999_999_999
     }
 
     /// Calculates the week number of the year for this date-time's date component.
@@ -703,6 +1155,46 @@ impl ParsiDateTime {
         self.date.week_of_year() // Delegate to the ParsiDate method
     }
 
+    /// Returns the fraction of the Persian year that has elapsed at this exact date and time.
+    ///
+    /// Unlike [`ParsiDate::year_progress`], which counts whole days up to the *end* of the date,
+    /// this method counts the completed days before this date plus the elapsed fraction of the
+    /// current day: `(ordinal - 1 + seconds_since_midnight / 86400) / days_in_year`.
+    /// The result lies in the range `[0.0, 1.0)`; Farvardin 1st at 00:00:00 yields `0.0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` or `Err(DateError::InvalidTime)` if the instance is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let start = ParsiDateTime::new(1403, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(start.year_progress(), Ok(0.0));
+    ///
+    /// // Noon on Farvardin 1st of a leap year: half a day out of 366
+    /// let noon = ParsiDateTime::new(1403, 1, 1, 12, 0, 0).unwrap();
+    /// assert!((noon.year_progress().unwrap() - 0.5 / 366.0).abs() < 1e-12);
+    ///
+    /// let last_second = ParsiDateTime::new(1404, 12, 29, 23, 59, 59).unwrap();
+    /// assert!(last_second.year_progress().unwrap() < 1.0);
+    /// ```
+    pub fn year_progress(&self) -> Result<f64, DateError> {
+        if !self.is_valid() {
+            return Err(if !self.date.is_valid() {
+                DateError::InvalidDate
+            } else {
+                DateError::InvalidTime
+            });
+        }
+        let ordinal = self.date.ordinal()?;
+        let seconds_of_day = f64::from(self.num_seconds_from_midnight());
+        let elapsed_days = f64::from(ordinal - 1) + seconds_of_day / 86_400.0;
+        Ok(elapsed_days / f64::from(ParsiDate::days_in_year(self.date.year())))
+    }
+
     // --- Formatting ---
 
     /// Formats the `ParsiDateTime` into a string according to a given format pattern.
@@ -721,15 +1213,31 @@ impl ParsiDateTime {
     ///
     /// **Date Specifiers (inherited from `ParsiDate`):**
     ///
-    /// *   `%Y`: Year with century (e.g., `1403`).
+    /// *   `%Y`: Year without padding (e.g., `1403`, or `50` for year 50). Same as `%-Y`.
+    /// *   `%0Y`, `%4Y`: Year zero-padded to 4 digits (e.g., `0050` for year 50).
+    /// *   `%EY`: Year spelled out in Persian words (e.g., `هزار و چهارصد و سه`).
+    /// *   `%Ee`: The era marker of the Solar Hijri calendar, `ه.ش`. Also accepted by parsing.
+    /// *   `%?L`: `کبیسه` if the year is a leap year, nothing otherwise (surrounding literal text,
+    ///     such as a preceding space, is kept).
+    /// *   `%Eg`: The Gregorian date in parentheses as `(YYYY-MM-DD)` (e.g., `(2024-07-23)`). Only the
+    ///     date part is converted, once per format call. Requires date to be valid.
     /// *   `%m`: Month as a zero-padded number (01-12).
     /// *   `%d`: Day of the month as a zero-padded number (01-31).
     /// *   `%B`: Full Persian month name (e.g., "فروردین", "مرداد"). Requires month to be valid.
     /// *   `%A`: Full Persian weekday name (e.g., "شنبه", "سه‌شنبه"). Requires date to be valid.
+    /// *   `%b`: Month name in Latin letters (Finglish, e.g., "Farvardin", "Mordad").
+    /// *   `%a`: Weekday name in Latin letters (Finglish, e.g., "Shanbeh", "Seshanbeh"). Requires
+    ///     date to be valid.
     /// *   `%w`: Weekday as a number (Saturday=0, Sunday=1, ..., Friday=6). Requires date to be valid.
     /// *   `%j`: Day of the year as a zero-padded number (001-365 or 366). Requires date to be valid.
     /// *   `%K`: Full Persian season name (e.g., "تابستان"). Requires date to be valid.
+    /// *   `%q`: Quarter of the year (1-4). Requires date to be valid.
     /// *   `%W`: Week number of the year (Saturday start, 01-53). Requires date to be valid.
+    /// *   `%GA`, `%Ga`: English (Gregorian) weekday name, full (`Tuesday`) or abbreviated (`Tue`).
+    ///     Shares the Gregorian conversion of `%Eg`. Requires date to be valid.
+    /// *   `%G`: Week-based year (see [`ParsiDate::iso_week`]). Requires date to be valid. Quote a
+    ///     following literal `a` or `A` (`%G'a'`) to keep it from being read as `%Ga`/`%GA`.
+    /// *   `%V`: Week of the week-based year (01-53). Requires date to be valid.
     /// *   `%%`: A literal percent sign (`%`).
     ///
     /// **Time Specifiers:**
@@ -738,6 +1246,14 @@ impl ParsiDateTime {
     /// *   `%M`: Minute as a zero-padded number (00-59).
     /// *   `%S`: Second as a zero-padded number (00-59).
     /// *   `%T`: Equivalent to `%H:%M:%S`.
+    /// *   `%f`: Fraction of the second as 9 zero-padded digits of nanoseconds (e.g., `%T.%f` gives
+    ///     `15:30:45.123456789`).
+    /// *   `%I`: Hour (12-hour clock) as a zero-padded number (01-12); midnight is `12` AM.
+    /// *   `%p`: Persian AM/PM marker, `ق.ظ` before noon and `ب.ظ` from noon on.
+    /// *   `%Ep`: English AM/PM marker, `AM` or `PM`.
+    ///
+    /// **Quoted literals:** Text between single quotes (`'...'`) is emitted verbatim without
+    /// interpreting specifiers; `''` produces a literal single quote.
     ///
     /// **Note:** If the `ParsiDateTime` instance contains invalid date or time components
     /// (e.g., created via `new_unchecked`), the output for the corresponding specifiers
@@ -780,6 +1296,9 @@ impl ParsiDateTime {
     /// // Literal percent sign
     /// assert_eq!(dt.format("Time is %H:%M %% %S seconds"), "Time is 08:05 % 30 seconds");
     ///
+    /// // Quoted literal block
+    /// assert_eq!(dt.format("%H 'ساعت و' %M 'دقیقه'"), "08 ساعت و 05 دقیقه");
+    ///
     /// // Formatting an invalid time (created unsafely)
     /// let invalid_dt = unsafe { ParsiDateTime::new_unchecked(1403, 1, 1, 25, 61, 99) };
     /// assert_eq!(invalid_dt.format("%H:%M:%S"), "25:61:99"); // Prints the invalid numbers
@@ -796,6 +1315,8 @@ impl ParsiDateTime {
         let mut weekday_num_cache: Option<Result<u32, DateError>> = None;
         let mut season_cache: Option<Result<Season, DateError>> = None;
         let mut week_of_year_cache: Option<Result<u32, DateError>> = None;
+        let mut iso_week_cache: Option<Result<(i32, u32), DateError>> = None;
+        let mut gregorian_cache: Option<Result<NaiveDate, DateError>> = None;
 
         while let Some(c) = chars.next() {
             if c == '%' {
@@ -809,21 +1330,68 @@ impl ParsiDateTime {
                         "{:02}:{:02}:{:02}",
                         self.hour, self.minute, self.second
                     )),
+                    Some('f') => result.push_str(&format!("{:09}", self.nanosecond)),
+                    Some('I') => result.push_str(&format!("{:02}", (self.hour + 11) % 12 + 1)),
+                    Some('p') => result.push_str(AM_PM_MARKERS[usize::from(self.hour >= 12)]),
+                    Some('E') if chars.peek() == Some(&'p') => {
+                        chars.next(); // Consume 'p'
+                        result.push_str(AM_PM_MARKERS[2 + usize::from(self.hour >= 12)]);
+                    }
 
                     // --- Date Specifiers (using self.date() or direct access) ---
                     Some('%') => result.push('%'),
                     Some('Y') => result.push_str(&self.year().to_string()),
-                    Some('m') => result.push_str(&format!("{:02}", self.month())),
-                    Some('d') => result.push_str(&format!("{:02}", self.day())),
-                    Some('B') => {
-                        let month_index = self.month().saturating_sub(1) as usize;
-                        if let Some(name) = MONTH_NAMES_PERSIAN.get(month_index) {
-                            result.push_str(name);
-                        } else {
-                            result.push_str("?InvalidMonth?");
-                        }
+                    Some('-') if chars.peek() == Some(&'Y') => {
+                        chars.next(); // Consume 'Y'
+                        result.push_str(&self.year().to_string());
                     }
-                    Some('A') => {
+                    Some('0' | '4') if chars.peek() == Some(&'Y') => {
+                        chars.next(); // Consume 'Y'
+                        result.push_str(&format!("{:04}", self.year()));
+                    }
+                    Some('E') if chars.peek() == Some(&'Y') => {
+                        chars.next(); // Consume 'Y'
+                        result.push_str(&self.date.year_in_words_persian());
+                    }
+                    Some('E') if chars.peek() == Some(&'e') => {
+                        chars.next(); // Consume 'e'
+                        result.push_str(ERA_MARKER_PERSIAN);
+                    }
+                    Some('?') if chars.peek() == Some(&'L') => {
+                        chars.next(); // Consume 'L'
+                        result.push_str(self.date.leap_year_marker());
+                    }
+                    Some('E') if chars.peek() == Some(&'g') => {
+                        chars.next(); // Consume 'g'
+                        if gregorian_cache.is_none() {
+                            gregorian_cache = Some(self.date.to_gregorian());
+                        }
+                        match gregorian_cache.as_ref().unwrap() {
+                            Ok(g) => result.push_str(&format!("({})", g.format("%Y-%m-%d"))),
+                            Err(_) => result.push_str("?GregorianError?"),
+                        }
+                    }
+                    Some('G') if matches!(chars.peek(), Some('A' | 'a')) => {
+                        let abbreviated = chars.next() == Some('a');
+                        if gregorian_cache.is_none() {
+                            gregorian_cache = Some(self.date.to_gregorian());
+                        }
+                        match gregorian_cache.as_ref().unwrap() {
+                            Ok(g) => result.push_str(english_weekday_name(g, abbreviated)),
+                            Err(_) => result.push_str("?WeekdayError?"),
+                        }
+                    }
+                    Some('m') => result.push_str(&format!("{:02}", self.month())),
+                    Some('d') => result.push_str(&format!("{:02}", self.day())),
+                    Some('B') => {
+                        let month_index = self.month().saturating_sub(1) as usize;
+                        if let Some(name) = MONTH_NAMES_PERSIAN.get(month_index) {
+                            result.push_str(name);
+                        } else {
+                            result.push_str("?InvalidMonth?");
+                        }
+                    }
+                    Some('A') => {
                         if weekday_name_cache.is_none() {
                             weekday_name_cache = Some(self.date.weekday_internal());
                         }
@@ -832,6 +1400,28 @@ impl ParsiDateTime {
                             Err(_) => result.push_str("?WeekdayError?"),
                         }
                     }
+                    Some('b') => {
+                        let month_index = self.month().wrapping_sub(1) as usize;
+                        match MONTH_NAMES_FINGLISH.get(month_index) {
+                            Some(name) => result.push_str(name),
+                            None => result.push_str("?InvalidMonth?"),
+                        }
+                    }
+                    Some('a') => {
+                        if weekday_num_cache.is_none() {
+                            weekday_num_cache = Some(self.date.weekday_num_sat_0());
+                        }
+                        match weekday_num_cache
+                            .as_ref()
+                            .unwrap()
+                            .as_ref()
+                            .ok()
+                            .and_then(|num| WEEKDAY_NAMES_FINGLISH.get(*num as usize))
+                        {
+                            Some(name) => result.push_str(name),
+                            None => result.push_str("?WeekdayError?"),
+                        }
+                    }
                     Some('w') => {
                         if weekday_num_cache.is_none() {
                             weekday_num_cache = Some(self.date.weekday_num_sat_0());
@@ -860,6 +1450,11 @@ impl ParsiDateTime {
                             Err(_) => result.push_str("?SeasonError?"),
                         }
                     }
+                    // --- Quarter '%q' --- //
+                    Some('q') => match self.date.quarter() {
+                        Ok(quarter) => result.push_str(&quarter.to_string()),
+                        Err(_) => result.push('?'),
+                    },
                     // --- Week of Year '%W' --- //
                     Some('W') => {
                         if week_of_year_cache.is_none() {
@@ -871,6 +1466,17 @@ impl ParsiDateTime {
                             Err(_) => result.push_str("?WeekError?"),
                         }
                     }
+                    // --- Week-based Year '%G' and Week '%V' --- //
+                    Some(spec @ ('G' | 'V')) => {
+                        if iso_week_cache.is_none() {
+                            iso_week_cache = Some(self.date.iso_week());
+                        }
+                        match (spec, iso_week_cache.as_ref().unwrap()) {
+                            ('G', Ok((week_year, _))) => result.push_str(&week_year.to_string()),
+                            (_, Ok((_, week))) => result.push_str(&format!("{:02}", week)),
+                            (_, Err(_)) => result.push_str("?WeekError?"),
+                        }
+                    }
 
                     // --- Unrecognized or Unsupported Specifier ---
                     Some(other) => {
@@ -883,6 +1489,9 @@ impl ParsiDateTime {
                         break;
                     }
                 }
+            } else if c == '\'' {
+                // Quoted literal block ('...') or escaped quote ('').
+                ParsiDate::push_quoted_literal(&mut chars, &mut result);
             } else {
                 // It's a literal character
                 result.push(c);
@@ -907,7 +1516,8 @@ impl ParsiDateTime {
     ///
     /// # Supported Format Specifiers for Parsing
     ///
-    /// *   `%Y`: Parses a 4-digit Persian year.
+    /// *   `%Y`, `%4Y`: Parses exactly 4 digits as the Persian year (zero-padded below 1000). A fifth
+    ///     digit is never consumed, so adjacent numeric fields need no separator.
     /// *   `%m`: Parses a 2-digit month (01-12).
     /// *   `%d`: Parses a 2-digit day (01-31).
     /// *   `%B`: Parses a full Persian month name (case-sensitive, must match names in `MONTH_NAMES_PERSIAN`, e.g., "فروردین").
@@ -915,6 +1525,16 @@ impl ParsiDateTime {
     /// *   `%M`: Parses a 2-digit minute (00-59).
     /// *   `%S`: Parses a 2-digit second (00-59).
     /// *   `%T`: Parses time in the exact format "HH:MM:SS" (e.g., "15:30:05").
+    /// *   `%f`: Parses the fraction of the second as exactly 9 digits of nanoseconds, as written by
+    ///     [`format`](#method.format) (e.g., `%T.%f` accepts "15:30:05.250000000"). Without `%f`
+    ///     the fraction is zero.
+    /// *   `%I`: Parses a 2-digit hour on the 12-hour clock (01-12). Must be combined with `%p`.
+    /// *   `%p`, `%Ep`: Parse an AM/PM marker: Persian "ق.ظ" (AM) / "ب.ظ" (PM), or "AM"/"PM" (upper or
+    ///     lower case). Combined with `%I`, 12 AM resolves to hour 0 and 12 PM to hour 12.
+    /// *   `%-m`, `%-d`, `%-H`, `%-M`, `%-S`: Parse a month, day, hour, minute, or second written with
+    ///     1 or 2 digits (e.g., "1403/5/2 9:5:3"). Digits are consumed greedily up to two, so a
+    ///     separator should follow these fields.
+    /// *   `%-Y`: Parses a year written with 1 to 4 digits.
     /// *   `%%`: Matches a literal percent sign (`%`) in the input string.
     ///
     /// **Unsupported Specifiers:** Specifiers like `%A`, `%w`, `%j`, `%K`, `%W` are *not* supported for parsing
@@ -932,9 +1552,13 @@ impl ParsiDateTime {
     /// *   `ParseErrorKind::FormatMismatch`: The input string `s` does not match the literal characters or overall structure defined by the `format` string, or expected components are missing, or there are trailing characters in `s`.
     /// *   `ParseErrorKind::InvalidNumber`: A numeric component (Year, Month, Day, Hour, Minute, Second) could not be parsed as a number, or it did not have the expected number of digits (e.g., `%m` expects exactly two digits).
     /// *   `ParseErrorKind::InvalidMonthName`: The input string did not contain a valid, recognized Persian month name where `%B` was expected.
+    /// *   `ParseErrorKind::InvalidAmPmMarker`: The input did not contain a recognized AM/PM marker where `%p` was expected.
+    /// *   `ParseErrorKind::IncompleteTwelveHourTime`: The format used `%I` without `%p` (or `%Ep`), or a marker without `%I`.
     /// *   `ParseErrorKind::UnsupportedSpecifier`: The `format` string contained a specifier not supported for parsing (e.g., `%A`, `%j`, `%K`). // <-- Added %K here
     /// *   `ParseErrorKind::InvalidDateValue`: The extracted year, month, and day values were syntactically valid but do not form a logically valid Persian date (e.g., "1404/12/30" - day 30 in Esfand of a non-leap year). This is checked by the final call to `ParsiDateTime::new`.
     /// *   `ParseErrorKind::InvalidTimeValue`: The extracted hour, minute, or second values were syntactically valid but outside their allowed ranges (e.g., Hour 24, Minute 60). This is checked by the final call to `ParsiDateTime::new`.
+    ///     Leap seconds are not modeled, so a second value of `60` is always rejected; use
+    ///     [`parse_allowing_leap_second`](#method.parse_allowing_leap_second) to accept it.
     ///
     /// # Examples
     ///
@@ -959,6 +1583,14 @@ impl ParsiDateTime {
     /// let expected3 = ParsiDateTime::new(1399, 11, 22, 23, 59, 59).unwrap();
     /// assert_eq!(ParsiDateTime::parse(s3, fmt3), Ok(expected3));
     ///
+    /// // Non-padded time fields (%-H, %-M, %-S)
+    /// let expected4 = ParsiDateTime::new(1403, 5, 2, 9, 5, 3).unwrap();
+    /// assert_eq!(ParsiDateTime::parse("1403/05/02 9:5:3", "%Y/%m/%d %-H:%-M:%-S"), Ok(expected4));
+    ///
+    /// // 12-hour clock with a Persian AM/PM marker
+    /// let expected5 = ParsiDateTime::new(1403, 5, 2, 14, 30, 0).unwrap();
+    /// assert_eq!(ParsiDateTime::parse("1403/05/02 02:30:00 ب.ظ", "%Y/%m/%d %I:%M:%S %p"), Ok(expected5));
+    ///
     /// // --- Error Cases ---
     /// // Invalid time value (hour 24)
     /// assert_eq!(ParsiDateTime::parse("1403/05/02 24:00:00", fmt1),
@@ -993,6 +1625,73 @@ impl ParsiDateTime {
     ///            Err(DateError::ParseError(ParseErrorKind::UnsupportedSpecifier)));
     /// ```
     pub fn parse(s: &str, format: &str) -> Result<Self, DateError> {
+        Self::parse_internal(s, format, false)
+    }
+
+    /// Parses a date and time like [`parse`](#method.parse), but accepts a leap second (`:60`).
+    ///
+    /// `ParsiDateTime` does not model leap seconds, so the strict [`parse`](#method.parse) always
+    /// rejects a second value of `60` with `ParseErrorKind::InvalidTimeValue`. Data from
+    /// leap-second-aware sources (e.g., UTC/NTP-derived logs) may nevertheless contain
+    /// timestamps such as `23:59:60`. This variant clamps a parsed second value of exactly `60`
+    /// to `59`, keeping the result within the same minute (and therefore the same day). All other
+    /// values, including `61` and above, are validated exactly as in `parse`.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The input string slice to be parsed.
+    /// * `format`: The format string, with the same specifiers as [`parse`](#method.parse).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`parse`](#method.parse), except for a leap second.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDateTime, DateError, ParseErrorKind};
+    ///
+    /// let input = "1403/10/11 03:29:60";
+    /// assert_eq!(
+    ///     ParsiDateTime::parse(input, "%Y/%m/%d %T"),
+    ///     Err(DateError::ParseError(ParseErrorKind::InvalidTimeValue)) // Strict by default
+    /// );
+    /// assert_eq!(
+    ///     ParsiDateTime::parse_allowing_leap_second(input, "%Y/%m/%d %T"),
+    ///     Ok(ParsiDateTime::new(1403, 10, 11, 3, 29, 59).unwrap())
+    /// );
+    /// ```
+    pub fn parse_allowing_leap_second(s: &str, format: &str) -> Result<Self, DateError> {
+        Self::parse_internal(s, format, true)
+    }
+
+    /// Parses a date and time like [`parse`](#method.parse), also accepting Persian (`۰`-`۹`)
+    /// and Arabic-Indic (`٠`-`٩`) digits.
+    ///
+    /// The digits are normalized to ASCII before parsing, as in [`ParsiDate::parse_localized`];
+    /// the strict [`parse`](#method.parse) only accepts ASCII digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`parse`](#method.parse) for the normalized input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// assert_eq!(
+    ///     ParsiDateTime::parse_localized("۱۴۰۳/۰۵/۰۲ ۱۵:۳۰:۴۵", "%Y/%m/%d %H:%M:%S"),
+    ///     Ok(ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap())
+    /// );
+    /// ```
+    pub fn parse_localized(s: &str, format: &str) -> Result<Self, DateError> {
+        Self::parse(&normalize_digits(s), &normalize_digits(format))
+    }
+
+    /// **Internal**: Shared implementation of [`parse`](#method.parse) and
+    /// [`parse_allowing_leap_second`](#method.parse_allowing_leap_second).
+    fn parse_internal(s: &str, format: &str, allow_leap_second: bool) -> Result<Self, DateError> {
         // Options to store the parsed components. They start as None.
         let mut parsed_year: Option<i32> = None;
         let mut parsed_month: Option<u32> = None;
@@ -1000,6 +1699,10 @@ impl ParsiDateTime {
         let mut parsed_hour: Option<u32> = None;
         let mut parsed_minute: Option<u32> = None;
         let mut parsed_second: Option<u32> = None;
+        let mut parsed_nanosecond: Option<u32> = None;
+        // 12-hour clock components ('%I' and '%p'), combined into `parsed_hour` at the end.
+        let mut parsed_hour12: Option<u32> = None;
+        let mut parsed_is_pm: Option<bool> = None;
 
         // Use byte slices for efficient processing of ASCII parts of the format and input.
         let mut s_bytes = s.as_bytes();
@@ -1030,6 +1733,59 @@ impl ParsiDateTime {
                         s_bytes = &s_bytes[2..];
                         fmt_bytes = &fmt_bytes[2..];
                     }
+                    // Fraction of the second, as written by '%f' (exactly 9 digits)
+                    b'f' => {
+                        if s_bytes.len() < 9 || !s_bytes[0..9].iter().all(|b| b.is_ascii_digit()) {
+                            return Err(DateError::ParseError(ParseErrorKind::InvalidNumber));
+                        }
+                        let (val, _) = ParsiDate::parse_variable_width_number(s_bytes, 9, 9)?;
+                        parsed_nanosecond = Some(val);
+                        s_bytes = &s_bytes[9..];
+                        fmt_bytes = &fmt_bytes[2..];
+                    }
+                    // 12-hour clock hour (01-12)
+                    b'I' => {
+                        let (val, _) = ParsiDate::parse_variable_width_number(s_bytes, 2, 2)?;
+                        parsed_hour12 = Some(val);
+                        s_bytes = &s_bytes[2..];
+                        fmt_bytes = &fmt_bytes[2..];
+                    }
+                    // AM/PM marker
+                    b'p' | b'E' if fmt_bytes[1] == b'p' || fmt_bytes.get(2) == Some(&b'p') => {
+                        let (marker_idx, marker_len) =
+                            ParsiDate::match_name_prefix(s_bytes, &AM_PM_MARKERS)
+                                .ok_or(DateError::ParseError(ParseErrorKind::InvalidAmPmMarker))?;
+                        parsed_is_pm = Some(marker_idx % 2 == 1);
+                        s_bytes = &s_bytes[marker_len..];
+                        fmt_bytes = &fmt_bytes[if fmt_bytes[1] == b'E' { 3 } else { 2 }..];
+                    }
+                    // Non-padded fields ('%-Y': 1-4 digits; '%-m', '%-d', '%-H', '%-M', '%-S': 1-2)
+                    b'-' => {
+                        let field = match fmt_bytes.get(2) {
+                            Some(&f @ (b'Y' | b'm' | b'd' | b'H' | b'M' | b'S')) => f,
+                            Some(_) => {
+                                return Err(DateError::ParseError(
+                                    ParseErrorKind::UnsupportedSpecifier,
+                                ))
+                            }
+                            None => {
+                                return Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+                            }
+                        };
+                        let max_digits = if field == b'Y' { 4 } else { 2 };
+                        let (val, consumed) =
+                            ParsiDate::parse_variable_width_number(s_bytes, 1, max_digits)?;
+                        match field {
+                            b'Y' => parsed_year = Some(val as i32),
+                            b'm' => parsed_month = Some(val),
+                            b'd' => parsed_day = Some(val),
+                            b'H' => parsed_hour = Some(val),
+                            b'M' => parsed_minute = Some(val),
+                            _ => parsed_second = Some(val),
+                        }
+                        s_bytes = &s_bytes[consumed..];
+                        fmt_bytes = &fmt_bytes[3..];
+                    }
                     b'T' => {
                         if s_bytes.len() < 8
                             || !s_bytes[0..2].iter().all(|b| b.is_ascii_digit())
@@ -1066,7 +1822,7 @@ impl ParsiDateTime {
                         s_bytes = &s_bytes[1..];
                         fmt_bytes = &fmt_bytes[2..];
                     }
-                    b'Y' => {
+                    b'Y' | b'4' if fmt_bytes[1] == b'Y' || fmt_bytes.get(2) == Some(&b'Y') => {
                         if s_bytes.len() < 4 || !s_bytes[0..4].iter().all(|b| b.is_ascii_digit()) {
                             return Err(DateError::ParseError(ParseErrorKind::InvalidNumber));
                         }
@@ -1076,7 +1832,8 @@ impl ParsiDateTime {
                                 DateError::ParseError(ParseErrorKind::InvalidNumber)
                             })?);
                         s_bytes = &s_bytes[4..];
-                        fmt_bytes = &fmt_bytes[2..];
+                        // Skip '%Y' or '%4Y'
+                        fmt_bytes = &fmt_bytes[if fmt_bytes[1] == b'4' { 3 } else { 2 }..];
                     }
                     b'm' | b'd' => {
                         if s_bytes.len() < 2 || !s_bytes[0..2].iter().all(|b| b.is_ascii_digit()) {
@@ -1094,32 +1851,21 @@ impl ParsiDateTime {
                         s_bytes = &s_bytes[2..];
                         fmt_bytes = &fmt_bytes[2..];
                     }
-                    b'B' => {
-                        fmt_bytes = &fmt_bytes[2..];
-                        let mut found_month = false;
-                        let mut best_match_len = 0;
-                        let mut matched_month_idx = 0;
-                        let current_s_str = match std::str::from_utf8(s_bytes) {
-                            Ok(s_str) => s_str,
-                            Err(_) => {
-                                return Err(DateError::ParseError(
-                                    ParseErrorKind::InvalidMonthName,
-                                ));
-                            }
-                        };
-                        for (idx, month_name) in MONTH_NAMES_PERSIAN.iter().enumerate() {
-                            if current_s_str.starts_with(month_name) {
-                                best_match_len = month_name.len();
-                                matched_month_idx = idx;
-                                found_month = true;
-                                break;
-                            }
-                        }
-                        if !found_month {
-                            return Err(DateError::ParseError(ParseErrorKind::InvalidMonthName));
+                    b'E' if fmt_bytes.get(2) == Some(&b'e') => {
+                        if !s_bytes.starts_with(ERA_MARKER_PERSIAN.as_bytes()) {
+                            return Err(DateError::ParseError(ParseErrorKind::FormatMismatch));
                         }
+                        s_bytes = &s_bytes[ERA_MARKER_PERSIAN.len()..];
+                        fmt_bytes = &fmt_bytes[3..];
+                    }
+                    b'B' => {
+                        // Bounded, longest-prefix month name match (see ParsiDate::parse).
+                        let (matched_month_idx, matched_len) =
+                            ParsiDate::match_name_prefix(s_bytes, &MONTH_NAMES_PERSIAN)
+                                .ok_or(DateError::ParseError(ParseErrorKind::InvalidMonthName))?;
                         parsed_month = Some((matched_month_idx + 1) as u32);
-                        s_bytes = &s_bytes[best_match_len..];
+                        s_bytes = &s_bytes[matched_len..];
+                        fmt_bytes = &fmt_bytes[2..];
                     }
                     // Unsupported for parsing
                     b'A' | b'w' | b'j' | b'K' | b'W' => {
@@ -1142,6 +1888,28 @@ impl ParsiDateTime {
             return Err(DateError::ParseError(ParseErrorKind::FormatMismatch));
         }
 
+        // Resolve a 12-hour clock reading into the 24-hour value.
+        // 12 AM is midnight (hour 0) and 12 PM is noon (hour 12).
+        match (parsed_hour12, parsed_is_pm) {
+            (Some(h12), Some(is_pm)) => {
+                if parsed_hour.is_some() {
+                    // The hour was given twice ('%H' and '%I').
+                    return Err(DateError::ParseError(ParseErrorKind::FormatMismatch));
+                }
+                if !(1..=12).contains(&h12) {
+                    return Err(DateError::ParseError(ParseErrorKind::InvalidTimeValue));
+                }
+                parsed_hour = Some(h12 % 12 + if is_pm { 12 } else { 0 });
+            }
+            // A 12-hour value without a marker (or vice versa) cannot be resolved.
+            (Some(_), None) | (None, Some(_)) => {
+                return Err(DateError::ParseError(
+                    ParseErrorKind::IncompleteTwelveHourTime,
+                ));
+            }
+            (None, None) => {}
+        }
+
         // Final validation and construction
         match (
             parsed_year,
@@ -1152,7 +1920,10 @@ impl ParsiDateTime {
             parsed_second,
         ) {
             (Some(y), Some(m), Some(d), Some(h), Some(min), Some(s)) => {
-                ParsiDateTime::new(y, m, d, h, min, s).map_err(|e| match e {
+                // A leap second (":60") is only accepted on request, clamped to ":59".
+                let s = if allow_leap_second && s == 60 { 59 } else { s };
+                let nano = parsed_nanosecond.unwrap_or(0);
+                ParsiDateTime::new_with_nano(y, m, d, h, min, s, nano).map_err(|e| match e {
                     DateError::InvalidDate => {
                         DateError::ParseError(ParseErrorKind::InvalidDateValue)
                     }
@@ -1239,6 +2010,290 @@ impl ParsiDateTime {
         self.add_duration(-duration)
     }
 
+    /// Adds a `chrono::Duration` to this `ParsiDateTime`, returning `None` on failure.
+    ///
+    /// This is the `Option`-returning counterpart of [`add_duration`](#method.add_duration),
+    /// mirroring chrono's `NaiveDateTime::checked_add_signed`. It composes well with
+    /// `unwrap_or` fallbacks and chrono-style code.
+    ///
+    /// # Returns
+    ///
+    /// `Some(result)` on success, or `None` if `self` is invalid, the result overflows chrono's
+    /// range, or the result falls outside the supported Persian year range.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    /// use chrono::Duration;
+    ///
+    /// let dt = ParsiDateTime::new(1403, 12, 30, 23, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     dt.checked_add_duration(Duration::hours(2)),
+    ///     Some(ParsiDateTime::new(1404, 1, 1, 1, 0, 0).unwrap())
+    /// );
+    ///
+    /// let near_max = ParsiDateTime::new(9999, 12, 29, 23, 59, 59).unwrap();
+    /// assert_eq!(near_max.checked_add_duration(Duration::seconds(1)), None);
+    ///
+    /// let invalid = unsafe { ParsiDateTime::new_unchecked(1403, 1, 1, 24, 0, 0) };
+    /// assert_eq!(invalid.checked_add_duration(Duration::seconds(1)), None);
+    /// ```
+    pub fn checked_add_duration(&self, duration: Duration) -> Option<Self> {
+        self.add_duration(duration).ok()
+    }
+
+    /// Subtracts a `chrono::Duration` from this `ParsiDateTime`, returning `None` on failure.
+    ///
+    /// This is the `Option`-returning counterpart of [`sub_duration`](#method.sub_duration),
+    /// mirroring chrono's `NaiveDateTime::checked_sub_signed`.
+    ///
+    /// # Returns
+    ///
+    /// `Some(result)` on success, or `None` if `self` is invalid or the result is out of range.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    /// use chrono::Duration;
+    ///
+    /// let dt = ParsiDateTime::new(1403, 1, 1, 0, 0, 5).unwrap();
+    /// assert_eq!(
+    ///     dt.checked_sub_duration(Duration::seconds(10)),
+    ///     Some(ParsiDateTime::new(1402, 12, 29, 23, 59, 55).unwrap())
+    /// );
+    ///
+    /// let min = ParsiDateTime::new(1, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(min.checked_sub_duration(Duration::seconds(1)), None);
+    /// ```
+    pub fn checked_sub_duration(&self, duration: Duration) -> Option<Self> {
+        if !self.is_valid() {
+            return None;
+        }
+        // Use chrono's checked subtraction directly to avoid negating the duration.
+        let new_gregorian_dt = self.to_gregorian().ok()?.checked_sub_signed(duration)?;
+        Self::from_gregorian(new_gregorian_dt).ok()
+    }
+
+    /// Shifts the wall-clock time by a fixed UTC offset, given in seconds.
+    ///
+    /// This is a lightweight alternative to the `timezone` feature for the common case of a fixed
+    /// offset, such as converting a UTC timestamp to Iran Standard Time (`+03:30`, i.e. `12600`
+    /// seconds). It is a **simple shift** of the naive value by `offset_seconds`, equivalent to
+    /// [`add_duration`](#method.add_duration) with `Duration::seconds(offset_seconds)`; day, month
+    /// and year rollovers are handled accordingly. No time zone rules (such as historical daylight
+    /// saving time) are applied, and the result is again a naive `ParsiDateTime`.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset_seconds`: The offset to apply, in seconds. Positive values move the clock forward
+    ///   (e.g., UTC to a zone east of Greenwich); negative values move it backward.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`add_duration`](#method.add_duration): `InvalidDate`/`InvalidTime`
+    /// if `self` is invalid, or an error if the shifted value leaves the supported range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// const TEHRAN_STANDARD_OFFSET: i32 = 3 * 3600 + 30 * 60; // +03:30
+    ///
+    /// let utc = ParsiDateTime::new(1403, 12, 30, 22, 0, 0).unwrap();
+    /// let tehran = utc.with_fixed_offset(TEHRAN_STANDARD_OFFSET).unwrap();
+    /// assert_eq!(tehran, ParsiDateTime::new(1404, 1, 1, 1, 30, 0).unwrap()); // Rolls into the new year
+    ///
+    /// // Applying the negated offset shifts back.
+    /// assert_eq!(tehran.with_fixed_offset(-TEHRAN_STANDARD_OFFSET), Ok(utc));
+    /// ```
+    pub fn with_fixed_offset(&self, offset_seconds: i32) -> Result<Self, DateError> {
+        self.add_duration(Duration::seconds(i64::from(offset_seconds)))
+    }
+
+    /// Checks whether two `ParsiDateTime` values are within `tolerance` of each other.
+    ///
+    /// Returns `true` if the absolute difference `|self - other|` (as computed by the `Sub`
+    /// implementation) is at most `tolerance`. Useful for comparing timestamps that went through
+    /// lossy conversions, in tests involving the current time, and for deduplication.
+    /// A negative `tolerance` never matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The `ParsiDateTime` to compare against.
+    /// * `tolerance`: The maximum allowed difference, inclusive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` or `Err(DateError::InvalidTime)` if either value is
+    /// invalid, as propagated from the subtraction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    /// use chrono::Duration;
+    ///
+    /// let a = ParsiDateTime::new(1403, 12, 30, 23, 59, 59).unwrap();
+    /// let b = ParsiDateTime::new(1404, 1, 1, 0, 0, 1).unwrap(); // 2 seconds later, next year
+    ///
+    /// assert_eq!(a.approx_eq(&b, Duration::seconds(2)), Ok(true));
+    /// assert_eq!(b.approx_eq(&a, Duration::seconds(2)), Ok(true)); // Symmetric
+    /// assert_eq!(a.approx_eq(&b, Duration::seconds(1)), Ok(false));
+    /// ```
+    pub fn approx_eq(&self, other: &ParsiDateTime, tolerance: Duration) -> Result<bool, DateError> {
+        let difference = (*self - *other)?;
+        Ok(difference <= tolerance && -difference <= tolerance)
+    }
+
+    /// Returns the signed number of calendar days `self - other`, ignoring the time of day.
+    ///
+    /// Only the date parts are compared, via [`ParsiDate::signed_days_between`], so 23:59 and
+    /// 00:01 of the following day are one calendar day apart even though less than a day has
+    /// passed. Subtract the values (`self - other`) for the exact `Duration` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The `ParsiDateTime` to subtract from `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if either date part is invalid, or
+    /// `Err(DateError::GregorianConversionError)` if converting either date to Gregorian fails.
+    /// The time parts are not validated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let late = ParsiDateTime::new(1403, 12, 30, 23, 59, 0).unwrap();
+    /// let early = ParsiDateTime::new(1404, 1, 1, 0, 1, 0).unwrap(); // 2 minutes later
+    ///
+    /// assert_eq!(early.calendar_days_between(&late), Ok(1));
+    /// assert_eq!(late.calendar_days_between(&early), Ok(-1));
+    /// assert_eq!((early - late).unwrap().num_days(), 0); // Less than a full day elapsed
+    /// ```
+    pub fn calendar_days_between(&self, other: &ParsiDateTime) -> Result<i64, DateError> {
+        self.date.signed_days_between(&other.date)
+    }
+
+    /// Describes when `self` happens relative to `reference` as a coarse Persian phrase, e.g.,
+    /// "۳ روز پیش" (3 days ago) or "۲ ساعت دیگر" (in 2 hours).
+    ///
+    /// The difference `self - reference` is rendered in the largest fitting unit, rounded down:
+    /// seconds, minutes, hours or days, and for gaps of a calendar month or more, months or years
+    /// as counted by the date difference (`self.date() - reference.date()`). Gaps of a year or more
+    /// are always given in years. Numbers use Persian digits and, as in
+    /// [`ParsiDate::humanize_difference`], nouns are not inflected for number. Times less than a
+    /// second apart give "اکنون" ("now").
+    ///
+    /// See [`humanize_since_english`](#method.humanize_since_english) for English output.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference`: The point in time to describe `self` against, typically the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` or `Err(DateError::InvalidTime)` if either value is
+    /// invalid, as for subtraction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let now = ParsiDateTime::new(1403, 5, 10, 12, 0, 0).unwrap();
+    /// let earlier = ParsiDateTime::new(1403, 5, 7, 9, 0, 0).unwrap();
+    /// assert_eq!(earlier.humanize_since(now).unwrap(), "۳ روز پیش");
+    ///
+    /// let later = ParsiDateTime::new(1403, 5, 10, 14, 30, 0).unwrap();
+    /// assert_eq!(later.humanize_since(now).unwrap(), "۲ ساعت دیگر");
+    /// assert_eq!(now.humanize_since(now).unwrap(), "اکنون");
+    /// ```
+    pub fn humanize_since(&self, reference: ParsiDateTime) -> Result<String, DateError> {
+        const UNITS: [&str; 6] = ["ثانیه", "دقیقه", "ساعت", "روز", "ماه", "سال"];
+        Ok(match self.relative_to(reference)? {
+            None => "اکنون".to_string(),
+            Some((count, unit, is_future)) => format!(
+                "{} {} {}",
+                DigitStyle::Persian.convert(&count.to_string()),
+                UNITS[unit],
+                if is_future { "دیگر" } else { "پیش" }
+            ),
+        })
+    }
+
+    /// Describes when `self` happens relative to `reference` as a coarse English phrase, e.g.,
+    /// "3 days ago", "in 1 hour" or "now".
+    ///
+    /// The units and rounding are those of [`humanize_since`](#method.humanize_since); the unit
+    /// takes the plural form for counts other than one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`humanize_since`](#method.humanize_since).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let now = ParsiDateTime::new(1403, 5, 10, 12, 0, 0).unwrap();
+    /// let earlier = ParsiDateTime::new(1403, 5, 10, 11, 59, 0).unwrap();
+    /// assert_eq!(earlier.humanize_since_english(now).unwrap(), "1 minute ago");
+    ///
+    /// let next_year = ParsiDateTime::new(1405, 6, 1, 12, 0, 0).unwrap();
+    /// assert_eq!(next_year.humanize_since_english(now).unwrap(), "in 2 years");
+    /// ```
+    pub fn humanize_since_english(&self, reference: ParsiDateTime) -> Result<String, DateError> {
+        const UNITS: [&str; 6] = ["second", "minute", "hour", "day", "month", "year"];
+        Ok(match self.relative_to(reference)? {
+            None => "now".to_string(),
+            Some((count, unit, is_future)) => {
+                let plural = if count == 1 { "" } else { "s" };
+                if is_future {
+                    format!("in {} {}{}", count, UNITS[unit], plural)
+                } else {
+                    format!("{} {}{} ago", count, UNITS[unit], plural)
+                }
+            }
+        })
+    }
+
+    /// **Internal**: Splits `self - reference` into a count of the largest fitting unit (an index
+    /// into seconds, minutes, hours, days, months, years) and whether `self` lies in the future.
+    /// Returns `None` if the two are less than a second apart.
+    fn relative_to(
+        &self,
+        reference: ParsiDateTime,
+    ) -> Result<Option<(i64, usize, bool)>, DateError> {
+        let seconds = (*self - reference)?.num_seconds();
+        if seconds == 0 {
+            return Ok(None);
+        }
+        let magnitude = seconds.unsigned_abs() as i64;
+        let (count, unit) = if magnitude < 60 {
+            (magnitude, 0)
+        } else if magnitude < 3_600 {
+            (magnitude / 60, 1)
+        } else if magnitude < 86_400 {
+            (magnitude / 3_600, 2)
+        } else {
+            // Months and years follow the calendar, so that leap days do not accumulate.
+            let interval = (self.date - reference.date)?;
+            if interval.years != 0 {
+                (i64::from(interval.years.unsigned_abs()), 5)
+            } else if interval.months != 0 {
+                (i64::from(interval.months.unsigned_abs()), 4)
+            } else {
+                (magnitude / 86_400, 3)
+            }
+        };
+        Ok(Some((count, unit, seconds > 0)))
+    }
+
     /// Adds a specified number of days to the date part, preserving the time component.
     ///
     /// Delegates date calculation to [`ParsiDate::add_days`]. Time remains unchanged.
@@ -1315,6 +2370,74 @@ impl ParsiDateTime {
         })
     }
 
+    /// Adds a specified number of weeks to the date part, preserving the time component.
+    ///
+    /// Delegates date calculation to [`ParsiDate::add_weeks`]. Time remains unchanged.
+    ///
+    /// # Arguments
+    /// * `weeks`: Number of weeks to add (can be negative).
+    ///
+    /// # Errors
+    /// Returns `Err` if the initial `ParsiDateTime` is invalid or if `ParsiDate::add_weeks` fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use parsidate::{ParsiDateTime, ParsiDate};
+    ///
+    /// let dt = ParsiDateTime::new(1403, 12, 25, 9, 15, 0).unwrap();
+    /// let next_week = dt.add_weeks(1).unwrap();
+    /// assert_eq!(next_week.date(), ParsiDate::new(1404, 1, 2).unwrap());
+    /// assert_eq!(next_week.time(), (9, 15, 0)); // Time unchanged
+    /// ```
+    pub fn add_weeks(&self, weeks: i64) -> Result<Self, DateError> {
+        if !self.is_valid() {
+            return Err(if !self.date.is_valid() {
+                DateError::InvalidDate
+            } else {
+                DateError::InvalidTime
+            });
+        }
+        let new_date = self.date.add_weeks(weeks)?;
+        Ok(ParsiDateTime {
+            date: new_date,
+            ..*self
+        })
+    }
+
+    /// Subtracts a specified number of weeks from the date part, preserving the time component.
+    ///
+    /// Delegates date calculation to [`ParsiDate::sub_weeks`].
+    ///
+    /// # Arguments
+    /// * `weeks`: Non-negative number of weeks to subtract.
+    ///
+    /// # Errors
+    /// Returns `Err` under the same conditions as [`add_weeks`](#method.add_weeks).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use parsidate::{ParsiDateTime, ParsiDate};
+    ///
+    /// let dt = ParsiDateTime::new(1404, 1, 5, 18, 0, 0).unwrap();
+    /// let last_week = dt.sub_weeks(1).unwrap();
+    /// assert_eq!(last_week.date(), ParsiDate::new(1403, 12, 28).unwrap());
+    /// assert_eq!(last_week.time(), (18, 0, 0));
+    /// ```
+    pub fn sub_weeks(&self, weeks: u64) -> Result<Self, DateError> {
+        if !self.is_valid() {
+            return Err(if !self.date.is_valid() {
+                DateError::InvalidDate
+            } else {
+                DateError::InvalidTime
+            });
+        }
+        let new_date = self.date.sub_weeks(weeks)?;
+        Ok(ParsiDateTime {
+            date: new_date,
+            ..*self
+        })
+    }
+
     /// Adds months to the date part, preserving time and clamping day if necessary.
     ///
     /// Delegates date calculation to [`ParsiDate::add_months`].
@@ -1590,9 +2713,45 @@ impl ParsiDateTime {
         Ok(ParsiDateTime { second, ..*self })
     }
 
+    /// Creates a new `ParsiDateTime` instance with only the nanosecond fraction changed.
+    ///
+    /// The date and the hour, minute and second components remain the same.
+    ///
+    /// # Arguments
+    ///
+    /// * `nano`: The desired fraction of the second in nanoseconds (0-999,999,999).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidTime)` if `nano` is 1,000,000,000 or more.
+    /// Returns `Err(DateError::InvalidDate)` if the date part of the original `ParsiDateTime` (`self`) was invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDateTime};
+    ///
+    /// let dt = ParsiDateTime::new(1403, 5, 2, 10, 30, 45).unwrap();
+    /// assert_eq!(dt.with_nanosecond(250_000_000).unwrap().format("%T.%f"), "10:30:45.250000000");
+    /// assert_eq!(dt.with_nanosecond(1_000_000_000), Err(DateError::InvalidTime));
+    /// ```
+    pub fn with_nanosecond(&self, nano: u32) -> Result<Self, DateError> {
+        if !self.date.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        if nano > 999_999_999 {
+            return Err(DateError::InvalidTime);
+        }
+        Ok(ParsiDateTime {
+            nanosecond: nano,
+            ..*self
+        })
+    }
+
     /// Creates a new `ParsiDateTime` instance with new time components (hour, minute, second).
     ///
-    /// The date (year, month, day) component remains the same.
+    /// The date (year, month, day) component remains the same. The whole time of day is replaced,
+    /// so the [`nanosecond`](#method.nanosecond) fraction is reset to zero.
     ///
     /// # Arguments
     ///
@@ -1631,15 +2790,46 @@ impl ParsiDateTime {
         if hour > 23 || minute > 59 || second > 59 {
             return Err(DateError::InvalidTime);
         }
-        // Create new instance with updated time
+        // Create new instance with updated time; the whole time of day is replaced.
         Ok(ParsiDateTime {
             date: self.date,
             hour,
             minute,
             second,
+            nanosecond: 0,
         })
     }
 
+    /// Creates a new `ParsiDateTime` on the same date with the time set from a number of seconds
+    /// since midnight.
+    ///
+    /// This is the inverse of [`num_seconds_from_midnight`](#method.num_seconds_from_midnight).
+    ///
+    /// # Arguments
+    ///
+    /// * `secs`: The number of seconds since midnight (0-86399).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidTime)` if `secs` is 86400 or more.
+    /// Returns `Err(DateError::InvalidDate)` if the date part of `self` is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDateTime};
+    ///
+    /// let dt = ParsiDateTime::new(1403, 5, 2, 10, 0, 0).unwrap();
+    /// assert_eq!(dt.with_seconds_from_midnight(55_845), Ok(ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap()));
+    /// assert_eq!(dt.with_seconds_from_midnight(86_400), Err(DateError::InvalidTime));
+    /// ```
+    pub fn with_seconds_from_midnight(&self, secs: u32) -> Result<Self, DateError> {
+        if secs >= 86_400 {
+            return Err(DateError::InvalidTime);
+        }
+        self.with_time(secs / 3600, secs % 3600 / 60, secs % 60)
+    }
+
     /// Creates a new `ParsiDateTime` instance with only the year component of the date changed.
     ///
     /// The month, day, and all time components remain the same. This method delegates the
@@ -1751,6 +2941,68 @@ impl ParsiDateTime {
         })
     }
 
+    // --- Day Boundaries ---
+
+    /// Returns the same date at the start of the day, 00:00:00 with a zero nanosecond fraction.
+    ///
+    /// Together with [`end_of_day`](#method.end_of_day) this gives the inclusive bounds of the
+    /// calendar day, e.g. for selecting "all events on this day".
+    ///
+    /// # Errors
+    /// Returns `Err(DateError::InvalidDate)` if the date part is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let dt = ParsiDateTime::new_with_nano(1403, 5, 2, 15, 30, 45, 123).unwrap();
+    /// assert_eq!(dt.start_of_day(), ParsiDateTime::new(1403, 5, 2, 0, 0, 0));
+    /// ```
+    pub fn start_of_day(&self) -> Result<Self, DateError> {
+        if !self.date.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        Ok(ParsiDateTime {
+            date: self.date,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+        })
+    }
+
+    /// Returns the same date at the last representable instant of the day,
+    /// 23:59:59.999999999.
+    ///
+    /// # Errors
+    /// Returns `Err(DateError::InvalidDate)` if the date part is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let dt = ParsiDateTime::new(1403, 12, 30, 8, 0, 0).unwrap();
+    /// let end = dt.end_of_day().unwrap();
+    /// assert_eq!(end, ParsiDateTime::new_with_nano(1403, 12, 30, 23, 59, 59, 999_999_999).unwrap());
+    /// // The next nanosecond is the start of the next day.
+    /// assert_eq!(
+    ///     end + chrono::Duration::nanoseconds(1),
+    ///     ParsiDateTime::new(1404, 1, 1, 0, 0, 0)
+    /// );
+    /// ```
+    pub fn end_of_day(&self) -> Result<Self, DateError> {
+        if !self.date.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        Ok(ParsiDateTime {
+            date: self.date,
+            hour: 23,
+            minute: 59,
+            second: 59,
+            nanosecond: 999_999_999,
+        })
+    }
+
     // --- Season Boundaries ---
 
     /// Returns the `ParsiDateTime` corresponding to the first day of the season this date falls into,
@@ -1805,6 +3057,83 @@ impl ParsiDateTime {
             ..*self
         }) // Reuse time components
     }
+
+    // --- Sorted Slice Lookups ---
+
+    /// Searches a sorted slice of `ParsiDateTime`s for `target` using binary search.
+    ///
+    /// Thin wrapper around [`slice::binary_search`] relying on the derived chronological `Ord`
+    /// (date first, then hour, minute and second). The slice **must** be sorted in ascending order.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(index)` if `target` is found, otherwise `Err(index)` with the insertion point.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let events = [
+    ///     ParsiDateTime::new(1403, 5, 2, 8, 0, 0).unwrap(),
+    ///     ParsiDateTime::new(1403, 5, 2, 12, 30, 0).unwrap(),
+    /// ];
+    /// let noon = ParsiDateTime::new(1403, 5, 2, 12, 0, 0).unwrap();
+    /// assert_eq!(ParsiDateTime::binary_search_in(&events, &events[1]), Ok(1));
+    /// assert_eq!(ParsiDateTime::binary_search_in(&events, &noon), Err(1));
+    /// ```
+    #[inline]
+    pub fn binary_search_in(
+        sorted: &[ParsiDateTime],
+        target: &ParsiDateTime,
+    ) -> Result<usize, usize> {
+        sorted.binary_search(target)
+    }
+
+    /// Finds the entry in a sorted slice that is closest to this `ParsiDateTime`, in O(log n).
+    ///
+    /// The neighbouring entries around the insertion point are compared by the absolute
+    /// [`Duration`] separating them from `self`. On a tie, the **earlier** entry is returned.
+    /// The slice **must** be sorted in ascending order.
+    ///
+    /// # Returns
+    ///
+    /// `Some(&datetime)` referencing the nearest entry, or `None` if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let events = [
+    ///     ParsiDateTime::new(1403, 5, 2, 8, 0, 0).unwrap(),
+    ///     ParsiDateTime::new(1403, 5, 2, 12, 30, 0).unwrap(),
+    /// ];
+    /// let eleven = ParsiDateTime::new(1403, 5, 2, 11, 0, 0).unwrap();
+    /// assert_eq!(eleven.nearest_in_sorted(&events), Some(&events[1]));
+    /// ```
+    pub fn nearest_in_sorted<'a>(&self, sorted: &'a [ParsiDateTime]) -> Option<&'a ParsiDateTime> {
+        let idx = match Self::binary_search_in(sorted, self) {
+            Ok(idx) => return sorted.get(idx),
+            Err(idx) => idx,
+        };
+        let before = idx.checked_sub(1).and_then(|i| sorted.get(i));
+        let after = sorted.get(idx);
+        match (before, after) {
+            (Some(b), Some(a)) => {
+                // Distances that cannot be computed (invalid values) are treated as infinitely far.
+                let dist_before = (*self - *b).ok().map(|d| d.abs());
+                let dist_after = (*a - *self).ok().map(|d| d.abs());
+                match (dist_before, dist_after) {
+                    (Some(db), Some(da)) if da < db => Some(a),
+                    (None, Some(_)) => Some(a),
+                    _ => Some(b),
+                }
+            }
+            (Some(b), None) => Some(b),
+            (None, after) => after,
+        }
+    }
 } // <<<=== End impl ParsiDateTime ===>>>
 
 // --- Trait Implementations ---
@@ -1818,6 +3147,17 @@ impl ParsiDateTime {
 /// hour, minute, and second. It utilizes the `Display` implementation of [`ParsiDate`]
 /// for the date part.
 ///
+/// The alternate flag (`{:#}`) selects a verbose form built from the alternate `ParsiDate`
+/// form (the `"full"` date style) and the time, separated by `" - "`:
+/// `"WeekdayName، D MonthName YYYY - HH:MM:SS"` (e.g., "سه‌شنبه، 2 مرداد 1403 - 08:05:30").
+///
+/// A non-zero [`nanosecond`](ParsiDateTime::nanosecond) fraction is appended to the seconds
+/// as 9 digits (`"HH:MM:SS.fffffffff"`), so values that differ only in the fraction never print
+/// the same; a zero fraction is omitted.
+///
+/// As with [`ParsiDate`], the width, fill and alignment flags apply to the whole output
+/// (e.g., `format!("{:<22}", dt)`), and a precision is ignored rather than truncating it.
+///
 /// # Examples
 ///
 /// ```rust
@@ -1825,23 +3165,126 @@ impl ParsiDateTime {
 ///
 /// let dt = ParsiDateTime::new(1403, 5, 2, 8, 5, 30).unwrap();
 /// assert_eq!(dt.to_string(), "1403/05/02 08:05:30");
+/// assert_eq!(format!("{:#}", dt), "سه‌شنبه، 2 مرداد 1403 - 08:05:30");
+///
+/// let precise = dt.with_nanosecond(250_000_000).unwrap();
+/// assert_eq!(precise.to_string(), "1403/05/02 08:05:30.250000000");
 ///
 /// let dt_end_of_year = ParsiDateTime::new(1399, 12, 30, 23, 59, 9).unwrap();
 /// // Note the zero-padding for second < 10
 /// assert_eq!(format!("{}", dt_end_of_year), "1399/12/30 23:59:09");
+///
+/// assert_eq!(format!("[{:>21}]", dt), "[  1403/05/02 08:05:30]");
+/// assert_eq!(format!("{:.4}", dt), "1403/05/02 08:05:30");
 /// ```
 impl fmt::Display for ParsiDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Use the Display implementation of the inner ParsiDate for the date part ("YYYY/MM/DD")
-        // Then, append the time part, ensuring zero-padding for H, M, S.
-        write!(
-            f,
-            "{} {:02}:{:02}:{:02}",
-            self.date, // ParsiDate's Display impl produces "YYYY/MM/DD"
-            self.hour,
-            self.minute,
-            self.second
-        )
+        let mut formatted = if f.alternate() {
+            format!(
+                "{:#} - {:02}:{:02}:{:02}",
+                self.date, self.hour, self.minute, self.second
+            )
+        } else {
+            // Use the Display implementation of the inner ParsiDate for the date part ("YYYY/MM/DD")
+            // Then, append the time part, ensuring zero-padding for H, M, S.
+            format!(
+                "{} {:02}:{:02}:{:02}",
+                self.date, // ParsiDate's Display impl produces "YYYY/MM/DD"
+                self.hour,
+                self.minute,
+                self.second
+            )
+        };
+        // Show the fraction only when present, keeping the common output unchanged.
+        if self.nanosecond != 0 {
+            formatted.push_str(&format!(".{:09}", self.nanosecond));
+        }
+        // Apply any width, fill and alignment to the whole output.
+        pad_display(f, &formatted)
+    }
+}
+
+/// Parses a `ParsiDateTime` from its `Display` form, `"YYYY/MM/DD HH:MM:SS"`.
+///
+/// The date part may also use dashes (`"YYYY-MM-DD HH:MM:SS"`), and, as for
+/// [`ParsiDate`'s `FromStr`](ParsiDate#impl-FromStr-for-ParsiDate), the year may have fewer than
+/// four digits. The seconds may be followed by a 9-digit fraction (`"HH:MM:SS.fffffffff"`), as
+/// written by `Display` for a non-zero [`nanosecond`](ParsiDateTime::nanosecond). Parsing
+/// delegates to [`ParsiDateTime::parse`], so `dt.to_string().parse::<ParsiDateTime>()`
+/// round-trips for every valid value.
+///
+/// # Errors
+///
+/// Returns the `DateError::ParseError` produced by [`ParsiDateTime::parse`].
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::ParsiDateTime;
+///
+/// let dt: ParsiDateTime = "1403/05/02 08:05:30".parse().unwrap();
+/// assert_eq!(dt, ParsiDateTime::new(1403, 5, 2, 8, 5, 30).unwrap());
+/// assert_eq!("1403-05-02 08:05:30".parse::<ParsiDateTime>(), Ok(dt));
+/// assert_eq!(dt.to_string().parse::<ParsiDateTime>(), Ok(dt));
+/// assert!("1403/05/02".parse::<ParsiDateTime>().is_err()); // Time is required
+///
+/// let precise: ParsiDateTime = "1403/05/02 08:05:30.000000007".parse().unwrap();
+/// assert_eq!(precise.nanosecond(), 7);
+/// assert_eq!(precise.to_string().parse::<ParsiDateTime>(), Ok(precise));
+/// ```
+impl FromStr for ParsiDateTime {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (input, date_format) = prepare_display_date(s);
+        // The date part never contains a '.', so one marks the optional fraction.
+        let fraction = if input.contains('.') { ".%f" } else { "" };
+        Self::parse(&input, &format!("{} %H:%M:%S{}", date_format, fraction))
+    }
+}
+
+/// Converts a Gregorian `NaiveDateTime` with `.try_into()`, delegating to
+/// [`ParsiDateTime::from_gregorian`] and failing under exactly the same conditions.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use parsidate::ParsiDateTime;
+///
+/// let gregorian = NaiveDate::from_ymd_opt(2024, 7, 23).unwrap().and_hms_opt(15, 30, 45).unwrap();
+/// let dt: ParsiDateTime = gregorian.try_into().unwrap();
+/// assert_eq!(dt, ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap());
+/// ```
+impl TryFrom<NaiveDateTime> for ParsiDateTime {
+    type Error = DateError;
+
+    fn try_from(gregorian: NaiveDateTime) -> Result<Self, Self::Error> {
+        Self::from_gregorian(gregorian)
+    }
+}
+
+/// Converts a `ParsiDateTime` to a Gregorian `NaiveDateTime` with `.try_into()`, delegating to
+/// [`ParsiDateTime::to_gregorian`] and failing under exactly the same conditions.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{NaiveDate, NaiveDateTime};
+/// use parsidate::{DateError, ParsiDateTime};
+///
+/// let dt = ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap();
+/// let gregorian: NaiveDateTime = dt.try_into().unwrap();
+/// assert_eq!(gregorian.to_string(), "2024-07-23 15:30:45");
+///
+/// let invalid = unsafe { ParsiDateTime::new_unchecked(1403, 5, 2, 24, 0, 0) };
+/// assert_eq!(NaiveDateTime::try_from(invalid), Err(DateError::InvalidTime));
+/// ```
+impl TryFrom<ParsiDateTime> for NaiveDateTime {
+    type Error = DateError;
+
+    fn try_from(dt: ParsiDateTime) -> Result<Self, Self::Error> {
+        dt.to_gregorian()
     }
 }
 
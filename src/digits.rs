@@ -0,0 +1,73 @@
+// ~/src/digits.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Digit Styles
+//!
+//! This module defines the [`DigitStyle`] enum, which selects the numerals used by
+//! [`ParsiDate::format_strftime_localized`](crate::ParsiDate::format_strftime_localized).
+//!
+//! Text shown to end users in Iran conventionally uses the Extended Arabic-Indic (Persian) digits
+//! `۰۱۲۳۴۵۶۷۸۹` rather than the ASCII digits `0123456789`. The `parse_localized` methods accept
+//! such input by normalizing it with [`normalize_digits`] first.
+
+use std::borrow::Cow;
+
+/// Selects the digits used when writing numbers.
+///
+/// The default is [`DigitStyle::Ascii`], the output of the regular formatting methods.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DigitStyle {
+    /// ASCII digits `0`-`9`.
+    #[default]
+    Ascii,
+    /// Extended Arabic-Indic (Persian) digits `۰`-`۹` (U+06F0 to U+06F9).
+    Persian,
+}
+
+impl DigitStyle {
+    /// Rewrites the ASCII digits in `text` using this digit style.
+    ///
+    /// All other characters, including digits that are already Persian, are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::DigitStyle;
+    ///
+    /// assert_eq!(DigitStyle::Persian.convert("1403/05/02"), "۱۴۰۳/۰۵/۰۲");
+    /// assert_eq!(DigitStyle::Ascii.convert("1403/05/02"), "1403/05/02");
+    /// ```
+    pub fn convert(&self, text: &str) -> String {
+        match self {
+            DigitStyle::Ascii => text.to_string(),
+            DigitStyle::Persian => text
+                .chars()
+                .map(|c| match c.to_digit(10) {
+                    Some(d) if c.is_ascii_digit() => char::from_u32('۰' as u32 + d).unwrap_or(c),
+                    _ => c,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// **Internal**: Replaces Persian (`۰`-`۹`) and Arabic-Indic (`٠`-`٩`) digits in `text` with their
+/// ASCII equivalents, borrowing `text` unchanged when it contains neither.
+pub(crate) fn normalize_digits(text: &str) -> Cow<'_, str> {
+    let to_ascii = |c: char| match c {
+        '۰'..='۹' => char::from_u32(c as u32 - '۰' as u32 + '0' as u32),
+        '٠'..='٩' => char::from_u32(c as u32 - '٠' as u32 + '0' as u32),
+        _ => None,
+    };
+    if !text.chars().any(|c| to_ascii(c).is_some()) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(text.chars().map(|c| to_ascii(c).unwrap_or(c)).collect())
+}
@@ -27,7 +27,11 @@ use std::fmt;
 ///
 /// This enum covers all failure modes, from invalid date construction and parsing
 /// to arithmetic overflows and conversion issues.
+///
+/// The enum is `#[non_exhaustive]`: new variants may be added in minor releases, so a `match`
+/// on it outside this crate needs a wildcard arm.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[non_exhaustive]
 pub enum DateError {
     /// Indicates that a given combination of year, month, and day is not a valid
     /// date in the Persian calendar.
@@ -81,13 +85,23 @@ pub enum DateError {
     ///
     /// Returned by: [`ParsiDate::from_ordinal`](crate::date::ParsiDate::from_ordinal).
     InvalidOrdinal,
+
+    /// Two representations of a date that were expected to agree describe different days.
+    ///
+    /// Returned by: [`ParsiDate::parse_and_verify_gregorian`](crate::date::ParsiDate::parse_and_verify_gregorian)
+    /// when the Persian date and its claimed Gregorian equivalent differ.
+    ConsistencyMismatch,
 }
 
 /// Provides specific reasons for a parsing failure.
 ///
 /// This enum is wrapped by [`DateError::ParseError`] to give detailed feedback when
 /// parsing a string into a date or date-time fails.
+///
+/// Like [`DateError`], the enum is `#[non_exhaustive]`, so a `match` on it outside this crate
+/// needs a wildcard arm.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[non_exhaustive]
 pub enum ParseErrorKind {
     /// The input string's structure or literal characters did not match the format string.
     /// For example, expecting a `/` but finding a `-`, or the input string has trailing characters.
@@ -114,6 +128,30 @@ pub enum ParseErrorKind {
 
     /// Reserved for future use if weekday parsing is implemented. Currently not returned.
     InvalidWeekdayName,
+
+    /// An AM/PM marker required by the `%p` specifier (e.g., "ق.ظ", "ب.ظ", "AM", "PM") was not
+    /// found or recognized in the input.
+    InvalidAmPmMarker,
+
+    /// A 12-hour clock time was incomplete: the format contained `%I` without an AM/PM marker
+    /// (`%p` or `%Ep`), or a marker without `%I`, so the 24-hour value cannot be determined.
+    IncompleteTwelveHourTime,
+}
+
+/// A parsing error together with the position in the input where matching failed.
+///
+/// Returned by [`ParsiDate::parse_located`](crate::date::ParsiDate::parse_located). The position
+/// lets a user interface point at the offending part of the input instead of only reporting the
+/// category of the failure.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct LocatedParseError {
+    /// The category of the parsing failure.
+    pub kind: ParseErrorKind,
+    /// The byte offset into the input string at which matching failed.
+    ///
+    /// For [`ParseErrorKind::InvalidDateValue`], which concerns the combination of the parsed
+    /// components rather than a single location, this is `0`.
+    pub position: usize,
 }
 
 // --- Trait Implementations ---
@@ -139,6 +177,10 @@ impl fmt::Display for DateError {
             DateError::InvalidOrdinal => {
                 write!(f, "Invalid ordinal day: must be between 1 and 365/366")
             }
+            DateError::ConsistencyMismatch => write!(
+                f,
+                "The Persian and Gregorian dates do not represent the same day"
+            ),
         }
     }
 }
@@ -153,10 +195,25 @@ impl fmt::Display for ParseErrorKind {
             ParseErrorKind::UnsupportedSpecifier => write!(f, "the format string contains a specifier that is not supported for parsing"),
             ParseErrorKind::InvalidMonthName => write!(f, "could not recognize a valid Persian month name for the '%B' specifier"),
             ParseErrorKind::InvalidWeekdayName => write!(f, "could not recognize a valid Persian weekday name (currently unused)"),
+            ParseErrorKind::InvalidAmPmMarker => write!(f, "could not recognize a valid AM/PM marker for the '%p' specifier"),
+            ParseErrorKind::IncompleteTwelveHourTime => write!(f, "a 12-hour time needs both '%I' and an AM/PM marker ('%p')"),
         }
     }
 }
 
+impl fmt::Display for LocatedParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Parsing error at byte {}: {}", self.position, self.kind)
+    }
+}
+
+/// Converts a located error into a plain [`DateError::ParseError`], discarding the position.
+impl From<LocatedParseError> for DateError {
+    fn from(err: LocatedParseError) -> Self {
+        DateError::ParseError(err.kind)
+    }
+}
+
 /// Implements the standard `Error` trait for `DateError`.
 ///
 /// This allows `DateError` to be used with standard Rust error handling mechanisms,
@@ -169,3 +226,5 @@ impl std::error::Error for DateError {
         None
     }
 }
+
+impl std::error::Error for LocatedParseError {}
@@ -0,0 +1,247 @@
+// ~/src/hijri.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Hijri (Islamic Lunar) Calendar Conversion
+//!
+//! This module (available with the `hijri` feature) provides [`HijriDate`] and conversions between
+//! it and [`ParsiDate`] via [`ParsiDate::to_hijri`] and [`ParsiDate::from_hijri`].
+//!
+//! The conversion uses the **tabular (arithmetic) Islamic calendar**: months alternate between
+//! 30 and 29 days, and 11 of every 30 years (years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29 of
+//! each cycle) are leap years with a 30-day Dhu al-Hijjah. The epoch is 1 Muharram 1 AH =
+//! July 19, 622 CE (proleptic Gregorian).
+//!
+//! Official Hijri Qamari dates in Iran are determined by moon sighting, so the tabular result may
+//! differ from the officially announced date by **±1 day** (occasionally 2). Use it for display
+//! and approximate scheduling, not for authoritative religious dates.
+
+use crate::date::ParsiDate;
+use crate::error::DateError;
+use chrono::NaiveDate;
+use std::fmt;
+
+/// Names of the Hijri months in Persian, as commonly written in Iran (Muharram to Dhu al-Hijjah).
+const HIJRI_MONTH_NAMES_PERSIAN: [&str; 12] = [
+    "محرم",
+    "صفر",
+    "ربیع‌الاول",
+    "ربیع‌الثانی",
+    "جمادی‌الاول",
+    "جمادی‌الثانی",
+    "رجب",
+    "شعبان",
+    "رمضان",
+    "شوال",
+    "ذی‌القعده",
+    "ذی‌الحجه",
+];
+
+/// The Gregorian date of 1 Muharram 1 AH in the tabular Islamic calendar (civil epoch).
+fn hijri_epoch_gregorian() -> NaiveDate {
+    NaiveDate::from_ymd_opt(622, 7, 19).expect("622-07-19 is a valid Gregorian date")
+}
+
+/// A date in the tabular (arithmetic) Hijri calendar.
+///
+/// Supports years 1 to 9999 AH. See the [module documentation](self) for the calendar rules and
+/// the expected divergence from officially sighted dates.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "hijri")] {
+/// use parsidate::{HijriDate, ParsiDate};
+///
+/// // Tabular result for 1403/05/02; the officially sighted date was 17 Muharram.
+/// let hijri = HijriDate::new(1446, 1, 16).unwrap();
+/// assert_eq!(hijri.to_string(), "1446/01/16");
+/// assert_eq!(hijri.month_name(), "محرم");
+/// assert_eq!(ParsiDate::from_hijri(hijri), Ok(ParsiDate::new(1403, 5, 2).unwrap()));
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct HijriDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl HijriDate {
+    /// Creates a new `HijriDate`, validating it against the tabular Hijri calendar.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the year is outside 1-9999, the month is outside
+    /// 1-12, or the day is 0 or exceeds the length of the month.
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Self, DateError> {
+        if !(1..=9999).contains(&year) || !(1..=12).contains(&month) {
+            return Err(DateError::InvalidDate);
+        }
+        if day == 0 || day > Self::days_in_month(year, month) {
+            return Err(DateError::InvalidDate);
+        }
+        Ok(HijriDate { year, month, day })
+    }
+
+    /// Returns the year.
+    #[inline]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month (1 = Muharram, ..., 12 = Dhu al-Hijjah).
+    #[inline]
+    pub const fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month.
+    #[inline]
+    pub const fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the Persian name of the month (e.g., "رمضان").
+    pub fn month_name(&self) -> &'static str {
+        HIJRI_MONTH_NAMES_PERSIAN[(self.month - 1) as usize]
+    }
+
+    /// Returns `true` if `year` is a leap year (355 days) in the tabular Hijri calendar.
+    #[inline]
+    pub const fn is_leap_year(year: i32) -> bool {
+        (14 + 11 * (year.rem_euclid(30) as i64)) % 30 < 11
+    }
+
+    /// Returns the number of days in the given month, or 0 if the month is outside 1-12.
+    ///
+    /// Odd months have 30 days and even months 29, except that Dhu al-Hijjah (12) has 30 days in
+    /// leap years.
+    pub const fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            12 if Self::is_leap_year(year) => 30,
+            1..=12 if month % 2 == 1 => 30,
+            1..=12 => 29,
+            _ => 0,
+        }
+    }
+
+    /// **Internal**: Days from the epoch (1 Muharram 1 AH) to Muharram 1st of `year`.
+    fn days_before_year(year: i32) -> i64 {
+        let year = i64::from(year);
+        (year - 1) * 354 + (3 + 11 * year).div_euclid(30)
+    }
+
+    /// **Internal**: Days from the epoch to this date.
+    fn days_since_epoch(&self) -> i64 {
+        // Months alternate 30/29 days, so the first `m` months span ceil(29.5 * m) days.
+        let months_before = i64::from(self.month - 1);
+        Self::days_before_year(self.year) + (59 * months_before + 1) / 2 + i64::from(self.day) - 1
+    }
+
+    /// **Internal**: Builds the date lying `days` days after the epoch.
+    fn from_days_since_epoch(days: i64) -> Result<Self, DateError> {
+        if days < 0 {
+            return Err(DateError::ArithmeticOverflow);
+        }
+        // Initial estimate from the mean year length (10631 / 30 days), then correct it.
+        let mut year = (30 * days + 10646) / 10631;
+        while Self::days_before_year(year as i32) > days {
+            year -= 1;
+        }
+        while Self::days_before_year(year as i32 + 1) <= days {
+            year += 1;
+        }
+        if year > 9999 {
+            return Err(DateError::ArithmeticOverflow);
+        }
+        let year = year as i32;
+        let mut remaining = (days - Self::days_before_year(year)) as u32;
+        let mut month = 1;
+        while remaining >= Self::days_in_month(year, month) {
+            remaining -= Self::days_in_month(year, month);
+            month += 1;
+        }
+        Ok(HijriDate {
+            year,
+            month,
+            day: remaining + 1,
+        })
+    }
+}
+
+impl fmt::Display for HijriDate {
+    /// Formats the date as `YYYY/MM/DD`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{:02}/{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl ParsiDate {
+    /// Converts this date to the tabular Hijri (Islamic lunar) calendar.
+    ///
+    /// Requires the `hijri` feature. The result may differ by about ±1 day from the officially
+    /// announced (sighting-based) Hijri date; see the [`HijriDate`] documentation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if `self` is invalid, or
+    /// `Err(DateError::ArithmeticOverflow)` if the date falls outside the supported Hijri range
+    /// (before 1 Muharram 1 AH, i.e. Persian dates before 1/4/28, or after the year 9999 AH).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "hijri")] {
+    /// use parsidate::{HijriDate, ParsiDate};
+    ///
+    /// let date = ParsiDate::new(1403, 1, 1).unwrap(); // 2024-03-20
+    /// assert_eq!(date.to_hijri(), Ok(HijriDate::new(1445, 9, 10).unwrap()));
+    ///
+    /// // Before the Hijri epoch
+    /// assert!(ParsiDate::new(1, 1, 1).unwrap().to_hijri().is_err());
+    /// # }
+    /// ```
+    pub fn to_hijri(&self) -> Result<HijriDate, DateError> {
+        if !self.is_valid() {
+            return Err(DateError::InvalidDate);
+        }
+        let gregorian = self.to_gregorian_internal()?;
+        HijriDate::from_days_since_epoch(
+            gregorian
+                .signed_duration_since(hijri_epoch_gregorian())
+                .num_days(),
+        )
+    }
+
+    /// Creates a `ParsiDate` from a date in the tabular Hijri calendar.
+    ///
+    /// Requires the `hijri` feature. This is the inverse of [`to_hijri`](#method.to_hijri).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::GregorianConversionError)` or `Err(DateError::ArithmeticOverflow)`
+    /// if the corresponding Persian date cannot be computed (e.g., it lies beyond year 9999).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "hijri")] {
+    /// use parsidate::{HijriDate, ParsiDate};
+    ///
+    /// let eid_al_fitr = HijriDate::new(1445, 10, 1).unwrap();
+    /// assert_eq!(ParsiDate::from_hijri(eid_al_fitr), Ok(ParsiDate::new(1403, 1, 22).unwrap()));
+    /// # }
+    /// ```
+    pub fn from_hijri(hijri: HijriDate) -> Result<Self, DateError> {
+        let gregorian = hijri_epoch_gregorian()
+            .checked_add_days(chrono::Days::new(hijri.days_since_epoch() as u64))
+            .ok_or(DateError::ArithmeticOverflow)?;
+        ParsiDate::from_gregorian(gregorian)
+    }
+}
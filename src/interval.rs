@@ -0,0 +1,74 @@
+// ~/src/interval.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Calendar Intervals
+//!
+//! This module defines [`ParsiInterval`], the difference between two [`ParsiDate`](crate::ParsiDate)s
+//! expressed in Persian calendar units (years, months and days), as produced by subtracting one
+//! date from another with the `-` operator.
+//!
+//! Unlike a day count (see [`ParsiDate::days_between`](crate::ParsiDate::days_between)), a calendar
+//! interval follows the variable month lengths of the Persian calendar, which is what ages, tenures
+//! and contract periods are usually measured in.
+
+/// A normalized difference between two Persian dates in years, months and days.
+///
+/// Produced by `date_a - date_b` (see the `Sub<ParsiDate>` implementation of
+/// [`ParsiDate`](crate::ParsiDate)). All three components share the same sign: they are all
+/// non-negative when `date_a >= date_b` and all non-positive otherwise. `months` is within
+/// `-11..=11` and `days` within `-30..=30`.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::{ParsiDate, ParsiInterval};
+///
+/// let later = ParsiDate::new(1403, 1, 5).unwrap();
+/// let earlier = ParsiDate::new(1402, 11, 20).unwrap();
+///
+/// let interval = (later - earlier).unwrap();
+/// assert_eq!(interval, ParsiInterval { years: 0, months: 1, days: 14 });
+///
+/// let reversed = (earlier - later).unwrap();
+/// assert_eq!(reversed, ParsiInterval { years: 0, months: -1, days: -14 });
+/// assert!(reversed.is_negative());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParsiInterval {
+    /// The number of whole years.
+    pub years: i32,
+    /// The number of whole months remaining after the years (-11 to 11).
+    pub months: i32,
+    /// The number of days remaining after the years and months (-30 to 30).
+    pub days: i32,
+}
+
+impl ParsiInterval {
+    /// Returns `true` if all components are zero (the two dates were equal).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let date = ParsiDate::new(1403, 5, 2).unwrap();
+    /// assert!((date - date).unwrap().is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0
+    }
+
+    /// Returns `true` if the interval is negative (the left-hand date was earlier).
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.years < 0 || self.months < 0 || self.days < 0
+    }
+}
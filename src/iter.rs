@@ -0,0 +1,61 @@
+// ~/src/iter.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Date Iteration
+//!
+//! This module defines the [`ParsiDateIter`] iterator returned by
+//! [`ParsiDate::iter_until`](crate::ParsiDate::iter_until), which yields consecutive days of a
+//! half-open date range.
+
+use crate::date::ParsiDate;
+use std::iter::FusedIterator;
+
+/// An iterator over consecutive days, yielding [`ParsiDate`] values.
+///
+/// Created by [`ParsiDate::iter_until`](crate::ParsiDate::iter_until). The number of days is
+/// known up front, so the iterator implements [`ExactSizeIterator`].
+#[derive(Debug, Clone)]
+pub struct ParsiDateIter {
+    next: ParsiDate,
+    remaining: usize,
+}
+
+impl ParsiDateIter {
+    /// **Internal**: Creates the iterator yielding `remaining` days starting at `start`.
+    pub(crate) fn new(start: ParsiDate, remaining: usize) -> Self {
+        ParsiDateIter {
+            next: start,
+            remaining,
+        }
+    }
+}
+
+impl Iterator for ParsiDateIter {
+    type Item = ParsiDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let date = self.next;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.next = date.following_day_unchecked();
+        }
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for ParsiDateIter {}
+
+impl FusedIterator for ParsiDateIter {}
@@ -194,23 +194,51 @@
 //!
 //! ## Features
 //!
-//! This crate has two optional features:
+//! This crate has four optional features:
 //!
 //! -   `serde`: Enables serialization and deserialization for `ParsiDate`, `ParsiDateTime`, and `Season`
 //!     via the `serde` crate. Add to `Cargo.toml` with `features = ["serde"]`.
 //! -   `timezone`: Enables the [`ZonedParsiDateTime`] struct for timezone-aware operations,
 //!     powered by the `chrono-tz` crate. Add to `Cargo.toml` with `features = ["timezone"]`.
+//! -   `hijri`: Enables `HijriDate` and conversions to and from the tabular (arithmetic) Islamic
+//!     lunar calendar via `ParsiDate::to_hijri` and `ParsiDate::from_hijri`.
+//! -   `arbitrary`: Implements `arbitrary::Arbitrary` for `ParsiDate` and `ParsiDateTime`,
+//!     generating only valid instances, for use in `cargo-fuzz` targets.
 //!
-//! You can enable both with `features = ["serde", "timezone"]`.
+//! The `full` feature enables `serde`, `timezone` and `hijri`.
 
 // --- Library Module Declarations ---
 // These `mod` statements declare the modules that form the library's internal structure.
 
+mod annotated;
+mod bidi;
 mod constants;
 mod date;
 mod datetime;
+mod digits;
 mod error;
+mod interval;
+mod iter;
+mod names;
+mod range;
 mod season;
+mod traits;
+mod view;
+mod weekday;
+mod year_calendar;
+
+// Alternative serde representations (e.g., `parsidate::serde::compact`), public as a module so
+// it can be named in `#[serde(with = "...")]` attributes.
+#[cfg(feature = "serde")]
+pub mod serde;
+
+// `arbitrary::Arbitrary` implementations for fuzzing, only compiled with the `arbitrary` feature.
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+// Tabular Hijri calendar conversion, only compiled when the `hijri` feature is enabled.
+#[cfg(feature = "hijri")]
+mod hijri;
 
 // Conditionally compile and declare the `zoned` module only when the `timezone` feature is enabled.
 #[cfg(feature = "timezone")]
@@ -224,11 +252,26 @@ mod tests;
 // Re-export the core public types to make them accessible directly from the crate root
 // (e.g., `use parsidate::ParsiDate;` instead of `use parsidate::date::ParsiDate;`).
 
+pub use annotated::{AnnotatedDay, AnnotatedRange};
+pub use bidi::BidiIsolate;
 pub use constants::{MAX_PARSI_DATE, MIN_PARSI_DATE};
-pub use date::ParsiDate;
+pub use date::{DetectedCalendar, ParsiDate};
 pub use datetime::ParsiDateTime;
-pub use error::{DateError, ParseErrorKind};
+pub use digits::DigitStyle;
+pub use error::{DateError, LocatedParseError, ParseErrorKind};
+pub use interval::ParsiInterval;
+pub use iter::ParsiDateIter;
+pub use names::ParsiNames;
+pub use range::ParsiDateRange;
 pub use season::Season;
+pub use traits::{ParsiDatelike, ParsiTimelike};
+pub use view::ParsiDateView;
+pub use weekday::ParsiWeekday;
+pub use year_calendar::{MonthInfo, YearCalendar};
+
+// Re-export the Hijri date type if the `hijri` feature is active.
+#[cfg(feature = "hijri")]
+pub use hijri::HijriDate;
 
 // Conditionally re-export the `ZonedParsiDateTime` struct if the `timezone` feature is active.
 #[cfg(feature = "timezone")]
@@ -0,0 +1,82 @@
+// ~/src/names.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Localized Name Tables
+//!
+//! This module defines the [`ParsiNames`] struct, a set of month, weekday and season names that
+//! can be injected into formatting at runtime via
+//! [`ParsiDate::format_with_names`](crate::ParsiDate::format_with_names).
+//!
+//! The built-in formatter uses the standard Iranian names (available as [`ParsiNames::IRANIAN`]).
+//! Supplying a custom table allows regional variants (e.g., Afghan Dari month names),
+//! abbreviations, or transliterations without forking the crate.
+
+use crate::constants::{MONTH_NAMES_PERSIAN, SEASON_NAMES_PERSIAN, WEEKDAY_NAMES_PERSIAN};
+
+/// A table of calendar names used when formatting dates.
+///
+/// *   `months[0]` is the name of Farvardin (month 1), ..., `months[11]` is Esfand (month 12).
+/// *   `weekdays[0]` is Saturday (the first day of the Persian week), ..., `weekdays[6]` is Friday.
+/// *   `seasons[0]` is Bahar (Spring), ..., `seasons[3]` is Zemestan (Winter).
+///
+/// The lifetime `'a` allows tables borrowed from runtime data (e.g., a loaded translation file)
+/// as well as `'static` constant tables.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::{ParsiDate, ParsiNames};
+///
+/// // Afghan (Dari) month names, keeping the Iranian weekday and season names.
+/// const AFGHAN: ParsiNames<'static> = ParsiNames {
+///     months: [
+///         "حمل", "ثور", "جوزا", "سرطان", "اسد", "سنبله",
+///         "میزان", "عقرب", "قوس", "جدی", "دلو", "حوت",
+///     ],
+///     ..ParsiNames::IRANIAN
+/// };
+///
+/// let date = ParsiDate::new(1403, 5, 2).unwrap();
+/// assert_eq!(date.format_with_names("%d %B %Y", &AFGHAN), "02 اسد 1403");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct ParsiNames<'a> {
+    /// Month names, indexed from 0 (Farvardin) to 11 (Esfand). Used by `%B` and the `"long"` style.
+    pub months: [&'a str; 12],
+    /// Weekday names, indexed from 0 (Saturday) to 6 (Friday). Used by `%A`.
+    pub weekdays: [&'a str; 7],
+    /// Season names, indexed from 0 (Bahar) to 3 (Zemestan). Used by `%K`.
+    pub seasons: [&'a str; 4],
+}
+
+impl ParsiNames<'static> {
+    /// The standard Iranian Persian names, as used by [`ParsiDate::format`](crate::ParsiDate::format).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiNames;
+    ///
+    /// assert_eq!(ParsiNames::IRANIAN.months[0], "فروردین");
+    /// assert_eq!(ParsiNames::IRANIAN.weekdays[6], "جمعه");
+    /// assert_eq!(ParsiNames::IRANIAN.seasons[1], "تابستان");
+    /// ```
+    pub const IRANIAN: ParsiNames<'static> = ParsiNames {
+        months: MONTH_NAMES_PERSIAN,
+        weekdays: WEEKDAY_NAMES_PERSIAN,
+        seasons: SEASON_NAMES_PERSIAN,
+    };
+}
+
+impl Default for ParsiNames<'static> {
+    /// Returns [`ParsiNames::IRANIAN`].
+    fn default() -> Self {
+        ParsiNames::IRANIAN
+    }
+}
@@ -0,0 +1,163 @@
+// ~/src/range.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Date Ranges
+//!
+//! This module defines [`ParsiDateRange`], an inclusive range of [`ParsiDate`]s with membership,
+//! length and overlap queries. Iterating over a range yields each of its days through
+//! [`ParsiDateIter`].
+
+use crate::date::ParsiDate;
+use crate::error::DateError;
+use crate::iter::ParsiDateIter;
+
+/// An inclusive range of dates, from `start` to `end`.
+///
+/// A range always contains at least one day: [`new`](ParsiDateRange::new) rejects an `end`
+/// earlier than `start`. Deserialization (with the `serde` feature) goes through `new` as well,
+/// so reversed ranges and invalid dates are rejected there too.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::{ParsiDate, ParsiDateRange};
+///
+/// let spring = ParsiDateRange::new(
+///     ParsiDate::new(1403, 1, 1).unwrap(),
+///     ParsiDate::new(1403, 3, 31).unwrap(),
+/// )
+/// .unwrap();
+///
+/// assert!(spring.contains(ParsiDate::new(1403, 2, 15).unwrap()));
+/// assert!(!spring.contains(ParsiDate::new(1403, 4, 1).unwrap()));
+/// assert_eq!(spring.len_days(), Ok(93));
+/// assert_eq!(spring.into_iter().count(), 93);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawParsiDateRange"))]
+pub struct ParsiDateRange {
+    start: ParsiDate,
+    end: ParsiDate,
+}
+
+/// **Internal**: The unchecked serialized form of [`ParsiDateRange`], validated through
+/// [`ParsiDateRange::new`] on deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawParsiDateRange {
+    start: ParsiDate,
+    end: ParsiDate,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawParsiDateRange> for ParsiDateRange {
+    type Error = DateError;
+
+    fn try_from(raw: RawParsiDateRange) -> Result<Self, Self::Error> {
+        ParsiDateRange::new(raw.start, raw.end)
+    }
+}
+
+impl ParsiDateRange {
+    /// Creates a range from `start` to `end`, both included.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if either date is invalid or if `end` is earlier than
+    /// `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{DateError, ParsiDate, ParsiDateRange};
+    ///
+    /// let day = ParsiDate::new(1403, 5, 2).unwrap();
+    /// assert!(ParsiDateRange::new(day, day).is_ok()); // A single day
+    ///
+    /// let earlier = ParsiDate::new(1403, 5, 1).unwrap();
+    /// assert_eq!(ParsiDateRange::new(day, earlier), Err(DateError::InvalidDate));
+    /// ```
+    pub fn new(start: ParsiDate, end: ParsiDate) -> Result<Self, DateError> {
+        if !start.is_valid() || !end.is_valid() || end < start {
+            return Err(DateError::InvalidDate);
+        }
+        Ok(ParsiDateRange { start, end })
+    }
+
+    /// Returns the first day of the range.
+    #[inline]
+    pub const fn start(&self) -> ParsiDate {
+        self.start
+    }
+
+    /// Returns the last day of the range.
+    #[inline]
+    pub const fn end(&self) -> ParsiDate {
+        self.end
+    }
+
+    /// Returns `true` if `date` lies within the range, including its first and last day.
+    #[inline]
+    pub fn contains(&self, date: ParsiDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// Returns the number of days in the range, counting both ends.
+    ///
+    /// This is [`ParsiDate::days_between`] plus one, so a single-day range has length 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` under the same conditions as [`ParsiDate::days_between`]; this does not
+    /// happen for a range created with [`new`](ParsiDateRange::new).
+    pub fn len_days(&self) -> Result<i64, DateError> {
+        Ok(self.start.days_between(&self.end)? + 1)
+    }
+
+    /// Returns `true` if the two ranges share at least one day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::{ParsiDate, ParsiDateRange};
+    ///
+    /// let d = |m, d| ParsiDate::new(1403, m, d).unwrap();
+    /// let first = ParsiDateRange::new(d(1, 1), d(1, 15)).unwrap();
+    /// let second = ParsiDateRange::new(d(1, 15), d(1, 31)).unwrap();
+    /// let third = ParsiDateRange::new(d(1, 16), d(1, 31)).unwrap();
+    ///
+    /// assert!(first.overlaps(&second)); // Both contain Farvardin 15th
+    /// assert!(!first.overlaps(&third));
+    /// ```
+    #[inline]
+    pub fn overlaps(&self, other: &ParsiDateRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+impl IntoIterator for ParsiDateRange {
+    type Item = ParsiDate;
+    type IntoIter = ParsiDateIter;
+
+    /// Returns an iterator over every day of the range, in order.
+    fn into_iter(self) -> ParsiDateIter {
+        let len = self.len_days().unwrap_or(0).max(0) as usize;
+        ParsiDateIter::new(self.start, len)
+    }
+}
+
+impl IntoIterator for &ParsiDateRange {
+    type Item = ParsiDate;
+    type IntoIter = ParsiDateIter;
+
+    fn into_iter(self) -> ParsiDateIter {
+        (*self).into_iter()
+    }
+}
@@ -22,7 +22,7 @@
 //! start and end months. It is returned by methods like [`ParsiDate::season()`](crate::ParsiDate::season)
 //! and can be used for date-based logic and formatting.
 
-use crate::constants::{SEASON_NAMES_ENGLISH, SEASON_NAMES_PERSIAN};
+use crate::constants::{SEASON_NAMES_ENGLISH, SEASON_NAMES_FINGLISH, SEASON_NAMES_PERSIAN};
 use std::fmt;
 
 /// Represents one of the four seasons in the Persian calendar.
@@ -80,6 +80,23 @@ impl Season {
         SEASON_NAMES_ENGLISH[*self as usize]
     }
 
+    /// Returns the Latin transliteration ("Finglish") of the Persian season name.
+    ///
+    /// The spellings match the variant names and are stable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::Season;
+    ///
+    /// assert_eq!(Season::Bahar.name_finglish(), "Bahar");
+    /// assert_eq!(Season::Paeez.name_finglish(), "Paeez");
+    /// ```
+    #[inline]
+    pub fn name_finglish(&self) -> &'static str {
+        SEASON_NAMES_FINGLISH[*self as usize]
+    }
+
     /// Returns the starting month number (1-12) of the season.
     ///
     /// - `Bahar` starts in month 1 (Farvardin).
@@ -0,0 +1,430 @@
+// ~/src/serde.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Alternative Serde Representations
+//!
+//! This module (available with the `serde` feature) provides alternative serialization formats
+//! for use with serde's `#[serde(with = "...")]` field attribute.
+//!
+//! The derived `Serialize`/`Deserialize` implementations of [`ParsiDate`](crate::ParsiDate) and
+//! [`ParsiDateTime`](crate::ParsiDateTime) use a struct (map) representation, which is
+//! self-describing but verbose. [`compact`] trades that readability for size, and [`auto`] picks
+//! a string or the compact form depending on whether the format is human-readable.
+//! [`string`] and [`string_datetime`] always use ISO-like strings such as `"1403-05-02"`, which
+//! suit configuration files.
+
+/// Serializes [`ParsiDate`](crate::ParsiDate) and [`ParsiDateTime`](crate::ParsiDateTime) as
+/// fixed-size arrays of their components.
+///
+/// *   `ParsiDate` is written as `[year, month, day]`.
+/// *   `ParsiDateTime` is written as `[year, month, day, hour, minute, second]`. This form holds
+///     whole seconds only, so serializing a value with a non-zero nanosecond fraction fails
+///     with a serde error instead of silently dropping the fraction.
+///
+/// The values are serialized as tuples, so self-describing formats (JSON, MessagePack) emit a
+/// short array, while non-self-describing formats (e.g., bincode) emit just the fixed sequence
+/// of numbers without field names. This is well suited to high-volume storage.
+///
+/// Unlike the derived implementations, deserialization **validates** the components and fails
+/// with a serde error if they do not form a valid date (or time). Serialization likewise refuses
+/// an invalid value (e.g., one built with `new_unchecked`), which could not be read back.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::{ParsiDate, ParsiDateTime};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Event {
+///     #[serde(with = "parsidate::serde::compact")]
+///     day: ParsiDate,
+///     #[serde(with = "parsidate::serde::compact")]
+///     at: ParsiDateTime,
+/// }
+///
+/// let event = Event {
+///     day: ParsiDate::new(1403, 5, 2).unwrap(),
+///     at: ParsiDateTime::new(1403, 5, 2, 14, 30, 0).unwrap(),
+/// };
+/// let json = serde_json::to_string(&event).unwrap();
+/// assert_eq!(json, r#"{"day":[1403,5,2],"at":[1403,5,2,14,30,0]}"#);
+/// assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+///
+/// // Invalid components are rejected on deserialization.
+/// assert!(serde_json::from_str::<Event>(r#"{"day":[1404,12,30],"at":[1403,5,2,14,30,0]}"#).is_err());
+/// ```
+pub mod compact {
+    use crate::{ParsiDate, ParsiDateTime};
+    use sealed::Sealed as _;
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) mod sealed {
+        use crate::DateError;
+
+        pub trait Sealed {
+            /// **Internal**: Checks the value before it is written, so a value built with
+            /// `new_unchecked` is never serialized into a form that cannot be read back.
+            fn check_valid(&self) -> Result<(), DateError>;
+        }
+
+        impl Sealed for crate::ParsiDate {
+            fn check_valid(&self) -> Result<(), DateError> {
+                if self.is_valid() {
+                    Ok(())
+                } else {
+                    Err(DateError::InvalidDate)
+                }
+            }
+        }
+
+        impl Sealed for crate::ParsiDateTime {
+            fn check_valid(&self) -> Result<(), DateError> {
+                self.date().check_valid()?;
+                if self.is_valid() {
+                    Ok(())
+                } else {
+                    Err(DateError::InvalidTime)
+                }
+            }
+        }
+    }
+
+    /// Types supporting the compact array representation.
+    ///
+    /// This trait is sealed and implemented only for [`ParsiDate`] and [`ParsiDateTime`]; it exists
+    /// so that the same `#[serde(with = "parsidate::serde::compact")]` attribute works for both.
+    pub trait Compact: sealed::Sealed + Sized {
+        /// Describes the `Display` string of the type, for the error message of
+        /// [`auto`](super::auto) when the input is not a string.
+        const EXPECTING: &'static str;
+
+        /// Serializes `self` as an array of its components.
+        fn serialize_compact<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+        /// Deserializes an array of components, validating the result.
+        fn deserialize_compact<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error>;
+    }
+
+    impl Compact for ParsiDate {
+        const EXPECTING: &'static str = "a Persian date string such as \"1403/05/02\"";
+
+        fn serialize_compact<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.check_valid().map_err(S::Error::custom)?;
+            self.as_tuple().serialize(serializer)
+        }
+
+        fn deserialize_compact<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let (year, month, day) = <(i32, u32, u32)>::deserialize(deserializer)?;
+            ParsiDate::new(year, month, day).map_err(D::Error::custom)
+        }
+    }
+
+    impl Compact for ParsiDateTime {
+        const EXPECTING: &'static str =
+            "a Persian date-time string such as \"1403/05/02 08:05:09\"";
+
+        fn serialize_compact<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.check_valid().map_err(S::Error::custom)?;
+            super::check_whole_seconds::<S>(self)?;
+            self.as_tuple().serialize(serializer)
+        }
+
+        fn deserialize_compact<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let (year, month, day, hour, minute, second) =
+                <(i32, u32, u32, u32, u32, u32)>::deserialize(deserializer)?;
+            ParsiDateTime::new(year, month, day, hour, minute, second).map_err(D::Error::custom)
+        }
+    }
+
+    /// Serializes a [`ParsiDate`] or [`ParsiDateTime`] as an array of its components.
+    ///
+    /// # Errors
+    ///
+    /// Returns a serializer error if `value` is not valid (e.g., one built with `new_unchecked`),
+    /// or for a [`ParsiDateTime`] with a non-zero nanosecond fraction.
+    pub fn serialize<T: Compact, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize_compact(serializer)
+    }
+
+    /// Deserializes a [`ParsiDate`] or [`ParsiDateTime`] from an array of its components.
+    ///
+    /// # Errors
+    ///
+    /// Returns a deserializer error if the input is not an array of the expected length, or if
+    /// the components do not form a valid date/time.
+    pub fn deserialize<'de, T: Compact, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::deserialize_compact(deserializer)
+    }
+}
+
+/// Picks the representation of [`ParsiDate`](crate::ParsiDate) and
+/// [`ParsiDateTime`](crate::ParsiDateTime) from the format being used, based on serde's
+/// `is_human_readable()` flag.
+///
+/// *   Human-readable formats (JSON, TOML, YAML) get the `Display` string, e.g. `"1403/05/02"`
+///     or `"1403/05/02 14:30:00"`, which reads back through `FromStr`.
+/// *   Compact formats (bincode, postcard) get the arrays of [`compact`].
+///
+/// The string keeps a non-zero nanosecond fraction of a `ParsiDateTime` (`"HH:MM:SS.fffffffff"`),
+/// while the compact form holds whole seconds only and fails to serialize such a value.
+/// Deserialization **validates** the value in both branches, failing with a serde error for an
+/// invalid date or time, and serialization refuses an invalid value in both branches.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::{ParsiDate, ParsiDateTime};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Event {
+///     #[serde(with = "parsidate::serde::auto")]
+///     day: ParsiDate,
+///     #[serde(with = "parsidate::serde::auto")]
+///     at: ParsiDateTime,
+/// }
+///
+/// let event = Event {
+///     day: ParsiDate::new(1403, 5, 2).unwrap(),
+///     at: ParsiDateTime::new(1403, 5, 2, 14, 30, 0).unwrap(),
+/// };
+/// let json = serde_json::to_string(&event).unwrap();
+/// assert_eq!(json, r#"{"day":"1403/05/02","at":"1403/05/02 14:30:00"}"#);
+/// assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+///
+/// // Invalid dates are rejected on deserialization.
+/// assert!(serde_json::from_str::<Event>(r#"{"day":"1404/12/30","at":"1403/05/02 14:30:00"}"#).is_err());
+/// ```
+pub mod auto {
+    use super::compact::Compact;
+    use super::ParseVisitor;
+    use crate::DateError;
+    use serde::ser::Error as _;
+    use serde::{Deserializer, Serializer};
+    use std::fmt;
+    use std::str::FromStr;
+
+    /// Serializes a [`ParsiDate`](crate::ParsiDate) or [`ParsiDateTime`](crate::ParsiDateTime)
+    /// as a string for human-readable formats and as an array of its components otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns a serializer error in both branches if `value` is not valid (e.g., one built with
+    /// `new_unchecked`), and in the compact branch for a
+    /// [`ParsiDateTime`](crate::ParsiDateTime) with a non-zero nanosecond fraction.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Compact + fmt::Display,
+        S: Serializer,
+    {
+        value.check_valid().map_err(S::Error::custom)?;
+        if serializer.is_human_readable() {
+            serializer.collect_str(value)
+        } else {
+            value.serialize_compact(serializer)
+        }
+    }
+
+    /// Deserializes a [`ParsiDate`](crate::ParsiDate) or [`ParsiDateTime`](crate::ParsiDateTime)
+    /// from a string for human-readable formats and from an array of its components otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns a deserializer error if the input has the wrong shape, or if it does not form a
+    /// valid date/time.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Compact + FromStr<Err = DateError>,
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ParseVisitor {
+                expecting: T::EXPECTING,
+                parse: T::from_str,
+            })
+        } else {
+            T::deserialize_compact(deserializer)
+        }
+    }
+}
+
+/// Serializes [`ParsiDate`](crate::ParsiDate) as an ISO-like `"YYYY-MM-DD"` string, e.g.
+/// `"1403-05-02"`.
+///
+/// The year is always written with four digits, so the strings sort chronologically. This is
+/// independent of the format's `is_human_readable()` flag; use [`string_datetime`] for
+/// [`ParsiDateTime`](crate::ParsiDateTime) fields.
+///
+/// Deserialization expects exactly this form and **validates** the date, failing with a serde
+/// error for input such as `"1404-12-30"` (1404 is a common year).
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::ParsiDate;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Config {
+///     #[serde(with = "parsidate::serde::string")]
+///     start: ParsiDate,
+/// }
+///
+/// let config = Config { start: ParsiDate::new(1403, 5, 2).unwrap() };
+/// let json = serde_json::to_string(&config).unwrap();
+/// assert_eq!(json, r#"{"start":"1403-05-02"}"#);
+/// assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+///
+/// assert!(serde_json::from_str::<Config>(r#"{"start":"1404-12-30"}"#).is_err());
+/// assert!(serde_json::from_str::<Config>(r#"{"start":"1403/05/02"}"#).is_err());
+/// ```
+pub mod string {
+    use super::compact::sealed::Sealed as _;
+    use super::ParseVisitor;
+    use crate::ParsiDate;
+    use serde::ser::Error as _;
+    use serde::{Deserializer, Serializer};
+
+    /// The pattern written by [`serialize`].
+    const FORMAT: &str = "%0Y-%m-%d";
+
+    /// Serializes a [`ParsiDate`] as a `"YYYY-MM-DD"` string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a serializer error if `date` is not valid (e.g., one built with
+    /// [`ParsiDate::new_unchecked`]), rather than writing a string that cannot be read back.
+    pub fn serialize<S: Serializer>(date: &ParsiDate, serializer: S) -> Result<S::Ok, S::Error> {
+        date.check_valid().map_err(S::Error::custom)?;
+        serializer.collect_str(&date.format(FORMAT))
+    }
+
+    /// Deserializes a [`ParsiDate`] from a `"YYYY-MM-DD"` string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a deserializer error if the input is not a string of that form, or if it does not
+    /// form a valid date.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ParsiDate, D::Error> {
+        deserializer.deserialize_str(ParseVisitor {
+            expecting: "a Persian date string such as \"1403-05-02\"",
+            parse: |s| ParsiDate::parse(s, "%Y-%m-%d"),
+        })
+    }
+}
+
+/// Serializes [`ParsiDateTime`](crate::ParsiDateTime) as an ISO-like `"YYYY-MM-DD HH:MM:SS"`
+/// string, e.g. `"1403-05-02 15:30:45"`.
+///
+/// The counterpart of [`string`] for date-time fields. The string holds whole seconds only, so
+/// serializing a value with a non-zero nanosecond fraction fails with a serde error instead of
+/// silently dropping the fraction. Deserialization expects exactly this form and **validates**
+/// the date and time.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::ParsiDateTime;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Config {
+///     #[serde(with = "parsidate::serde::string_datetime")]
+///     backup_at: ParsiDateTime,
+/// }
+///
+/// let config = Config { backup_at: ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap() };
+/// let json = serde_json::to_string(&config).unwrap();
+/// assert_eq!(json, r#"{"backup_at":"1403-05-02 15:30:45"}"#);
+/// assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+///
+/// assert!(serde_json::from_str::<Config>(r#"{"backup_at":"1403-05-02 24:00:00"}"#).is_err());
+/// ```
+pub mod string_datetime {
+    use super::compact::sealed::Sealed as _;
+    use super::ParseVisitor;
+    use crate::ParsiDateTime;
+    use serde::ser::Error as _;
+    use serde::{Deserializer, Serializer};
+
+    /// The pattern written by [`serialize`].
+    const FORMAT: &str = "%0Y-%m-%d %H:%M:%S";
+
+    /// Serializes a [`ParsiDateTime`] as a `"YYYY-MM-DD HH:MM:SS"` string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a serializer error if `dt` is not valid (e.g., one built with
+    /// [`ParsiDateTime::new_unchecked`]), or if it has a non-zero nanosecond fraction.
+    pub fn serialize<S: Serializer>(dt: &ParsiDateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        dt.check_valid().map_err(S::Error::custom)?;
+        super::check_whole_seconds::<S>(dt)?;
+        serializer.collect_str(&dt.format(FORMAT))
+    }
+
+    /// Deserializes a [`ParsiDateTime`] from a `"YYYY-MM-DD HH:MM:SS"` string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a deserializer error if the input is not a string of that form, or if it does not
+    /// form a valid date and time.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ParsiDateTime, D::Error> {
+        deserializer.deserialize_str(ParseVisitor {
+            expecting: "a Persian date-time string such as \"1403-05-02 15:30:45\"",
+            parse: |s| ParsiDateTime::parse(s, "%Y-%m-%d %H:%M:%S"),
+        })
+    }
+}
+
+/// **Internal**: Rejects a date-time whose nanosecond fraction a whole-second representation
+/// would drop.
+fn check_whole_seconds<S: serde::Serializer>(dt: &crate::ParsiDateTime) -> Result<(), S::Error> {
+    if dt.nanosecond() == 0 {
+        Ok(())
+    } else {
+        Err(serde::ser::Error::custom(
+            "ParsiDateTime has a non-zero nanosecond fraction, which this representation cannot hold",
+        ))
+    }
+}
+
+/// **Internal**: Deserializes a string with a fixed parse function, without allocating. Shared
+/// by [`auto`] (through `FromStr`) and the fixed-pattern [`string`] and [`string_datetime`].
+struct ParseVisitor<T> {
+    expecting: &'static str,
+    parse: fn(&str) -> Result<T, crate::DateError>,
+}
+
+impl<T> serde::de::Visitor<'_> for ParseVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<T, E> {
+        (self.parse)(s).map_err(E::custom)
+    }
+}
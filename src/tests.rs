@@ -73,6 +73,14 @@ mod datetime_tests {
         // Both invalid
     }
 
+    #[test]
+    fn test_as_tuple_datetime() {
+        let dt = pdt(1403, 5, 2, 15, 30, 45);
+        assert_eq!(dt.as_tuple(), (1403, 5, 2, 15, 30, 45));
+        let (y, m, d, h, min, s) = dt.as_tuple();
+        assert_eq!(ParsiDateTime::new(y, m, d, h, min, s), Ok(dt));
+    }
+
     // --- Conversion Tests ---
     #[test]
     fn test_gregorian_to_persian_datetime() {
@@ -196,6 +204,318 @@ mod datetime_tests {
         ));
     }
 
+    #[test]
+    fn test_is_today_datetime() {
+        let past = pdt(1300, 1, 1, 12, 0, 0);
+        assert!(!past.is_today());
+        assert_eq!(past.is_today_result(), Ok(false));
+        // Any time on today's date counts as today
+        let today = ParsiDate::today().unwrap();
+        let dt = ParsiDateTime::from_date_and_time(today, 23, 59, 59).unwrap();
+        assert_eq!(
+            dt.is_today_result(),
+            Ok(dt.date() == ParsiDate::today().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_named_comparisons_datetime() {
+        let a = pdt(1403, 5, 2, 23, 59, 59);
+        let b = pdt(1403, 5, 3, 0, 0, 0);
+        assert!(a.is_before(&b) && b.is_after(&a));
+        assert!(a.is_same_or_before(&a) && a.is_same_or_after(&a));
+        assert!(!a.is_before(&a) && !a.is_after(&a));
+        // The time-of-day breaks ties within the same date.
+        let noon = pdt(1403, 5, 2, 12, 0, 0);
+        assert!(noon.is_before(&a) && !noon.is_same_or_after(&a));
+    }
+
+    #[test]
+    fn test_gregorian_round_trip_precision() {
+        use chrono::Timelike;
+        let base = NaiveDate::from_ymd_opt(2024, 7, 23).unwrap();
+        for nano in [0, 1, 123_456_789, 999_999_999] {
+            let g = base.and_hms_nano_opt(15, 30, 45, nano).unwrap();
+            let round_trip = ParsiDateTime::from_gregorian(g)
+                .unwrap()
+                .to_gregorian()
+                .unwrap();
+            // The round trip is lossless, including the nanoseconds.
+            assert_eq!(round_trip, g);
+            assert_eq!(round_trip.nanosecond(), nano);
+        }
+        // A leap second (nanosecond >= 1e9) is clamped into the same second.
+        let leap = base.and_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+        assert_eq!(
+            ParsiDateTime::from_gregorian(leap).unwrap(),
+            ParsiDateTime::new_with_nano(1403, 5, 2, 23, 59, 59, 999_999_999).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_str_datetime() {
+        let dt = pdt(1403, 5, 2, 8, 5, 30);
+        assert_eq!("1403/05/02 08:05:30".parse::<ParsiDateTime>(), Ok(dt));
+        assert_eq!("1403-05-02 08:05:30".parse::<ParsiDateTime>(), Ok(dt));
+        for value in [dt, pdt(50, 1, 1, 0, 0, 0), pdt(1399, 12, 30, 23, 59, 59)] {
+            assert_eq!(value.to_string().parse::<ParsiDateTime>(), Ok(value));
+        }
+        assert_eq!(
+            "1403/05/02T08:05:30".parse::<ParsiDateTime>(),
+            Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+        );
+        assert_eq!(
+            "1403/05/02 24:00:00".parse::<ParsiDateTime>(),
+            Err(DateError::ParseError(ParseErrorKind::InvalidTimeValue))
+        );
+    }
+
+    #[test]
+    fn test_display_from_str_nanosecond_round_trip() {
+        let dt = ParsiDateTime::new_with_nano(1403, 5, 2, 8, 5, 30, 7).unwrap();
+        assert_eq!(dt.to_string(), "1403/05/02 08:05:30.000000007");
+        assert!(format!("{:#}", dt).ends_with(" - 08:05:30.000000007"));
+        assert_eq!(dt.to_string().parse::<ParsiDateTime>(), Ok(dt));
+        assert_ne!(dt.to_string(), pdt(1403, 5, 2, 8, 5, 30).to_string());
+
+        // `%f` takes exactly nine digits
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 08:05:30.250000000", "%Y/%m/%d %T.%f"),
+            Ok(dt.with_nanosecond(250_000_000).unwrap())
+        );
+        for bad in [
+            "1403/05/02 08:05:30.25",
+            "1403/05/02 08:05:30.",
+            "1403/05/02 08:05:30.x00000000",
+        ] {
+            assert_eq!(
+                bad.parse::<ParsiDateTime>(),
+                Err(DateError::ParseError(ParseErrorKind::InvalidNumber)),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_width_datetime() {
+        let dt = pdt(1403, 5, 2, 8, 5, 30);
+        assert_eq!(format!("{:>21}", dt), "  1403/05/02 08:05:30");
+        assert_eq!(format!("{:_<21}", dt), "1403/05/02 08:05:30__");
+        assert_eq!(format!("{}", dt), "1403/05/02 08:05:30");
+        assert_eq!(format!("{:.4}", dt), "1403/05/02 08:05:30");
+        assert_eq!(format!("{:^23.1}", dt), "  1403/05/02 08:05:30  ");
+        let alt = format!("{:#}", dt);
+        assert_eq!(format!("{:*<#40}", dt).trim_end_matches('*'), alt);
+        assert_eq!(format!("{:*<#40}", dt).chars().count(), 40);
+    }
+
+    #[test]
+    fn test_nanosecond_field() {
+        let dt = ParsiDateTime::new_with_nano(1403, 5, 2, 15, 30, 45, 123_456_789).unwrap();
+        assert_eq!(dt.nanosecond(), 123_456_789);
+        assert_eq!(dt.time(), (15, 30, 45));
+        assert!(dt.is_valid());
+        assert_eq!(pdt(1403, 5, 2, 15, 30, 45).nanosecond(), 0);
+        assert_eq!(
+            ParsiDateTime::new_with_nano(1403, 5, 2, 15, 30, 45, 1_000_000_000),
+            Err(DateError::InvalidTime)
+        );
+        assert_eq!(
+            ParsiDateTime::new_with_nano(1404, 12, 30, 0, 0, 0, 0),
+            Err(DateError::InvalidDate)
+        );
+        // Ordering takes the fraction into account.
+        assert!(pdt(1403, 5, 2, 15, 30, 45) < dt);
+        assert!(dt < pdt(1403, 5, 2, 15, 30, 46));
+        // Formatting with %f
+        assert_eq!(dt.format("%T.%f"), "15:30:45.123456789");
+        assert_eq!(
+            dt.with_nanosecond(5).unwrap().format("%S.%f"),
+            "45.000000005"
+        );
+        // Field setters keep the fraction; with_time replaces the whole time of day.
+        assert_eq!(dt.with_hour(1).unwrap().nanosecond(), 123_456_789);
+        assert_eq!(dt.add_days(1).unwrap().nanosecond(), 123_456_789);
+        assert_eq!(dt.with_time(1, 2, 3).unwrap().nanosecond(), 0);
+        // Duration arithmetic is exact.
+        let later = (dt + Duration::nanoseconds(876_543_211)).unwrap();
+        assert_eq!(later, pdt(1403, 5, 2, 15, 30, 46));
+        assert_eq!(later - dt, Ok(Duration::nanoseconds(876_543_211)));
+    }
+
+    #[test]
+    fn test_year_progress_datetime() {
+        assert_eq!(pdt(1404, 1, 1, 0, 0, 0).year_progress(), Ok(0.0));
+        let end_leap = pdt(1403, 12, 30, 23, 59, 59).year_progress().unwrap();
+        let end_common = pdt(1404, 12, 29, 23, 59, 59).year_progress().unwrap();
+        assert!(end_leap < 1.0 && end_leap > 365.99 / 366.0);
+        assert!(end_common < 1.0 && end_common > 364.99 / 365.0);
+        // Start of the last day equals the date-based progress of the previous day
+        assert_eq!(
+            pdt(1403, 12, 30, 0, 0, 0).year_progress(),
+            pd(1403, 12, 29).year_progress()
+        );
+        let invalid_time = unsafe { ParsiDateTime::new_unchecked(1403, 1, 1, 24, 0, 0) };
+        assert_eq!(invalid_time.year_progress(), Err(DateError::InvalidTime));
+    }
+
+    #[test]
+    fn test_unix_timestamp_round_trip() {
+        assert_eq!(
+            ParsiDateTime::from_timestamp(0),
+            Ok(pdt(1348, 10, 11, 0, 0, 0))
+        );
+        assert_eq!(
+            ParsiDateTime::from_timestamp(-1),
+            Ok(pdt(1348, 10, 10, 23, 59, 59))
+        );
+        for dt in [
+            pdt(1, 1, 1, 0, 0, 0),
+            pdt(1348, 10, 11, 0, 0, 0),
+            pdt(1403, 12, 30, 23, 59, 59),
+            pdt(1404, 1, 1, 0, 0, 0),
+        ] {
+            let secs = dt.timestamp().unwrap();
+            assert_eq!(secs, dt.to_gregorian().unwrap().and_utc().timestamp());
+            assert_eq!(ParsiDateTime::from_timestamp(secs), Ok(dt));
+        }
+        // One second before the Persian epoch, and beyond chrono's range
+        let first = pdt(1, 1, 1, 0, 0, 0).timestamp().unwrap();
+        assert_eq!(
+            ParsiDateTime::from_timestamp(first - 1),
+            Err(DateError::GregorianConversionError)
+        );
+        assert_eq!(
+            ParsiDateTime::from_timestamp(i64::MAX),
+            Err(DateError::GregorianConversionError)
+        );
+    }
+
+    #[test]
+    fn test_day_boundaries() {
+        let dt = ParsiDateTime::new_with_nano(1403, 12, 30, 14, 5, 9, 42).unwrap();
+        let start = dt.start_of_day().unwrap();
+        let end = dt.end_of_day().unwrap();
+        assert_eq!(start, pdt(1403, 12, 30, 0, 0, 0));
+        assert_eq!((end.time(), end.nanosecond()), ((23, 59, 59), 999_999_999));
+        assert_eq!(start.date(), dt.date());
+        assert_eq!(end.date(), dt.date());
+        assert!(start <= dt && dt <= end);
+        assert_eq!(
+            end.sub_duration(Duration::hours(24)).unwrap().date(),
+            pd(1403, 12, 29)
+        );
+
+        // An invalid time is replaced, but an invalid date is rejected
+        let bad_time = unsafe { ParsiDateTime::new_unchecked(1403, 5, 2, 24, 0, 0) };
+        assert_eq!(bad_time.start_of_day(), Ok(pdt(1403, 5, 2, 0, 0, 0)));
+        let bad_date = unsafe { ParsiDateTime::new_unchecked(1404, 12, 30, 10, 0, 0) };
+        assert_eq!(bad_date.start_of_day(), Err(DateError::InvalidDate));
+        assert_eq!(bad_date.end_of_day(), Err(DateError::InvalidDate));
+    }
+
+    #[test]
+    fn test_try_from_chrono() {
+        use chrono::NaiveDateTime;
+
+        let dates = [(1, 1, 1), (1403, 5, 2), (1403, 12, 30), (9999, 12, 29)];
+        for (year, month, day) in dates {
+            let dt = ParsiDateTime::new(year, month, day, 23, 59, 59).unwrap();
+            let gregorian: NaiveDateTime = dt.try_into().unwrap();
+            assert_eq!(Ok(gregorian), dt.to_gregorian());
+            assert_eq!(ParsiDateTime::try_from(gregorian), Ok(dt));
+            assert_eq!(ParsiDate::try_from(gregorian.date()), Ok(dt.date()));
+            assert_eq!(NaiveDate::try_from(dt.date()), Ok(gregorian.date()));
+        }
+
+        // Errors match the explicit conversions
+        let before_epoch = NaiveDate::from_ymd_opt(622, 3, 20).unwrap();
+        assert_eq!(
+            ParsiDate::try_from(before_epoch),
+            ParsiDate::from_gregorian(before_epoch)
+        );
+        assert!(ParsiDate::try_from(before_epoch).is_err());
+        let midnight = before_epoch.and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(
+            ParsiDateTime::try_from(midnight),
+            ParsiDateTime::from_gregorian(midnight)
+        );
+        let invalid = unsafe { ParsiDateTime::new_unchecked(1404, 12, 30, 0, 0, 0) };
+        assert_eq!(
+            NaiveDateTime::try_from(invalid),
+            Err(DateError::InvalidDate)
+        );
+        assert_eq!(
+            NaiveDate::try_from(invalid.date()),
+            Err(DateError::InvalidDate)
+        );
+    }
+
+    #[test]
+    fn test_calendar_days_between() {
+        let a = pdt(1403, 5, 2, 23, 59, 59);
+        let b = pdt(1403, 5, 3, 0, 0, 0);
+        assert_eq!(b.calendar_days_between(&a), Ok(1));
+        assert_eq!(a.calendar_days_between(&b), Ok(-1));
+        assert_eq!(a.calendar_days_between(&pdt(1403, 5, 2, 0, 0, 0)), Ok(0));
+        // Same result as the date parts, whatever the times
+        let c = pdt(1402, 1, 1, 12, 0, 0);
+        assert_eq!(
+            a.calendar_days_between(&c),
+            a.date().signed_days_between(&c.date())
+        );
+        assert_eq!(a.calendar_days_between(&c), Ok(365 + 126 - 1)); // 1402 is a common year
+
+        let invalid = unsafe { ParsiDateTime::new_unchecked(1404, 12, 30, 0, 0, 0) };
+        assert_eq!(
+            a.calendar_days_between(&invalid),
+            Err(DateError::InvalidDate)
+        );
+        assert_eq!(
+            invalid.calendar_days_between(&a),
+            Err(DateError::InvalidDate)
+        );
+        // The time is ignored, even when invalid
+        let bad_time = unsafe { ParsiDateTime::new_unchecked(1403, 5, 4, 24, 0, 0) };
+        assert_eq!(bad_time.calendar_days_between(&a), Ok(2));
+    }
+
+    #[test]
+    fn test_nanos_since_unix_epoch_round_trip() {
+        assert_eq!(pdt(1348, 10, 11, 0, 0, 0).nanos_since_unix_epoch(), Ok(0));
+        assert_eq!(
+            pdt(1348, 10, 10, 23, 59, 59).nanos_since_unix_epoch(),
+            Ok(-1_000_000_000)
+        );
+        for dt in [
+            pdt(1, 1, 1, 0, 0, 0),
+            pdt(1348, 10, 11, 0, 0, 1),
+            pdt(1403, 5, 2, 15, 30, 45),
+            pdt(1403, 12, 30, 23, 59, 59),
+            pdt(9999, 12, 29, 23, 59, 59),
+        ] {
+            let nanos = dt.nanos_since_unix_epoch().unwrap();
+            assert_eq!(ParsiDateTime::from_nanos_since_unix_epoch(nanos), Ok(dt));
+            // Sub-second parts stay within the same second, as the nanosecond fraction
+            let precise = ParsiDateTime::from_nanos_since_unix_epoch(nanos + 999_999_999).unwrap();
+            assert_eq!(precise, dt.with_nanosecond(999_999_999).unwrap());
+            assert_eq!(precise.nanos_since_unix_epoch(), Ok(nanos + 999_999_999));
+        }
+        // Ordering is preserved
+        assert!(
+            pdt(1403, 5, 2, 0, 0, 0).nanos_since_unix_epoch().unwrap()
+                < pdt(1403, 5, 2, 0, 0, 1).nanos_since_unix_epoch().unwrap()
+        );
+
+        assert!(ParsiDateTime::from_nanos_since_unix_epoch(i128::MAX).is_err());
+        assert!(ParsiDateTime::from_nanos_since_unix_epoch(i128::MIN).is_err());
+        let invalid = unsafe { ParsiDateTime::new_unchecked(1404, 12, 30, 0, 0, 0) };
+        assert_eq!(
+            invalid.nanos_since_unix_epoch(),
+            Err(DateError::InvalidDate)
+        );
+    }
+
     // --- Formatting Tests ---
     #[test]
     fn test_format_datetime() {
@@ -221,6 +541,24 @@ mod datetime_tests {
         );
     }
 
+    #[test]
+    fn test_format_datetime_quoted_literals() {
+        let dt = pdt(1403, 5, 2, 8, 5, 3);
+        assert_eq!(dt.format("%T 'at' %d"), "08:05:03 at 02");
+        assert_eq!(dt.format("'%H:%M'' = '%H:%M"), "%H:%M' = 08:05");
+    }
+
+    #[test]
+    fn test_display_alternate_datetime() {
+        let dt = pdt(1403, 5, 2, 8, 5, 30);
+        assert_eq!(format!("{}", dt), "1403/05/02 08:05:30");
+        assert_eq!(format!("{:#}", dt), "سه‌شنبه، 2 مرداد 1403 - 08:05:30");
+        assert_eq!(
+            format!("{:#}", pdt(1403, 12, 30, 23, 59, 9)),
+            "پنجشنبه، 30 اسفند 1403 - 23:59:09"
+        );
+    }
+
     // --- Parsing Tests ---
     #[test]
     fn test_parse_datetime() {
@@ -269,6 +607,210 @@ mod datetime_tests {
         ); // Invalid date part
     }
 
+    #[test]
+    fn test_parse_datetime_non_padded_time() {
+        let fmt = "%Y/%m/%d %-H:%-M:%-S";
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 9:5:3", fmt),
+            Ok(pdt(1403, 5, 2, 9, 5, 3))
+        );
+        // Two-digit values are still accepted
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 09:05:03", fmt),
+            Ok(pdt(1403, 5, 2, 9, 5, 3))
+        );
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 23:59:59", fmt),
+            Ok(pdt(1403, 5, 2, 23, 59, 59))
+        );
+        // Range validation still applies
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 24:0:0", fmt),
+            Err(DateError::ParseError(ParseErrorKind::InvalidTimeValue))
+        );
+        // At most two digits are consumed
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 123:0:0", fmt),
+            Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+        );
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 :0:0", fmt),
+            Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+        );
+        // Strict specifiers keep requiring two digits
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 9:05:03", "%Y/%m/%d %H:%M:%S"),
+            Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+        );
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 9", "%Y/%m/%d %-X"),
+            Err(DateError::ParseError(ParseErrorKind::UnsupportedSpecifier))
+        );
+        // Non-padded date fields
+        assert_eq!(
+            ParsiDateTime::parse("1403/5/2 9:5:3", "%-Y/%-m/%-d %-H:%-M:%-S"),
+            Ok(pdt(1403, 5, 2, 9, 5, 3))
+        );
+    }
+
+    #[test]
+    fn test_checked_add_sub_duration() {
+        let dt = pdt(1403, 5, 2, 10, 30, 15);
+        assert_eq!(
+            dt.checked_add_duration(Duration::days(1)),
+            dt.add_duration(Duration::days(1)).ok()
+        );
+        assert_eq!(
+            dt.checked_sub_duration(Duration::minutes(31)),
+            Some(pdt(1403, 5, 2, 9, 59, 15))
+        );
+        // Out of supported range
+        let max = pdt(9999, 12, 29, 23, 59, 59);
+        assert_eq!(max.checked_add_duration(Duration::seconds(1)), None);
+        assert_eq!(
+            pdt(1, 1, 1, 0, 0, 0).checked_sub_duration(Duration::seconds(1)),
+            None
+        );
+        // Overflow in chrono itself
+        assert_eq!(dt.checked_add_duration(Duration::MAX), None);
+        assert_eq!(dt.checked_sub_duration(Duration::MAX), None);
+        // Invalid self yields None
+        let invalid = unsafe { ParsiDateTime::new_unchecked(1404, 12, 30, 10, 0, 0) };
+        assert_eq!(invalid.checked_add_duration(Duration::seconds(1)), None);
+        assert_eq!(invalid.checked_sub_duration(Duration::seconds(1)), None);
+    }
+
+    #[test]
+    fn test_sorted_slice_lookups_datetime() {
+        let events = [
+            pdt(1403, 5, 1, 23, 0, 0),
+            pdt(1403, 5, 2, 8, 0, 0),
+            pdt(1403, 5, 2, 12, 0, 0),
+        ];
+        assert_eq!(ParsiDateTime::binary_search_in(&events, &events[2]), Ok(2));
+        assert_eq!(
+            ParsiDateTime::binary_search_in(&events, &pdt(1403, 5, 2, 9, 0, 0)),
+            Err(2)
+        );
+        assert_eq!(
+            pdt(1403, 5, 2, 1, 0, 0).nearest_in_sorted(&events),
+            Some(&events[0])
+        );
+        assert_eq!(
+            pdt(1403, 5, 2, 10, 0, 0).nearest_in_sorted(&events),
+            Some(&events[1])
+        ); // Tie: earlier entry
+        assert_eq!(
+            pdt(1403, 5, 2, 10, 0, 1).nearest_in_sorted(&events),
+            Some(&events[2])
+        );
+        assert_eq!(pdt(1403, 5, 2, 10, 0, 0).nearest_in_sorted(&[]), None);
+    }
+
+    #[test]
+    fn test_parse_datetime_12_hour_clock() {
+        let fmt = "%Y/%m/%d %I:%M:%S %p";
+        let cases = [
+            ("1403/05/02 02:30:00 ب.ظ", 14),
+            ("1403/05/02 02:30:00 ق.ظ", 2),
+            ("1403/05/02 12:30:00 ق.ظ", 0),  // 12 AM -> midnight
+            ("1403/05/02 12:30:00 ب.ظ", 12), // 12 PM -> noon
+            ("1403/05/02 11:30:00 PM", 23),
+            ("1403/05/02 01:30:00 am", 1),
+        ];
+        for (input, hour) in cases {
+            assert_eq!(
+                ParsiDateTime::parse(input, fmt),
+                Ok(pdt(1403, 5, 2, hour, 30, 0)),
+                "input {:?}",
+                input
+            );
+        }
+        // Marker before the time
+        assert_eq!(
+            ParsiDateTime::parse("ب.ظ 09:15:00 1403/05/02", "%p %I:%M:%S %Y/%m/%d"),
+            Ok(pdt(1403, 5, 2, 21, 15, 0))
+        );
+
+        // Errors
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 13:30:00 ب.ظ", fmt),
+            Err(DateError::ParseError(ParseErrorKind::InvalidTimeValue))
+        );
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 00:30:00 ق.ظ", fmt),
+            Err(DateError::ParseError(ParseErrorKind::InvalidTimeValue))
+        );
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 02:30:00 عصر", fmt),
+            Err(DateError::ParseError(ParseErrorKind::InvalidAmPmMarker))
+        );
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 02:30:00", "%Y/%m/%d %I:%M:%S"),
+            Err(DateError::ParseError(
+                ParseErrorKind::IncompleteTwelveHourTime
+            ))
+        );
+        assert_eq!(
+            ParsiDateTime::parse("1403/05/02 14:30:00 ب.ظ", "%Y/%m/%d %H:%M:%S %p"),
+            Err(DateError::ParseError(
+                ParseErrorKind::IncompleteTwelveHourTime
+            ))
+        );
+    }
+
+    #[test]
+    fn test_format_12_hour_clock() {
+        let cases = [
+            (0, "12 ق.ظ", "12 AM"),
+            (1, "01 ق.ظ", "01 AM"),
+            (11, "11 ق.ظ", "11 AM"),
+            (12, "12 ب.ظ", "12 PM"),
+            (13, "01 ب.ظ", "01 PM"),
+            (23, "11 ب.ظ", "11 PM"),
+        ];
+        for (hour, persian, english) in cases {
+            let dt = pdt(1403, 5, 2, hour, 30, 0);
+            assert_eq!(dt.format("%I %p"), persian);
+            assert_eq!(dt.format("%I %Ep"), english);
+            // Both forms parse back to the same hour
+            for fmt in ["%Y/%m/%d %I:%M:%S %p", "%Y/%m/%d %I:%M:%S %Ep"] {
+                assert_eq!(ParsiDateTime::parse(&dt.format(fmt), fmt), Ok(dt), "{fmt}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_leap_second() {
+        let time_err = Err(DateError::ParseError(ParseErrorKind::InvalidTimeValue));
+        // Strict parsing never accepts a leap second
+        assert_eq!(
+            ParsiDateTime::parse("1403/12/30 23:59:60", "%Y/%m/%d %T"),
+            time_err
+        );
+        assert_eq!(
+            ParsiDateTime::parse("1403/12/30 23:59:60", "%Y/%m/%d %H:%M:%S"),
+            time_err
+        );
+        // The lenient variant clamps :60 to :59 without rolling over
+        assert_eq!(
+            ParsiDateTime::parse_allowing_leap_second("1403/12/30 23:59:60", "%Y/%m/%d %T"),
+            Ok(pdt(1403, 12, 30, 23, 59, 59))
+        );
+        assert_eq!(
+            ParsiDateTime::parse_allowing_leap_second("1403/12/30 23:59:58", "%Y/%m/%d %T"),
+            Ok(pdt(1403, 12, 30, 23, 59, 58))
+        );
+        assert_eq!(
+            ParsiDateTime::parse_allowing_leap_second("1403/12/30 23:59:61", "%Y/%m/%d %T"),
+            time_err
+        );
+        assert_eq!(
+            ParsiDateTime::parse_allowing_leap_second("1403/12/30 23:60:00", "%Y/%m/%d %T"),
+            time_err
+        );
+    }
+
     // --- Arithmetic Tests ---
     #[test]
     fn test_add_sub_duration() {
@@ -358,6 +900,90 @@ mod datetime_tests {
         assert_eq!(dt_precise.add_years(1).unwrap().time(), (1, 2, 3));
     }
 
+    #[test]
+    fn test_add_sub_weeks_datetime() {
+        let dt = pdt(1403, 12, 27, 23, 59, 58);
+        assert_eq!(dt.add_weeks(1), Ok(pdt(1404, 1, 4, 23, 59, 58)));
+        assert_eq!(dt.sub_weeks(52), Ok(pdt(1402, 12, 28, 23, 59, 58)));
+        assert_eq!(dt.add_weeks(i64::MAX), Err(DateError::ArithmeticOverflow));
+
+        let invalid_time = unsafe { ParsiDateTime::new_unchecked(1403, 5, 2, 24, 0, 0) };
+        assert_eq!(invalid_time.add_weeks(1), Err(DateError::InvalidTime));
+        assert_eq!(invalid_time.sub_weeks(1), Err(DateError::InvalidTime));
+    }
+
+    #[test]
+    fn test_humanize_since() {
+        let now = pdt(1403, 5, 10, 12, 0, 0);
+        let cases = [
+            (pdt(1403, 5, 10, 11, 59, 59), "۱ ثانیه پیش", "1 second ago"),
+            (
+                pdt(1403, 5, 10, 12, 0, 45),
+                "۴۵ ثانیه دیگر",
+                "in 45 seconds",
+            ),
+            (pdt(1403, 5, 10, 11, 0, 1), "۵۹ دقیقه پیش", "59 minutes ago"),
+            (pdt(1403, 5, 11, 11, 59, 59), "۲۳ ساعت دیگر", "in 23 hours"),
+            (pdt(1403, 5, 9, 12, 0, 0), "۱ روز پیش", "1 day ago"),
+            (pdt(1403, 6, 9, 12, 0, 0), "۳۰ روز دیگر", "in 30 days"),
+            (pdt(1403, 6, 10, 12, 0, 0), "۱ ماه دیگر", "in 1 month"),
+            (pdt(1402, 5, 11, 12, 0, 0), "۱۱ ماه پیش", "11 months ago"),
+            (pdt(1402, 5, 10, 12, 0, 0), "۱ سال پیش", "1 year ago"),
+            (pdt(9999, 1, 1, 0, 0, 0), "۸۵۹۵ سال دیگر", "in 8595 years"),
+        ];
+        for (dt, persian, english) in cases {
+            assert_eq!(dt.humanize_since(now).unwrap(), persian, "{dt}");
+            assert_eq!(dt.humanize_since_english(now).unwrap(), english, "{dt}");
+        }
+
+        // Less than a second apart is "now"
+        let almost = now.with_nanosecond(999_999_999).unwrap();
+        assert_eq!(almost.humanize_since(now).unwrap(), "اکنون");
+        assert_eq!(now.humanize_since_english(almost).unwrap(), "now");
+
+        let invalid = unsafe { ParsiDateTime::new_unchecked(1403, 5, 2, 24, 0, 0) };
+        assert_eq!(invalid.humanize_since(now), Err(DateError::InvalidTime));
+        assert_eq!(
+            now.humanize_since_english(invalid),
+            Err(DateError::InvalidTime)
+        );
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = pdt(1403, 5, 2, 10, 0, 0);
+        let b = pdt(1403, 5, 2, 10, 0, 1);
+        assert_eq!(a.approx_eq(&a, Duration::zero()), Ok(true));
+        assert_eq!(a.approx_eq(&b, Duration::zero()), Ok(false));
+        assert_eq!(a.approx_eq(&b, Duration::seconds(1)), Ok(true));
+        assert_eq!(b.approx_eq(&a, Duration::seconds(1)), Ok(true));
+        assert_eq!(a.approx_eq(&a, Duration::seconds(-1)), Ok(false));
+        assert_eq!(a.approx_eq(&b, Duration::MAX), Ok(true));
+
+        let invalid = unsafe { ParsiDateTime::new_unchecked(1403, 5, 2, 24, 0, 0) };
+        assert_eq!(
+            a.approx_eq(&invalid, Duration::days(1)),
+            Err(DateError::InvalidTime)
+        );
+    }
+
+    #[test]
+    fn test_with_fixed_offset() {
+        let dt = pdt(1403, 5, 2, 10, 0, 0);
+        assert_eq!(dt.with_fixed_offset(0), Ok(dt));
+        assert_eq!(dt.with_fixed_offset(12600), Ok(pdt(1403, 5, 2, 13, 30, 0)));
+        assert_eq!(
+            dt.with_fixed_offset(-11 * 3600),
+            Ok(pdt(1403, 5, 1, 23, 0, 0))
+        );
+        assert_eq!(
+            dt.with_fixed_offset(12600),
+            dt.add_duration(Duration::seconds(12600))
+        );
+        let invalid = unsafe { ParsiDateTime::new_unchecked(1403, 5, 2, 24, 0, 0) };
+        assert_eq!(invalid.with_fixed_offset(60), Err(DateError::InvalidTime));
+    }
+
     // --- Helper Method Tests ---
     #[test]
     fn test_with_time_components() {
@@ -375,6 +1001,49 @@ mod datetime_tests {
         assert_eq!(dt.with_time(10, 60, 0), Err(DateError::InvalidTime));
     }
 
+    #[test]
+    fn test_seconds_from_midnight_and_timelike() {
+        use crate::ParsiTimelike;
+
+        let dt = pdt(1403, 5, 2, 10, 20, 30);
+        assert_eq!(dt.num_seconds_from_midnight(), 37_230);
+        assert_eq!(
+            pdt(1403, 5, 2, 23, 59, 59).num_seconds_from_midnight(),
+            86_399
+        );
+        for secs in [0, 1, 59, 60, 3_599, 3_600, 37_230, 86_399] {
+            let moved = dt.with_seconds_from_midnight(secs).unwrap();
+            assert_eq!(moved.num_seconds_from_midnight(), secs);
+            assert_eq!(moved.date(), dt.date());
+        }
+        assert_eq!(
+            dt.with_seconds_from_midnight(86_400),
+            Err(DateError::InvalidTime)
+        );
+        let invalid_date = unsafe { ParsiDateTime::new_unchecked(1404, 12, 30, 0, 0, 0) };
+        assert_eq!(
+            invalid_date.with_seconds_from_midnight(10),
+            Err(DateError::InvalidDate)
+        );
+
+        // The trait agrees with the inherent methods.
+        fn bump_minute<T: ParsiTimelike>(value: &T) -> Result<T, DateError> {
+            value.with_seconds_from_midnight(value.num_seconds_from_midnight() + 60)
+        }
+        assert_eq!(bump_minute(&dt), Ok(pdt(1403, 5, 2, 10, 21, 30)));
+        assert_eq!(
+            bump_minute(&pdt(1403, 5, 2, 23, 59, 0)),
+            Err(DateError::InvalidTime)
+        );
+        assert_eq!(ParsiTimelike::with_hour(&dt, 11), dt.with_hour(11));
+        assert_eq!(ParsiTimelike::with_minute(&dt, 60), dt.with_minute(60));
+        assert_eq!(ParsiTimelike::with_second(&dt, 5), dt.with_second(5));
+        assert_eq!(
+            ParsiTimelike::num_seconds_from_midnight(&dt),
+            dt.num_seconds_from_midnight()
+        );
+    }
+
     #[test]
     fn test_with_date_components_datetime() {
         let dt = pdt(1403, 12, 30, 12, 34, 56); // Leap end
@@ -389,6 +1058,19 @@ mod datetime_tests {
         assert_eq!(dt.with_day(31), Err(DateError::InvalidDate)); // Esfand never has 31 days
     }
 
+    #[test]
+    fn test_parts_and_into_parts() {
+        let dt = pdt(1403, 5, 2, 10, 20, 30);
+        assert_eq!(dt.parts(), (pd(1403, 5, 2), (10, 20, 30)));
+        let (date, (h, m, s)) = dt.into_parts();
+        assert_eq!(date, dt.date());
+        assert_eq!((h, m, s), dt.time());
+        assert_eq!(
+            ParsiDateTime::from_date_and_time(date, h, m, s).unwrap(),
+            dt
+        );
+    }
+
     // --- Serde Tests (conditional on 'serde' feature) ---
     #[cfg(feature = "serde")]
     mod serde_tests_dt {
@@ -397,8 +1079,7 @@ mod datetime_tests {
         #[test]
         fn test_datetime_serialization_deserialization() {
             let dt = pdt(1403, 5, 2, 10, 20, 30);
-            let expected_json =
-                r#"{"date":{"year":1403,"month":5,"day":2},"hour":10,"minute":20,"second":30}"#;
+            let expected_json = r#"{"date":{"year":1403,"month":5,"day":2},"hour":10,"minute":20,"second":30,"nanosecond":0}"#;
 
             let json = serde_json::to_string(&dt).expect("Serialization failed");
             assert_eq!(json, expected_json);
@@ -432,6 +1113,22 @@ mod datetime_tests {
                 r#"{"date":{"year":1403,"month":5,"day":2},"hour":10,"minute":20}"#; // Missing second
             assert!(serde_json::from_str::<ParsiDateTime>(json_missing_field).is_err());
         }
+
+        #[test]
+        fn test_datetime_serde_nanosecond() {
+            let dt = ParsiDateTime::new_with_nano(1403, 5, 2, 10, 20, 30, 500).unwrap();
+            let json = serde_json::to_string(&dt).unwrap();
+            assert!(json.ends_with(r#""second":30,"nanosecond":500}"#));
+            assert_eq!(serde_json::from_str::<ParsiDateTime>(&json).unwrap(), dt);
+
+            // Data written before the field existed still deserializes, with a zero fraction.
+            let legacy =
+                r#"{"date":{"year":1403,"month":5,"day":2},"hour":10,"minute":20,"second":30}"#;
+            assert_eq!(
+                serde_json::from_str::<ParsiDateTime>(legacy).unwrap(),
+                pdt(1403, 5, 2, 10, 20, 30)
+            );
+        }
     }
 } // end mod datetime_tests
 
@@ -568,6 +1265,50 @@ fn test_from_ordinal() {
     assert_eq!(ParsiDate::from_ordinal(0, 100), Err(DateError::InvalidDate)); // Example check
 }
 
+#[test]
+fn test_from_ordinal_error_kinds() {
+    // Ordinal-range failures within a supported year
+    for (year, ordinal) in [(1403, 0), (1403, 367), (1404, 366), (9999, 367)] {
+        assert_eq!(
+            ParsiDate::from_ordinal(year, ordinal),
+            Err(DateError::InvalidOrdinal),
+            "{}/{}",
+            year,
+            ordinal
+        );
+        assert_eq!(ParsiDate::checked_from_ordinal(year, ordinal), None);
+    }
+    // Year-range failures take precedence over the ordinal
+    for (year, ordinal) in [(0, 1), (0, 0), (-5, 400), (10000, 1), (10000, 366)] {
+        assert_eq!(
+            ParsiDate::from_ordinal(year, ordinal),
+            Err(DateError::InvalidDate),
+            "{}/{}",
+            year,
+            ordinal
+        );
+        assert_eq!(ParsiDate::checked_from_ordinal(year, ordinal), None);
+    }
+    // Valid combinations at both ends of the supported range
+    assert_eq!(ParsiDate::checked_from_ordinal(1, 1), Some(MIN_PARSI_DATE));
+    let last = if ParsiDate::is_persian_leap_year(9999) {
+        366
+    } else {
+        365
+    };
+    assert_eq!(
+        ParsiDate::checked_from_ordinal(9999, last),
+        Some(MAX_PARSI_DATE)
+    );
+}
+
+#[test]
+fn test_as_tuple() {
+    assert_eq!(pd(1403, 5, 2).as_tuple(), (1403, 5, 2));
+    let (y, m, d) = MAX_PARSI_DATE.as_tuple();
+    assert_eq!(ParsiDate::new(y, m, d), Ok(MAX_PARSI_DATE));
+}
+
 // --- Conversion Tests ---
 #[test]
 fn test_gregorian_to_persian() {
@@ -709,7 +1450,50 @@ fn test_today_function() {
     }
 }
 
+#[test]
+fn test_is_today() {
+    let today = ParsiDate::today().expect("today() should succeed");
+    assert!(today.is_today());
+    assert_eq!(today.is_today_result(), Ok(true));
+    let yesterday = today.add_days(-1).unwrap();
+    assert!(!yesterday.is_today());
+    assert_eq!(yesterday.is_today_result(), Ok(false));
+    assert!(!MIN_PARSI_DATE.is_today());
+}
+
+#[test]
+fn test_named_comparisons() {
+    let a = pd(1402, 12, 29);
+    let b = pd(1403, 1, 1);
+    assert!(a.is_before(&b) && !b.is_before(&a) && !a.is_before(&a));
+    assert!(b.is_after(&a) && !a.is_after(&b) && !b.is_after(&b));
+    assert!(a.is_same_or_before(&b) && a.is_same_or_before(&a) && !b.is_same_or_before(&a));
+    assert!(b.is_same_or_after(&a) && b.is_same_or_after(&b) && !a.is_same_or_after(&b));
+    // Consistent with the derived ordering across month and year boundaries.
+    assert!(pd(1403, 1, 31).is_before(&pd(1403, 2, 1)));
+    assert!(MAX_PARSI_DATE.is_after(&MIN_PARSI_DATE));
+}
+
 // --- Leap Year & DaysInMonth Tests ---
+#[test]
+fn test_leap_years_match_official_table() {
+    // Leap years of the official Iranian calendar in the commonly published span 1206-1498.
+    const OFFICIAL_LEAP_YEARS: [i32; 71] = [
+        1210, 1214, 1218, 1222, 1226, 1230, 1234, 1238, 1243, 1247, 1251, 1255, 1259, 1263, 1267,
+        1271, 1276, 1280, 1284, 1288, 1292, 1296, 1300, 1304, 1309, 1313, 1317, 1321, 1325, 1329,
+        1333, 1337, 1342, 1346, 1350, 1354, 1358, 1362, 1366, 1370, 1375, 1379, 1383, 1387, 1391,
+        1395, 1399, 1403, 1408, 1412, 1416, 1420, 1424, 1428, 1432, 1436, 1441, 1445, 1449, 1453,
+        1457, 1461, 1465, 1469, 1474, 1478, 1482, 1486, 1490, 1494, 1498,
+    ];
+    for year in 1206..=1498 {
+        assert_eq!(
+            ParsiDate::is_persian_leap_year(year),
+            OFFICIAL_LEAP_YEARS.contains(&year),
+            "{year}"
+        );
+    }
+}
+
 #[test]
 fn test_leap_years() {
     // Test cases based on the 33-year cycle rule: year % 33 in {1, 5, 9, 13, 17, 22, 26, 30}
@@ -795,6 +1579,76 @@ fn test_days_in_month() {
     assert_eq!(ParsiDate::days_in_month(1403, 13), 0, "Invalid month 13");
 }
 
+#[test]
+fn test_month_lengths_of() {
+    let leap = ParsiDate::month_lengths_of(1403);
+    let common = ParsiDate::month_lengths_of(1404);
+    for month in 1..=12u32 {
+        assert_eq!(
+            leap[(month - 1) as usize],
+            ParsiDate::days_in_month(1403, month)
+        );
+        assert_eq!(
+            common[(month - 1) as usize],
+            ParsiDate::days_in_month(1404, month)
+        );
+    }
+    assert_eq!(leap.iter().sum::<u32>(), 366);
+    assert_eq!(common.iter().sum::<u32>(), 365);
+}
+
+#[test]
+fn test_month_start_offsets() {
+    for year in [1, 1399, 1403, 1404, 9999] {
+        let offsets = ParsiDate::month_start_offsets(year);
+        let lengths = ParsiDate::month_lengths_of(year);
+        assert_eq!(offsets[0], 0);
+        for month in 0..12 {
+            assert_eq!(offsets[month + 1] - offsets[month], lengths[month]);
+        }
+        // Ordinal and Gregorian round trips over every day of the year
+        for ordinal in 1..=offsets[12] {
+            let date = ParsiDate::from_ordinal(year, ordinal).unwrap();
+            assert_eq!(
+                offsets[date.month() as usize - 1] + date.day(),
+                ordinal,
+                "{}",
+                date
+            );
+            assert_eq!(date.ordinal(), Ok(ordinal));
+            assert_eq!(
+                ParsiDate::from_gregorian(date.to_gregorian().unwrap()),
+                Ok(date)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_year_calendar() {
+    for year in [1, 1399, 1403, 1404, 9999] {
+        let calendar = ParsiDate::year_calendar(year).unwrap();
+        assert_eq!(calendar.year, year);
+        assert_eq!(calendar.is_leap, ParsiDate::is_persian_leap_year(year));
+        assert_eq!(
+            calendar.len_days(),
+            if calendar.is_leap { 366 } else { 365 }
+        );
+        for info in calendar.months.iter() {
+            let first = pd(year, info.month, 1);
+            assert_eq!(info.length, ParsiDate::days_in_month(year, info.month));
+            assert_eq!(Ok(info.first_weekday), first.weekday_num_sat_0());
+            assert_eq!(Ok(info.season), first.season());
+            assert_eq!(calendar.month(info.month), Some(info));
+        }
+    }
+    let calendar = ParsiDate::year_calendar(1403).unwrap();
+    assert!(calendar.month(0).is_none());
+    assert!(calendar.month(13).is_none());
+    assert_eq!(ParsiDate::year_calendar(0), Err(DateError::InvalidDate));
+    assert_eq!(ParsiDate::year_calendar(10000), Err(DateError::InvalidDate));
+}
+
 // --- Formatting Tests ---
 #[test]
 fn test_format_predefined() {
@@ -876,210 +1730,1250 @@ fn test_format_strftime() {
     );
 }
 
-// --- Parsing Tests ---
 #[test]
-fn test_parse_simple() {
-    // Basic YMD formats with different separators
-    assert_eq!(
-        ParsiDate::parse("1403/05/02", "%Y/%m/%d"),
-        Ok(pd(1403, 5, 2))
-    );
+fn test_format_bidi_isolated() {
+    use crate::BidiIsolate;
+    let d = pd(1403, 5, 2);
     assert_eq!(
-        ParsiDate::parse("1403-01-31", "%Y-%m-%d"),
-        Ok(pd(1403, 1, 31))
+        d.format_bidi_isolated("iso", BidiIsolate::LeftToRight),
+        "\u{2066}1403-05-02\u{2069}"
     );
-    // Different order of components
     assert_eq!(
-        ParsiDate::parse("07/04/1399", "%d/%m/%Y"),
-        Ok(pd(1399, 4, 7))
+        d.format_bidi_isolated("long", BidiIsolate::RightToLeft),
+        "\u{2067}2 مرداد 1403\u{2069}"
     );
-    // Test parsing epoch start and max supported date
-    assert_eq!(ParsiDate::parse("0001/01/01", "%Y/%m/%d"), Ok(pd(1, 1, 1)));
+    let fsi = d.format_bidi_isolated("%Y/%m/%d", BidiIsolate::FirstStrong);
+    assert!(fsi.starts_with('\u{2068}'));
+    assert!(fsi.ends_with('\u{2069}'));
     assert_eq!(
-        ParsiDate::parse("9999/12/29", "%Y/%m/%d"),
-        Ok(pd(9999, 12, 29)),
-        "Max date (9999 is common)"
+        fsi.trim_matches(|c| c == '\u{2068}' || c == '\u{2069}'),
+        "1403/05/02"
     );
 }
 
 #[test]
-fn test_parse_month_name() {
-    // %d requires padded day (2 digits)
+fn test_format_english_weekday() {
+    // 1403/01/07 is Tuesday 2024-03-26.
+    let d = pd(1403, 1, 7);
+    assert_eq!(d.format("%A (%GA)"), "سه‌شنبه (Tuesday)");
+    assert_eq!(d.format("%Ga %Eg"), "Tue (2024-03-26)");
+    // The English name follows the Persian one across a whole week.
+    let persian_to_english = [
+        ("شنبه", "Saturday"),
+        ("یکشنبه", "Sunday"),
+        ("دوشنبه", "Monday"),
+        ("سه‌شنبه", "Tuesday"),
+        ("چهارشنبه", "Wednesday"),
+        ("پنجشنبه", "Thursday"),
+        ("جمعه", "Friday"),
+    ];
+    let saturday = pd(1403, 1, 4);
+    for (offset, (fa, en)) in persian_to_english.iter().enumerate() {
+        let day = saturday.add_days(offset as i64).unwrap();
+        assert_eq!(
+            day.format("%A|%GA|%Ga"),
+            format!("{}|{}|{}", fa, en, &en[..3])
+        );
+        assert_eq!(day.weekday().unwrap(), *fa);
+    }
+    // %G alone still prints the week-based year; quoting separates a literal 'a'.
+    assert_eq!(d.format("%G"), "1403");
+    assert_eq!(d.format("%G'a'"), "1403a");
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(invalid.format("%GA"), "?WeekdayError?");
+    // ParsiDateTime
+    let dt = crate::ParsiDateTime::new(1403, 1, 7, 8, 0, 0).unwrap();
+    assert_eq!(dt.format("%A (%GA) %H:%M"), "سه‌شنبه (Tuesday) 08:00");
+}
+
+#[test]
+fn test_format_finglish_names() {
+    use crate::{ParsiDateTime, Season};
+
+    let date = pd(1403, 5, 2); // A Tuesday
+    assert_eq!(date.format("%a %d %b %Y"), "Seshanbeh 02 Mordad 1403");
+    assert_eq!(pd(1403, 12, 30).format("%b"), "Esfand");
+    assert_eq!(pd(1404, 1, 7).format("%a"), "Panjshanbeh");
+    // %Ga keeps its meaning; %a is the Persian weekday in Latin letters
+    assert_eq!(date.format("%Ga/%a"), "Tue/Seshanbeh");
+
+    let months: Vec<String> = (1..=12).map(|m| pd(1403, m, 1).format("%b")).collect();
     assert_eq!(
-        ParsiDate::parse("02 مرداد 1403", "%d %B %Y"),
-        Ok(pd(1403, 5, 2))
+        months,
+        [
+            "Farvardin",
+            "Ordibehesht",
+            "Khordad",
+            "Tir",
+            "Mordad",
+            "Shahrivar",
+            "Mehr",
+            "Aban",
+            "Azar",
+            "Dey",
+            "Bahman",
+            "Esfand"
+        ]
     );
-    // End of leap year with month name
+    // 1403/05/06 is a Saturday
+    let weekdays: Vec<String> = (0..7).map(|d| pd(1403, 5, 6 + d).format("%a")).collect();
     assert_eq!(
-        ParsiDate::parse("30 اسفند 1399", "%d %B %Y"),
-        Ok(pd(1399, 12, 30)), // 1399 is leap
-        "End of leap year with %B"
+        weekdays,
+        [
+            "Shanbeh",
+            "Yekshanbeh",
+            "Doshanbeh",
+            "Seshanbeh",
+            "Chaharshanbeh",
+            "Panjshanbeh",
+            "Jomeh"
+        ]
     );
-    // End of common year with month name
+
+    let dt = ParsiDateTime::new(1403, 5, 2, 8, 5, 0).unwrap();
+    assert_eq!(dt.format("%a %d %b %H:%M"), "Seshanbeh 02 Mordad 08:05");
+    assert_eq!(Season::Tabestan.name_finglish(), "Tabestan");
+    assert_eq!(Season::Zemestan.name_finglish(), "Zemestan");
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
+    assert_eq!(invalid.format("%b"), "?InvalidMonth?");
+}
+
+#[test]
+fn test_format_strftime_localized() {
+    use crate::DigitStyle;
+    let d = pd(1403, 1, 7); // A Tuesday
+    let persian = DigitStyle::Persian;
     assert_eq!(
-        ParsiDate::parse("29 اسفند 1404", "%d %B %Y"),
-        Ok(pd(1404, 12, 29)), // 1404 is common
-        "End of common year with %B"
+        d.format_strftime_localized("%Y-%m-%d", persian),
+        "۱۴۰۳-۰۱-۰۷"
     );
-    // Test with exact single spaces as required by the current parser implementation
     assert_eq!(
-        ParsiDate::parse("10 دی 1400", "%d %B %Y"),
-        Ok(pd(1400, 10, 10))
+        d.format_strftime_localized("%j %W %w %0Y", persian),
+        "۰۰۷ ۰۲ ۳ ۱۴۰۳"
     );
-    // Test month name at different positions in format string
+    // Names, literals and the percent escape are untouched.
     assert_eq!(
-        ParsiDate::parse("1400-دی-10", "%Y-%B-%d"),
-        Ok(pd(1400, 10, 10))
+        d.format_strftime_localized("%A، %d %B %Y (%K)", persian),
+        "سه‌شنبه، ۰۷ فروردین ۱۴۰۳ (بهار)"
     );
     assert_eq!(
-        ParsiDate::parse("فروردین-01-1390", "%B-%d-%Y"),
-        Ok(pd(1390, 1, 1))
+        d.format_strftime_localized("'2024' 5%% %m %x", persian),
+        "2024 5% ۰۱ %x"
     );
-    // Test month name followed immediately by year
+    assert_eq!(d.format_strftime_localized("%Eg", persian), "(۲۰۲۴-۰۳-۲۶)");
+    // Ascii reproduces format_strftime.
+    for pattern in ["%Y/%m/%d", "%d %B %Y", "%G-W%V", "'x' %j%%"] {
+        assert_eq!(
+            d.format_strftime_localized(pattern, DigitStyle::Ascii),
+            d.format_strftime(pattern)
+        );
+    }
+    assert_eq!(DigitStyle::default(), DigitStyle::Ascii);
+    assert_eq!(persian.convert("a1b2 ۳"), "a۱b۲ ۳");
+    // Invalid dates keep their placeholders.
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
     assert_eq!(
-        ParsiDate::parse("01اردیبهشت1395", "%d%B%Y"),
-        Ok(pd(1395, 2, 1))
+        invalid.format_strftime_localized("%d %j", persian),
+        "۳۰ ???"
     );
 }
 
 #[test]
-fn test_parse_errors() {
-    // --- Invalid Number Errors ---
-    // %m and %d require exactly two digits
-    assert_eq!(
-        ParsiDate::parse("1403/5/02", "%Y/%m/%d").unwrap_err(),
-        DateError::ParseError(ParseErrorKind::InvalidNumber),
-        "Single digit month for %m"
-    );
-    assert_eq!(
-        ParsiDate::parse("1403/05/2", "%Y/%m/%d").unwrap_err(),
-        DateError::ParseError(ParseErrorKind::InvalidNumber),
-        "Single digit day for %d"
-    );
-    // %Y requires exactly four digits
-    assert_eq!(
-        ParsiDate::parse("403/01/01", "%Y/%m/%d").unwrap_err(),
-        DateError::ParseError(ParseErrorKind::InvalidNumber),
-        "Three digit year for %Y"
-    );
-    // Non-digit characters where digits are expected
+fn test_format_with_names() {
+    use crate::ParsiNames;
+    let d = pd(1403, 1, 7); // Tuesday, Bahar
+
+    // The Iranian table matches the default formatter exactly
+    for pattern in ["%A %d %B %Y %K", "long", "short", "iso", "%w %j %W %%"] {
+        assert_eq!(
+            d.format_with_names(pattern, &ParsiNames::IRANIAN),
+            d.format(pattern)
+        );
+    }
+    assert_eq!(ParsiNames::default(), ParsiNames::IRANIAN);
+
+    let custom = ParsiNames {
+        months: [
+            "M1", "M2", "M3", "M4", "M5", "M6", "M7", "M8", "M9", "M10", "M11", "M12",
+        ],
+        weekdays: ["Sa", "Su", "Mo", "Tu", "We", "Th", "Fr"],
+        seasons: ["Spring", "Summer", "Autumn", "Winter"],
+    };
     assert_eq!(
-        ParsiDate::parse("1403/XX/01", "%Y/%m/%d").unwrap_err(),
-        DateError::ParseError(ParseErrorKind::InvalidNumber),
-        "Non-digit month"
+        d.format_with_names("%A %d %B %Y (%K)", &custom),
+        "Tu 07 M1 1403 (Spring)"
     );
     assert_eq!(
-        ParsiDate::parse("ABCD/01/01", "%Y/%m/%d").unwrap_err(),
-        DateError::ParseError(ParseErrorKind::InvalidNumber),
-        "Non-digit year"
+        pd(1403, 12, 30).format_with_names("long", &custom),
+        "30 M12 1403"
     );
 
-    // --- Format Mismatch Errors ---
-    // Missing separators
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
     assert_eq!(
-        ParsiDate::parse("14030502", "%Y/%m/%d").unwrap_err(),
-        DateError::ParseError(ParseErrorKind::FormatMismatch), // Expected '/', got '0'
-        "Missing separators"
+        invalid.format_with_names("%B|%A", &custom),
+        "?InvalidMonth?|?WeekdayError?"
     );
-    // Wrong separator
+}
+
+#[test]
+fn test_format_cow() {
+    use std::borrow::Cow;
+    let d = pd(1403, 5, 2);
+    for pattern in [
+        "",
+        "literal text",
+        "سررسید",
+        "short",
+        "long",
+        "iso",
+        "%d %B %Y",
+        "100%%",
+        "'quoted'",
+        "it''s",
+    ] {
+        assert_eq!(
+            d.format_cow(pattern),
+            d.format(pattern),
+            "pattern {:?}",
+            pattern
+        );
+    }
+    assert!(matches!(d.format_cow("no specifiers"), Cow::Borrowed(_)));
+    assert!(matches!(d.format_cow(""), Cow::Borrowed(_)));
+    assert!(matches!(d.format_cow("short"), Cow::Owned(_)));
+    assert!(matches!(d.format_cow("%Y"), Cow::Owned(_)));
+}
+
+#[test]
+fn test_format_quoted_literals() {
+    let d = pd(1403, 5, 2);
+    assert_eq!(d.format("%Y 'سال' %m"), "1403 سال 05");
+    assert_eq!(d.format("'%Y%m%d'"), "%Y%m%d");
+    assert_eq!(d.format("'Day '%d"), "Day 02");
+    assert_eq!(d.format("''"), "'");
+    assert_eq!(d.format("'it''s' %d"), "it's 02");
+    assert_eq!(d.format("%d''%m"), "02'05");
+    assert_eq!(d.format("'unterminated %Y"), "unterminated %Y");
+    assert_eq!(d.format("%Y''"), "1403'");
+    // Quotes produced by a specifier are not interpreted
+    assert_eq!(d.format("%%'%Y'"), "%%Y");
+}
+
+#[test]
+fn test_year_in_words_persian() {
+    let words = |y| pd(y, 1, 1).year_in_words_persian();
+    assert_eq!(words(1), "یک");
+    assert_eq!(words(13), "سیزده");
+    assert_eq!(words(40), "چهل");
+    assert_eq!(words(111), "صد و یازده");
+    assert_eq!(words(1000), "هزار");
+    assert_eq!(words(1005), "هزار و پنج");
+    assert_eq!(words(1300), "هزار و سیصد");
+    assert_eq!(words(1357), "هزار و سیصد و پنجاه و هفت");
+    assert_eq!(words(1403), "هزار و چهارصد و سه");
+    assert_eq!(words(2000), "دو هزار");
+    assert_eq!(words(3020), "سه هزار و بیست");
+    assert_eq!(words(9999), "نه هزار و نهصد و نود و نه");
+
+    // Out-of-range years fall back to digits
+    let invalid = unsafe { ParsiDate::new_unchecked(0, 1, 1) };
+    assert_eq!(invalid.year_in_words_persian(), "0");
+
+    // %EY specifier; a lone %E is left as-is
+    let date = pd(1403, 5, 2);
+    assert_eq!(date.format("%d %B %EY"), "02 مرداد هزار و چهارصد و سه");
+    assert_eq!(date.format("%E-%Y"), "%E-1403");
     assert_eq!(
-        ParsiDate::parse("1403 05 02", "%Y/%m/%d").unwrap_err(),
-        DateError::ParseError(ParseErrorKind::FormatMismatch), // Expected '/', got ' '
-        "Wrong separator (space vs /)"
+        crate::ParsiDateTime::new(1403, 5, 2, 8, 0, 0)
+            .unwrap()
+            .format("%EY %H"),
+        "هزار و چهارصد و سه 08"
     );
-    // Trailing text not in format
+}
+
+#[test]
+fn test_format_gregorian_shorthand() {
+    let date = pd(1403, 5, 2);
+    assert_eq!(date.format("%Y/%m/%d %Eg"), "1403/05/02 (2024-07-23)");
+    assert_eq!(date.format("%Eg|%Eg"), "(2024-07-23)|(2024-07-23)");
+    assert_eq!(pd(1, 1, 1).format("%Eg"), "(0622-03-21)");
     assert_eq!(
-        ParsiDate::parse("1403/01/01extra", "%Y/%m/%d").unwrap_err(),
-        DateError::ParseError(ParseErrorKind::FormatMismatch),
-        "Trailing text"
+        crate::ParsiDateTime::new(1403, 12, 30, 23, 59, 0)
+            .unwrap()
+            .format("%Y/%m/%d %Eg %H:%M"),
+        "1403/12/30 (2025-03-20) 23:59"
     );
-    // Incomplete input for format
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(invalid.format("%Eg"), "?GregorianError?");
+    // Not a parsing specifier
     assert_eq!(
-        ParsiDate::parse("1403/05", "%Y/%m/%d").unwrap_err(), // Input ends before matching %d
-        DateError::ParseError(ParseErrorKind::FormatMismatch),
-        "Incomplete input"
+        ParsiDate::parse("1403 (2024-07-23)", "%Y %Eg"),
+        Err(DateError::ParseError(ParseErrorKind::UnsupportedSpecifier))
     );
-    // Mismatch with literal format chars
+}
+
+#[test]
+fn test_leap_year_marker() {
+    assert_eq!(pd(1403, 1, 1).leap_year_marker(), "کبیسه");
+    assert_eq!(pd(1404, 1, 1).leap_year_marker(), "");
+    assert_eq!(pd(1403, 5, 2).format("%Y %?L"), "1403 کبیسه");
+    assert_eq!(pd(1404, 5, 2).format("%Y %?L"), "1404 "); // Trailing space is kept
+    assert_eq!(pd(1404, 5, 2).format("%?"), "%?"); // Incomplete specifier stays literal
     assert_eq!(
-        ParsiDate::parse("Year: 1403", "Date: %Y").unwrap_err(),
-        DateError::ParseError(ParseErrorKind::FormatMismatch),
-        "Literal prefix mismatch"
+        crate::ParsiDateTime::new(1403, 12, 30, 10, 0, 0)
+            .unwrap()
+            .format("%Y(%?L) %H"),
+        "1403(کبیسه) 10"
     );
+}
 
-    // --- Invalid Date Value Errors (parsed components are invalid logically) ---
+#[test]
+fn test_debug_names_calendar() {
     assert_eq!(
-        ParsiDate::parse("1403/13/01", "%Y/%m/%d").unwrap_err(), // Month > 12
-        DateError::ParseError(ParseErrorKind::InvalidDateValue),
-        "Invalid month value > 12"
+        format!("{:?}", pd(1403, 5, 2)),
+        "ParsiDate(1403-05-02 [Jalali])"
     );
     assert_eq!(
-        ParsiDate::parse("1403/00/01", "%Y/%m/%d").unwrap_err(), // Month 0
-        DateError::ParseError(ParseErrorKind::InvalidDateValue),
-        "Invalid month value 0"
+        format!("{:?}", pd(50, 1, 9)),
+        "ParsiDate(0050-01-09 [Jalali])"
     );
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
     assert_eq!(
-        ParsiDate::parse("1404/12/30", "%Y/%m/%d").unwrap_err(), // Day 30 invalid for Esfand in common year 1404
-        DateError::ParseError(ParseErrorKind::InvalidDateValue),
-        "Invalid day (Esfand 30 common year)"
+        format!("{:?}", invalid),
+        "ParsiDate(1403-13-01 [Jalali, INVALID])"
     );
+    // Display is unchanged
+    assert_eq!(pd(1403, 5, 2).to_string(), "1403/05/02");
     assert_eq!(
-        ParsiDate::parse("1403/07/31", "%Y/%m/%d").unwrap_err(), // Day 31 invalid for Mehr (Month 7)
-        DateError::ParseError(ParseErrorKind::InvalidDateValue),
-        "Invalid day (Mehr 31)"
+        format!("{:?}", Some(pd(1403, 5, 2))),
+        "Some(ParsiDate(1403-05-02 [Jalali]))"
     );
+}
+
+#[test]
+fn test_format_year_padding() {
+    for (year, unpadded, padded) in [
+        (1, "1", "0001"),
+        (50, "50", "0050"),
+        (999, "999", "0999"),
+        (1403, "1403", "1403"),
+    ] {
+        let date = pd(year, 1, 1);
+        assert_eq!(date.format("%Y"), unpadded);
+        assert_eq!(date.format("%-Y"), unpadded);
+        assert_eq!(date.format("%0Y"), padded);
+    }
+    // Padded output sorts lexicographically and round-trips through `%Y` parsing.
+    let early = pd(50, 5, 2);
+    assert_eq!(early.format("%0Y/%m/%d"), "0050/05/02");
     assert_eq!(
-        ParsiDate::parse("1403/01/00", "%Y/%m/%d").unwrap_err(), // Day 0
-        DateError::ParseError(ParseErrorKind::InvalidDateValue),
-        "Invalid day value 0"
+        ParsiDate::parse(&early.format("%0Y/%m/%d"), "%Y/%m/%d"),
+        Ok(early)
     );
+    assert!(early.format("%0Y%m%d") < pd(999, 1, 1).format("%0Y%m%d"));
+    // Other characters after '0' and '-' stay literal
+    assert_eq!(early.format("%0m %-d"), "%0m %-d");
     assert_eq!(
-        ParsiDate::parse("0000/01/01", "%Y/%m/%d").unwrap_err(), // Year 0
-        DateError::ParseError(ParseErrorKind::InvalidDateValue), // Assuming ParsiDate::new(0, ..) fails
-        "Invalid year value 0"
+        crate::ParsiDateTime::new(50, 1, 1, 8, 0, 0)
+            .unwrap()
+            .format("%0Y %-Y %H"),
+        "0050 50 08"
     );
+}
 
-    // --- Invalid Month Name Errors (%B) ---
+#[test]
+fn test_display_alternate() {
+    let date = pd(1403, 5, 2);
+    assert_eq!(format!("{}", date), "1403/05/02");
+    assert_eq!(format!("{:#}", date), "سه‌شنبه، 2 مرداد 1403");
+    assert_eq!(format!("{:#}", date), date.format("full"));
+}
+
+#[test]
+fn test_display_width_and_alignment() {
+    let date = pd(1403, 5, 2);
+    assert_eq!(format!("{:>12}", date), "  1403/05/02");
+    assert_eq!(format!("{:12}|", date), "1403/05/02  |"); // Left-aligned by default, like strings
+    assert_eq!(format!("{:-^14}", date), "--1403/05/02--");
+    assert_eq!(format!("{:4}", date), "1403/05/02"); // Never truncated by width
+    assert_eq!(format!("{:.4}", date), "1403/05/02"); // ...nor by precision
+    assert_eq!(format!("{:>12.3}", date), "  1403/05/02");
+    assert_eq!(format!("{:#.2}", date), date.format("full"));
+    let width = 11;
+    assert_eq!(format!("{:>width$}", pd(50, 1, 1)), "   50/01/01");
+    // Alternate form: width counts characters, not bytes.
+    let full = date.format("full");
+    let padded = format!("{:>#30}", date);
+    assert_eq!(padded.chars().count(), 30);
+    assert_eq!(padded.trim_start(), full);
+    // Table rows line up.
+    let rows: Vec<String> = [pd(1403, 5, 2), pd(999, 1, 1)]
+        .iter()
+        .map(|d| format!("|{:>10}|", d))
+        .collect();
+    assert_eq!(rows, vec!["|1403/05/02|", "| 999/01/01|"]);
+}
+
+#[test]
+fn test_format_specifiers_used() {
     assert_eq!(
-        ParsiDate::parse("02 Mordad 1403", "%d %B %Y").unwrap_err(), // Non-Persian name
-        DateError::ParseError(ParseErrorKind::InvalidMonthName),
-        "Non-Persian month name"
+        ParsiDate::format_specifiers_used("%A، %d %B %Y (%K) %d"),
+        vec!['A', 'd', 'B', 'Y', 'K']
     );
     assert_eq!(
-        ParsiDate::parse("01 XXX 1400", "%d %B %Y").unwrap_err(), // Completely wrong name
-        DateError::ParseError(ParseErrorKind::InvalidMonthName),
-        "Unrecognized month name"
+        ParsiDate::format_specifiers_used("100%% %"),
+        Vec::<char>::new()
     );
     assert_eq!(
-        ParsiDate::parse("01 فروردین-1400", "%d %B %Y").unwrap_err(), // Expected space after name, got '-'
-        DateError::ParseError(ParseErrorKind::FormatMismatch), // Fails matching the literal space in format
-        "Wrong separator after month name"
+        ParsiDate::format_specifiers_used("'at %H' %?L ''%j"),
+        vec!['?', 'j']
     );
-
-    // --- Unsupported Specifier Error ---
     assert_eq!(
-        ParsiDate::parse("Some text", "%j").unwrap_err(), // %j not supported for parsing
-        DateError::ParseError(ParseErrorKind::UnsupportedSpecifier),
-        "Unsupported specifier %j for parse"
+        ParsiDate::format_specifiers_used("%Eg %Ee %q"),
+        vec!['E', 'q']
     );
+    assert!(ParsiDate::format_specifiers_used("").is_empty());
+}
+
+#[test]
+fn test_format_additional_named_styles() {
+    let date = pd(1403, 5, 2); // Tuesday
+    assert_eq!(date.format("medium"), "2 مرداد 1403");
+    assert_eq!(date.format("full"), "سه‌شنبه، 2 مرداد 1403");
+    assert_eq!(date.format("numeric-dotted"), "1403.05.02");
+    assert_eq!(date.format("compact"), "14030502");
+    assert_eq!(pd(1403, 12, 30).format("full"), "پنجشنبه، 30 اسفند 1403");
+    assert_eq!(pd(9, 1, 1).format("compact"), "00090101"); // Year padded to 4 digits
+    assert_eq!(date.format("ordinal"), "1403-126");
+    assert_eq!(pd(1403, 1, 9).format("ordinal"), "1403-009");
+    assert_eq!(pd(1403, 12, 30).format("ordinal"), "1403-366");
+    assert_eq!(date.format("ordinal"), date.format("%Y-%j"));
+
+    // Names tables and format_cow honor the named styles
+    let names = crate::ParsiNames {
+        weekdays: ["Sat", "Sun", "Mon", "Tue", "Wed", "Thu", "Fri"],
+        ..crate::ParsiNames::IRANIAN
+    };
+    assert_eq!(date.format_with_names("full", &names), "Tue، 2 مرداد 1403");
+    assert_eq!(date.format_cow("compact"), "14030502");
+
+    // Invalid dates show placeholders instead of panicking
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
     assert_eq!(
-        ParsiDate::parse("Some text", "%A").unwrap_err(), // %A not supported for parsing
-        DateError::ParseError(ParseErrorKind::UnsupportedSpecifier),
-        "Unsupported specifier %A for parse"
+        invalid.format("full"),
+        "?WeekdayError?، 1 ?InvalidMonth? 1403"
     );
+    assert_eq!(invalid.format("ordinal"), "1403-???");
 }
 
-// --- Date Info Tests ---
 #[test]
-fn test_weekday() {
-    // Gregorian: Wed 2024-03-20 -> Persian: Chaharshanbe 1403-01-01 -> Weekday 3
+fn test_format_named() {
+    let date = pd(1403, 5, 2);
     assert_eq!(
-        pd(1403, 1, 1).weekday(),
-        Ok("چهارشنبه".to_string()),
-        "1403-01-01 -> Wed"
+        date.format_named(
+            "{year}|{year_words}|{month}|{month_name}|{day}|{weekday}|{weekday_num}|{season}|{ordinal}|{week}"
+        ),
+        date.format("%Y|%EY|%m|%B|%d|%A|%w|%K|%j|%W")
     );
-    // Gregorian: Tue 2024-07-23 -> Persian: Seshanbe 1403-05-02 -> Weekday 2
+    // Escaped and unmatched braces, literal '%' and quotes
+    assert_eq!(date.format_named("{{{day}}}"), "{02}");
+    assert_eq!(date.format_named("{day"), "{day");
+    assert_eq!(date.format_named("day}"), "day}");
+    assert_eq!(date.format_named("{} {DAY}"), "{} {DAY}");
+    assert_eq!(date.format_named("%Y '{day}'"), "%Y '02'");
+    assert_eq!(date.format_named("روز {day}"), "روز 02");
+    assert_eq!(date.format_named(""), "");
+}
+
+// --- Parsing Tests ---
+#[test]
+fn test_parse_simple() {
+    // Basic YMD formats with different separators
     assert_eq!(
-        pd(1403, 5, 2).weekday(),
+        ParsiDate::parse("1403/05/02", "%Y/%m/%d"),
+        Ok(pd(1403, 5, 2))
+    );
+    assert_eq!(
+        ParsiDate::parse("1403-01-31", "%Y-%m-%d"),
+        Ok(pd(1403, 1, 31))
+    );
+    // Different order of components
+    assert_eq!(
+        ParsiDate::parse("07/04/1399", "%d/%m/%Y"),
+        Ok(pd(1399, 4, 7))
+    );
+    // Test parsing epoch start and max supported date
+    assert_eq!(ParsiDate::parse("0001/01/01", "%Y/%m/%d"), Ok(pd(1, 1, 1)));
+    assert_eq!(
+        ParsiDate::parse("9999/12/29", "%Y/%m/%d"),
+        Ok(pd(9999, 12, 29)),
+        "Max date (9999 is common)"
+    );
+}
+
+#[test]
+fn test_parse_trimmed() {
+    let expected = pd(1403, 5, 2);
+    assert_eq!(
+        ParsiDate::parse_trimmed("1403/05/02", "%Y/%m/%d"),
+        Ok(expected)
+    );
+    assert_eq!(
+        ParsiDate::parse_trimmed("  1403/05/02\n", "%Y/%m/%d"),
+        Ok(expected)
+    );
+    // Unicode whitespace: no-break space and ideographic space
+    assert_eq!(
+        ParsiDate::parse_trimmed("\u{a0}1403/05/02\u{3000}", "%Y/%m/%d"),
+        Ok(expected)
+    );
+    assert_eq!(
+        ParsiDate::parse_trimmed(" 02 مرداد 1403 ", "%d %B %Y"),
+        Ok(expected)
+    );
+    // Inner whitespace still has to match the format.
+    assert_eq!(
+        ParsiDate::parse_trimmed(" 1403 /05/02 ", "%Y/%m/%d"),
+        Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    );
+    assert_eq!(
+        ParsiDate::parse_trimmed("   ", "%Y/%m/%d"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+}
+
+#[test]
+fn test_parse_column() {
+    let rows = ["1403/05/02", "", "1404/12/30", "1403/01/31"];
+    let results = ParsiDate::parse_column(&rows, "%Y/%m/%d");
+    assert_eq!(
+        results,
+        vec![
+            Ok(pd(1403, 5, 2)),
+            Err(DateError::ParseError(ParseErrorKind::InvalidNumber)),
+            Err(DateError::ParseError(ParseErrorKind::InvalidDateValue)),
+            Ok(pd(1403, 1, 31)),
+        ]
+    );
+    assert!(ParsiDate::parse_column(&[], "%Y/%m/%d").is_empty());
+
+    assert_eq!(
+        ParsiDate::parse_column_strict(&rows, "%Y/%m/%d"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+    assert_eq!(
+        ParsiDate::parse_column_strict(&["1403/05/02", "1403/01/31"], "%Y/%m/%d"),
+        Ok(vec![pd(1403, 5, 2), pd(1403, 1, 31)])
+    );
+    assert_eq!(ParsiDate::parse_column_strict(&[], "%Y/%m/%d"), Ok(vec![]));
+}
+
+#[test]
+fn test_parse_non_padded_date() {
+    let fmt = "%Y/%-m/%-d";
+    assert_eq!(ParsiDate::parse("1403/5/2", fmt), Ok(pd(1403, 5, 2)));
+    assert_eq!(ParsiDate::parse("1403/05/02", fmt), Ok(pd(1403, 5, 2)));
+    assert_eq!(ParsiDate::parse("1403/12/30", fmt), Ok(pd(1403, 12, 30)));
+    assert_eq!(
+        ParsiDate::parse("2/5/1403", "%-d/%-m/%Y"),
+        Ok(pd(1403, 5, 2))
+    );
+    assert_eq!(ParsiDate::parse("50/1/1", "%-Y/%-m/%-d"), Ok(pd(50, 1, 1)));
+
+    // Validation still applies and at most two digits are consumed
+    assert_eq!(
+        ParsiDate::parse("1403/7/31", fmt),
+        Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    );
+    assert_eq!(
+        ParsiDate::parse("1403/5/123", fmt),
+        Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    );
+    assert_eq!(
+        ParsiDate::parse("1403//2", fmt),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+    assert_eq!(
+        ParsiDate::parse_located("1403/x/2", fmt).map_err(|e| e.position),
+        Err(5)
+    );
+    assert_eq!(
+        ParsiDate::parse("1403/5/2", "%Y/%-B/%-d"),
+        Err(DateError::ParseError(ParseErrorKind::UnsupportedSpecifier))
+    );
+
+    // The strict specifiers keep requiring two digits
+    assert_eq!(
+        ParsiDate::parse("1403/5/2", "%Y/%m/%d"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+}
+
+#[test]
+fn test_parse_fixed_width_year() {
+    use crate::LocatedParseError;
+    let expected = pd(1403, 5, 2);
+    // Adjacent numeric fields: the year never swallows the month's digits.
+    assert_eq!(ParsiDate::parse("14030502", "%Y%m%d"), Ok(expected));
+    assert_eq!(ParsiDate::parse("14030502", "%4Y%m%d"), Ok(expected));
+    assert_eq!(ParsiDate::parse("1403/05/02", "%4Y/%m/%d"), Ok(expected));
+    assert_eq!(
+        ParsiDate::parse("0050/01/01", "%4Y/%m/%d"),
+        Ok(pd(50, 1, 1))
+    );
+    assert_eq!(
+        ParsiDate::parse("1403052", "%Y%m%d"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+    // A fifth year digit is rejected rather than read as part of the year.
+    for fmt in ["%Y/%m/%d", "%4Y/%m/%d"] {
+        assert_eq!(
+            ParsiDate::parse("14030/05/02", fmt),
+            Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+        );
+        assert_eq!(
+            ParsiDate::parse_located("14030/05/02", fmt),
+            Err(LocatedParseError {
+                kind: ParseErrorKind::FormatMismatch,
+                position: 4
+            })
+        );
+    }
+    assert_eq!(
+        ParsiDate::parse("140/05/02", "%4Y/%m/%d"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+    // The same rules apply to ParsiDateTime.
+    assert_eq!(
+        crate::ParsiDateTime::parse("14030502 123000", "%4Y%m%d %H%M%S"),
+        crate::ParsiDateTime::new(1403, 5, 2, 12, 30, 0)
+    );
+    assert_eq!(
+        crate::ParsiDateTime::parse("14030/05/02 12:30:00", "%4Y/%m/%d %T"),
+        Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    );
+    // Formatting: %4Y pads like %0Y.
+    assert_eq!(pd(50, 1, 1).format("%4Y/%m/%d"), "0050/01/01");
+    assert_eq!(expected.format("%4Y%m%d"), "14030502");
+}
+
+#[test]
+fn test_from_str() {
+    let expected = pd(1403, 5, 2);
+    assert_eq!("1403/05/02".parse::<ParsiDate>(), Ok(expected));
+    assert_eq!("1403-05-02".parse::<ParsiDate>(), Ok(expected));
+    // Display output round-trips, including short years.
+    for date in [
+        pd(1, 1, 1),
+        pd(50, 12, 29),
+        pd(999, 7, 30),
+        pd(1403, 12, 30),
+    ] {
+        assert_eq!(date.to_string().parse::<ParsiDate>(), Ok(date));
+    }
+    assert_eq!(MAX_PARSI_DATE.to_string().parse(), Ok(MAX_PARSI_DATE));
+    // Errors come from parse().
+    assert_eq!(
+        "1403/5/02".parse::<ParsiDate>(),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+    assert_eq!(
+        "1403.05.02".parse::<ParsiDate>(),
+        Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    );
+    assert_eq!(
+        "1403-05/02".parse::<ParsiDate>(),
+        Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    );
+    assert_eq!(
+        "1403/05/02 ".parse::<ParsiDate>(),
+        Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    );
+    assert!("".parse::<ParsiDate>().is_err());
+    assert_eq!(
+        "0/01/01".parse::<ParsiDate>(),
+        Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    );
+}
+
+#[test]
+fn test_parse_localized() {
+    let expected = pd(1403, 5, 2);
+    assert_eq!(
+        ParsiDate::parse_localized("۱۴۰۳/۰۵/۰۲", "%Y/%m/%d"),
+        Ok(expected)
+    );
+    assert_eq!(
+        ParsiDate::parse_localized("١٤٠٣-٠٥-٠٢", "%Y-%m-%d"),
+        Ok(expected)
+    );
+    // Mixed digit sets and plain ASCII input
+    assert_eq!(
+        ParsiDate::parse_localized("14۰3/0۵/02", "%Y/%m/%d"),
+        Ok(expected)
+    );
+    assert_eq!(
+        ParsiDate::parse_localized("1403/05/02", "%Y/%m/%d"),
+        Ok(expected)
+    );
+    assert_eq!(
+        ParsiDate::parse_localized("۲ مرداد ۱۴۰۳", "%-d %B %Y"),
+        ParsiDate::parse("2 مرداد 1403", "%-d %B %Y")
+    );
+    // Width rules still apply after normalization.
+    assert_eq!(
+        ParsiDate::parse_localized("۱۴۰۳/۵/۰۲", "%Y/%m/%d"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+    assert_eq!(
+        ParsiDate::parse_localized("۱۴۰۴/۱۲/۳۰", "%Y/%m/%d"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    );
+    // The strict parser is unchanged.
+    assert_eq!(
+        ParsiDate::parse("۱۴۰۳/۰۵/۰۲", "%Y/%m/%d"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+    assert_eq!(
+        crate::ParsiDateTime::parse_localized("۱۴۰۳/۰۵/۰۲ ۰۹:۰۵:۰۰", "%Y/%m/%d %T"),
+        crate::ParsiDateTime::new(1403, 5, 2, 9, 5, 0)
+    );
+}
+
+#[test]
+fn test_parse_and_verify_gregorian() {
+    assert_eq!(
+        ParsiDate::parse_and_verify_gregorian("1403/12/30", "%Y/%m/%d", "20/03/2025", "%d/%m/%Y"),
+        Ok(pd(1403, 12, 30))
+    );
+    assert_eq!(
+        ParsiDate::parse_and_verify_gregorian("02 مرداد 1403", "%d %B %Y", "2024-07-23", "%F"),
+        Ok(pd(1403, 5, 2))
+    );
+    assert_eq!(
+        ParsiDate::parse_and_verify_gregorian("1404/01/01", "%Y/%m/%d", "2025-03-20", "%Y-%m-%d"),
+        Err(DateError::ConsistencyMismatch)
+    );
+    // Persian parse errors take precedence and are reported as-is.
+    assert_eq!(
+        ParsiDate::parse_and_verify_gregorian("1403/13/01", "%Y/%m/%d", "oops", "%Y-%m-%d"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    );
+    assert_eq!(
+        ParsiDate::parse_and_verify_gregorian("1403/05/02", "%Y/%m/%d", "2024-02-30", "%Y-%m-%d"),
+        Err(DateError::GregorianConversionError)
+    );
+    assert!(DateError::ConsistencyMismatch
+        .to_string()
+        .contains("same day"));
+}
+
+#[test]
+fn test_parse_month_name() {
+    // %d requires padded day (2 digits)
+    assert_eq!(
+        ParsiDate::parse("02 مرداد 1403", "%d %B %Y"),
+        Ok(pd(1403, 5, 2))
+    );
+    // End of leap year with month name
+    assert_eq!(
+        ParsiDate::parse("30 اسفند 1399", "%d %B %Y"),
+        Ok(pd(1399, 12, 30)), // 1399 is leap
+        "End of leap year with %B"
+    );
+    // End of common year with month name
+    assert_eq!(
+        ParsiDate::parse("29 اسفند 1404", "%d %B %Y"),
+        Ok(pd(1404, 12, 29)), // 1404 is common
+        "End of common year with %B"
+    );
+    // Test with exact single spaces as required by the current parser implementation
+    assert_eq!(
+        ParsiDate::parse("10 دی 1400", "%d %B %Y"),
+        Ok(pd(1400, 10, 10))
+    );
+    // Test month name at different positions in format string
+    assert_eq!(
+        ParsiDate::parse("1400-دی-10", "%Y-%B-%d"),
+        Ok(pd(1400, 10, 10))
+    );
+    assert_eq!(
+        ParsiDate::parse("فروردین-01-1390", "%B-%d-%Y"),
+        Ok(pd(1390, 1, 1))
+    );
+    // Test month name followed immediately by year
+    assert_eq!(
+        ParsiDate::parse("01اردیبهشت1395", "%d%B%Y"),
+        Ok(pd(1395, 2, 1))
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    // --- Invalid Number Errors ---
+    // %m and %d require exactly two digits
+    assert_eq!(
+        ParsiDate::parse("1403/5/02", "%Y/%m/%d").unwrap_err(),
+        DateError::ParseError(ParseErrorKind::InvalidNumber),
+        "Single digit month for %m"
+    );
+    assert_eq!(
+        ParsiDate::parse("1403/05/2", "%Y/%m/%d").unwrap_err(),
+        DateError::ParseError(ParseErrorKind::InvalidNumber),
+        "Single digit day for %d"
+    );
+    // %Y requires exactly four digits
+    assert_eq!(
+        ParsiDate::parse("403/01/01", "%Y/%m/%d").unwrap_err(),
+        DateError::ParseError(ParseErrorKind::InvalidNumber),
+        "Three digit year for %Y"
+    );
+    // Non-digit characters where digits are expected
+    assert_eq!(
+        ParsiDate::parse("1403/XX/01", "%Y/%m/%d").unwrap_err(),
+        DateError::ParseError(ParseErrorKind::InvalidNumber),
+        "Non-digit month"
+    );
+    assert_eq!(
+        ParsiDate::parse("ABCD/01/01", "%Y/%m/%d").unwrap_err(),
+        DateError::ParseError(ParseErrorKind::InvalidNumber),
+        "Non-digit year"
+    );
+
+    // --- Format Mismatch Errors ---
+    // Missing separators
+    assert_eq!(
+        ParsiDate::parse("14030502", "%Y/%m/%d").unwrap_err(),
+        DateError::ParseError(ParseErrorKind::FormatMismatch), // Expected '/', got '0'
+        "Missing separators"
+    );
+    // Wrong separator
+    assert_eq!(
+        ParsiDate::parse("1403 05 02", "%Y/%m/%d").unwrap_err(),
+        DateError::ParseError(ParseErrorKind::FormatMismatch), // Expected '/', got ' '
+        "Wrong separator (space vs /)"
+    );
+    // Trailing text not in format
+    assert_eq!(
+        ParsiDate::parse("1403/01/01extra", "%Y/%m/%d").unwrap_err(),
+        DateError::ParseError(ParseErrorKind::FormatMismatch),
+        "Trailing text"
+    );
+    // Incomplete input for format
+    assert_eq!(
+        ParsiDate::parse("1403/05", "%Y/%m/%d").unwrap_err(), // Input ends before matching %d
+        DateError::ParseError(ParseErrorKind::FormatMismatch),
+        "Incomplete input"
+    );
+    // Mismatch with literal format chars
+    assert_eq!(
+        ParsiDate::parse("Year: 1403", "Date: %Y").unwrap_err(),
+        DateError::ParseError(ParseErrorKind::FormatMismatch),
+        "Literal prefix mismatch"
+    );
+
+    // --- Invalid Date Value Errors (parsed components are invalid logically) ---
+    assert_eq!(
+        ParsiDate::parse("1403/13/01", "%Y/%m/%d").unwrap_err(), // Month > 12
+        DateError::ParseError(ParseErrorKind::InvalidDateValue),
+        "Invalid month value > 12"
+    );
+    assert_eq!(
+        ParsiDate::parse("1403/00/01", "%Y/%m/%d").unwrap_err(), // Month 0
+        DateError::ParseError(ParseErrorKind::InvalidDateValue),
+        "Invalid month value 0"
+    );
+    assert_eq!(
+        ParsiDate::parse("1404/12/30", "%Y/%m/%d").unwrap_err(), // Day 30 invalid for Esfand in common year 1404
+        DateError::ParseError(ParseErrorKind::InvalidDateValue),
+        "Invalid day (Esfand 30 common year)"
+    );
+    assert_eq!(
+        ParsiDate::parse("1403/07/31", "%Y/%m/%d").unwrap_err(), // Day 31 invalid for Mehr (Month 7)
+        DateError::ParseError(ParseErrorKind::InvalidDateValue),
+        "Invalid day (Mehr 31)"
+    );
+    assert_eq!(
+        ParsiDate::parse("1403/01/00", "%Y/%m/%d").unwrap_err(), // Day 0
+        DateError::ParseError(ParseErrorKind::InvalidDateValue),
+        "Invalid day value 0"
+    );
+    assert_eq!(
+        ParsiDate::parse("0000/01/01", "%Y/%m/%d").unwrap_err(), // Year 0
+        DateError::ParseError(ParseErrorKind::InvalidDateValue), // Assuming ParsiDate::new(0, ..) fails
+        "Invalid year value 0"
+    );
+
+    // --- Invalid Month Name Errors (%B) ---
+    assert_eq!(
+        ParsiDate::parse("02 Mordad 1403", "%d %B %Y").unwrap_err(), // Non-Persian name
+        DateError::ParseError(ParseErrorKind::InvalidMonthName),
+        "Non-Persian month name"
+    );
+    assert_eq!(
+        ParsiDate::parse("01 XXX 1400", "%d %B %Y").unwrap_err(), // Completely wrong name
+        DateError::ParseError(ParseErrorKind::InvalidMonthName),
+        "Unrecognized month name"
+    );
+    assert_eq!(
+        ParsiDate::parse("01 فروردین-1400", "%d %B %Y").unwrap_err(), // Expected space after name, got '-'
+        DateError::ParseError(ParseErrorKind::FormatMismatch), // Fails matching the literal space in format
+        "Wrong separator after month name"
+    );
+
+    // --- Unsupported Specifier Error ---
+    assert_eq!(
+        ParsiDate::parse("Some text", "%j").unwrap_err(), // %j not supported for parsing
+        DateError::ParseError(ParseErrorKind::UnsupportedSpecifier),
+        "Unsupported specifier %j for parse"
+    );
+    assert_eq!(
+        ParsiDate::parse("Some text", "%A").unwrap_err(), // %A not supported for parsing
+        DateError::ParseError(ParseErrorKind::UnsupportedSpecifier),
+        "Unsupported specifier %A for parse"
+    );
+}
+
+#[test]
+fn test_parse_relative() {
+    let today = ParsiDate::today().expect("today() should succeed");
+    for kw in ["امروز", "today", "TODAY", "  Today\n"] {
+        assert_eq!(ParsiDate::parse_relative(kw), Ok(today), "keyword {:?}", kw);
+    }
+    for kw in ["دیروز", "yesterday"] {
+        assert_eq!(ParsiDate::parse_relative(kw), today.add_days(-1));
+    }
+    for kw in ["فردا", "Tomorrow"] {
+        assert_eq!(ParsiDate::parse_relative(kw), today.add_days(1));
+    }
+
+    assert_eq!(
+        ParsiDate::parse_relative("1399/12/30"),
+        Ok(pd(1399, 12, 30))
+    );
+    assert_eq!(
+        ParsiDate::parse_relative("1399-12-30"),
+        Ok(pd(1399, 12, 30))
+    );
+    assert_eq!(
+        ParsiDate::parse_relative("30 اسفند 1399"),
+        Ok(pd(1399, 12, 30))
+    );
+    assert_eq!(
+        ParsiDate::parse_relative("1404/12/30"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    );
+    assert_eq!(
+        ParsiDate::parse_relative("پس‌فردا"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+    assert_eq!(
+        ParsiDate::parse_relative("1403.05.02"),
+        Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    );
+}
+
+#[test]
+fn test_parse_month_name_bounded_longest_match() {
+    // Longest-prefix matching with names where one is a prefix of another
+    let names = ["دی", "دیماه", "بهمن"];
+    assert_eq!(
+        ParsiDate::match_name_prefix("دیماه 1403".as_bytes(), &names),
+        Some((1, "دیماه".len()))
+    );
+    assert_eq!(
+        ParsiDate::match_name_prefix("دی 1403".as_bytes(), &names),
+        Some((0, "دی".len()))
+    );
+    assert_eq!(ParsiDate::match_name_prefix("آذر".as_bytes(), &names), None);
+    assert_eq!(ParsiDate::match_name_prefix(b"", &names), None);
+
+    // Pathological input: a huge string that almost matches a month name
+    let mut huge = String::from("02 مردا");
+    huge.push_str(&"ا".repeat(1_000_000));
+    assert_eq!(
+        ParsiDate::parse(&huge, "%d %B %Y"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidMonthName))
+    );
+    // A long tail after a valid month name is left for the following specifiers
+    let mut bytes = "02 مرداد ".as_bytes().to_vec();
+    bytes.extend_from_slice(&[b'x'; 100_000]);
+    let long_tail = String::from_utf8(bytes).unwrap();
+    assert_eq!(
+        ParsiDate::parse(&long_tail, "%d %B %Y"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+}
+
+#[test]
+fn test_parse_auto_calendar() {
+    use crate::DetectedCalendar;
+    assert_eq!(
+        ParsiDate::parse_auto_calendar_typed("1399/12/30"),
+        Ok((pd(1399, 12, 30), DetectedCalendar::Persian))
+    );
+    assert_eq!(
+        ParsiDate::parse_auto_calendar_typed(" 2021-03-20 "),
+        Ok((pd(1399, 12, 30), DetectedCalendar::Gregorian))
+    );
+    // Threshold boundary
+    assert_eq!(
+        ParsiDate::parse_auto_calendar_typed("1699/01/01"),
+        Ok((pd(1699, 1, 1), DetectedCalendar::Persian))
+    );
+    assert_eq!(
+        ParsiDate::parse_auto_calendar_typed("1700/01/01").map(|(_, c)| c),
+        Ok(DetectedCalendar::Gregorian)
+    );
+    assert_eq!(
+        ParsiDate::parse_auto_calendar("2024-07-23"),
+        Ok(pd(1403, 5, 2))
+    );
+
+    // Errors
+    assert_eq!(
+        ParsiDate::parse_auto_calendar("1404/12/30"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    );
+    assert_eq!(
+        ParsiDate::parse_auto_calendar("2023/02/29"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    );
+    assert_eq!(
+        ParsiDate::parse_auto_calendar("1403/05-02"),
+        Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    );
+    assert_eq!(
+        ParsiDate::parse_auto_calendar("1403/5/2"),
+        Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    );
+    assert_eq!(
+        ParsiDate::parse_auto_calendar("14x3/05/02"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+}
+
+#[test]
+fn test_parse_partial_report() {
+    let err = |k| Some(DateError::ParseError(k));
+    assert_eq!(
+        ParsiDate::parse_partial_report("1403/05/02", "%Y/%m/%d"),
+        (Some(1403), Some(5), Some(2), None)
+    );
+    assert_eq!(
+        ParsiDate::parse_partial_report("14o3/05/02", "%Y/%m/%d"),
+        (None, None, None, err(ParseErrorKind::InvalidNumber))
+    );
+    assert_eq!(
+        ParsiDate::parse_partial_report("1403-05/02", "%Y/%m/%d"),
+        (Some(1403), None, None, err(ParseErrorKind::FormatMismatch))
+    );
+    assert_eq!(
+        ParsiDate::parse_partial_report("02 Mordad 1403", "%d %B %Y"),
+        (None, None, Some(2), err(ParseErrorKind::InvalidMonthName))
+    );
+    assert_eq!(
+        ParsiDate::parse_partial_report("1403/07/31", "%Y/%m/%d"),
+        (
+            Some(1403),
+            Some(7),
+            Some(31),
+            err(ParseErrorKind::InvalidDateValue)
+        )
+    );
+    assert_eq!(
+        ParsiDate::parse_partial_report("1403/05/02 extra", "%Y/%m/%d"),
+        (
+            Some(1403),
+            Some(5),
+            Some(2),
+            err(ParseErrorKind::FormatMismatch)
+        )
+    );
+    // A missing component is reported like in `parse`
+    assert_eq!(
+        ParsiDate::parse_partial_report("1403/05", "%Y/%m"),
+        (
+            Some(1403),
+            Some(5),
+            None,
+            err(ParseErrorKind::FormatMismatch)
+        )
+    );
+}
+
+#[test]
+fn test_parse_located() {
+    use crate::LocatedParseError;
+    let at = |kind, position| Err(LocatedParseError { kind, position });
+    assert_eq!(
+        ParsiDate::parse_located("1403/05/02", "%Y/%m/%d"),
+        Ok(pd(1403, 5, 2))
+    );
+    assert_eq!(
+        ParsiDate::parse_located("14o3/05/02", "%Y/%m/%d"),
+        at(ParseErrorKind::InvalidNumber, 0)
+    );
+    assert_eq!(
+        ParsiDate::parse_located("1403/5/02", "%Y/%m/%d"),
+        at(ParseErrorKind::InvalidNumber, 5)
+    );
+    assert_eq!(
+        ParsiDate::parse_located("02 Mordad 1403", "%d %B %Y"),
+        at(ParseErrorKind::InvalidMonthName, 3)
+    );
+    assert_eq!(
+        ParsiDate::parse_located("1403/05/02 extra", "%Y/%m/%d"),
+        at(ParseErrorKind::FormatMismatch, 10)
+    );
+    assert_eq!(
+        ParsiDate::parse_located("1403/05", "%Y/%m"),
+        at(ParseErrorKind::FormatMismatch, 7)
+    );
+    assert_eq!(
+        ParsiDate::parse_located("1403/07/31", "%Y/%m/%d"),
+        at(ParseErrorKind::InvalidDateValue, 0)
+    );
+    assert_eq!(
+        ParsiDate::parse_located("1403 05", "%Y %A"),
+        at(ParseErrorKind::UnsupportedSpecifier, 5)
+    );
+
+    // The kind always agrees with `parse`
+    for (input, format) in [
+        ("1403/05/x2", "%Y/%m/%d"),
+        ("1403-05-02", "%Y/%m/%d"),
+        ("1404/12/30", "%Y/%m/%d"),
+        ("02 مرداد 14x3", "%d %B %Y"),
+    ] {
+        let located = ParsiDate::parse_located(input, format).unwrap_err();
+        assert_eq!(ParsiDate::parse(input, format), Err(located.into()));
+    }
+}
+
+#[test]
+fn test_parse_into_partial_updates() {
+    let mut date = pd(1403, 5, 2);
+    date.parse_into("11", "%d").unwrap();
+    assert_eq!(date, pd(1403, 5, 11));
+    date.parse_into("آبان", "%B").unwrap();
+    assert_eq!(date, pd(1403, 8, 11));
+    date.parse_into("1404", "%Y").unwrap();
+    assert_eq!(date, pd(1404, 8, 11));
+    date.parse_into("1402/01/01", "%Y/%m/%d").unwrap();
+    assert_eq!(date, pd(1402, 1, 1));
+    // An empty format changes nothing
+    date.parse_into("", "").unwrap();
+    assert_eq!(date, pd(1402, 1, 1));
+
+    // Failures leave the date unchanged
+    let mut leap_day = pd(1403, 12, 30);
+    assert_eq!(
+        leap_day.parse_into("1404", "%Y"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    );
+    assert_eq!(
+        leap_day.parse_into("1x", "%m"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidNumber))
+    );
+    assert_eq!(
+        leap_day.parse_into("01 extra", "%d"),
+        Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    );
+    assert_eq!(leap_day, pd(1403, 12, 30));
+}
+
+#[test]
+fn test_parse_and_format_era_suffixes() {
+    // Multi-byte literals in the format are matched exactly
+    assert_eq!(
+        ParsiDate::parse("1403/05/02 ه.ش", "%Y/%m/%d ه.ش"),
+        Ok(pd(1403, 5, 2))
+    );
+    assert_eq!(
+        ParsiDate::parse("02 مرداد 1403 ه.ش", "%d %B %Y ه.ش"),
+        Ok(pd(1403, 5, 2))
+    );
+    assert_eq!(
+        ParsiDate::parse("1403/05/02 ه.ق", "%Y/%m/%d ه.ش"),
+        Err(DateError::ParseError(ParseErrorKind::FormatMismatch))
+    );
+
+    // %Ee formats and parses the era marker
+    let date = pd(1403, 5, 2);
+    assert_eq!(date.format("%Y/%m/%d %Ee"), "1403/05/02 ه.ش");
+    assert_eq!(ParsiDate::parse("1403/05/02 ه.ش", "%Y/%m/%d %Ee"), Ok(date));
+    assert_eq!(
+        ParsiDate::parse_located("1403/05/02 ه.ق", "%Y/%m/%d %Ee"),
+        Err(crate::LocatedParseError {
+            kind: ParseErrorKind::FormatMismatch,
+            position: 11
+        })
+    );
+    let dt = crate::ParsiDateTime::new(1403, 5, 2, 8, 30, 0).unwrap();
+    assert_eq!(dt.format("%Y/%m/%d %Ee %H:%M"), "1403/05/02 ه.ش 08:30");
+    assert_eq!(
+        crate::ParsiDateTime::parse("1403/05/02 ه.ش 08:30:00", "%Y/%m/%d %Ee %T"),
+        Ok(dt)
+    );
+}
+
+#[test]
+fn test_parse_day_first_and_detect_field_order() {
+    assert_eq!(
+        ParsiDate::parse("02/05/1403", "%d/%m/%Y"),
+        Ok(pd(1403, 5, 2))
+    );
+    assert_eq!(
+        ParsiDate::parse("30/12/1403", "%d/%m/%Y"),
+        Ok(pd(1403, 12, 30))
+    );
+    assert_eq!(
+        ParsiDate::parse("30/12/1404", "%d/%m/%Y"),
+        Err(DateError::ParseError(ParseErrorKind::InvalidDateValue))
+    );
+
+    assert_eq!(
+        ParsiDate::detect_field_order(&["1403/05/02", "1402/12/29"]),
+        Some("%Y/%m/%d")
+    );
+    assert_eq!(
+        ParsiDate::detect_field_order(&[" 02-05-1403 ", "01/01/01", ""]),
+        Some("%d-%m-%Y")
+    );
+    // Mixed separators, conflicting orders, and no conclusive samples
+    assert_eq!(
+        ParsiDate::detect_field_order(&["1403/05/02", "1403-05-02"]),
+        None
+    );
+    assert_eq!(
+        ParsiDate::detect_field_order(&["02/05/1403", "1403/05/02"]),
+        None
+    );
+    assert_eq!(ParsiDate::detect_field_order(&[]), None);
+    assert_eq!(
+        ParsiDate::detect_field_order(&["12/11/10", "1403/05", "x/y/z"]),
+        None
+    );
+    // Every detected format parses its samples
+    let samples = ["1403-01-31", "1399-12-30"];
+    let format = ParsiDate::detect_field_order(&samples).unwrap();
+    assert!(samples.iter().all(|s| ParsiDate::parse(s, format).is_ok()));
+}
+
+// --- Date Info Tests ---
+#[test]
+fn test_weekday() {
+    // Gregorian: Wed 2024-03-20 -> Persian: Chaharshanbe 1403-01-01 -> Weekday 3
+    assert_eq!(
+        pd(1403, 1, 1).weekday(),
+        Ok("چهارشنبه".to_string()),
+        "1403-01-01 -> Wed"
+    );
+    // Gregorian: Tue 2024-07-23 -> Persian: Seshanbe 1403-05-02 -> Weekday 2
+    assert_eq!(
+        pd(1403, 5, 2).weekday(),
         Ok("سه‌شنبه".to_string()),
         "1403-05-02 -> Tue"
     );
@@ -1127,6 +3021,661 @@ fn test_ordinal() {
     assert_eq!(invalid_date.ordinal(), Err(DateError::InvalidDate)); // Fails validation
 }
 
+#[test]
+fn test_year_progress() {
+    assert_eq!(pd(1403, 12, 30).year_progress(), Ok(1.0));
+    assert_eq!(pd(1404, 12, 29).year_progress(), Ok(1.0));
+    assert_eq!(pd(1403, 1, 1).year_progress(), Ok(1.0 / 366.0));
+    assert_eq!(pd(1404, 1, 1).year_progress(), Ok(1.0 / 365.0));
+    assert_eq!(pd(1403, 12, 29).year_progress(), Ok(365.0 / 366.0));
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(invalid.year_progress(), Err(DateError::InvalidDate));
+}
+
+#[test]
+fn test_days_remaining_and_elapsed_in_year() {
+    // Leap (1403) and common (1404) years
+    for (year, len) in [(1403, 366), (1404, 365)] {
+        let last_day = if len == 366 { 30 } else { 29 };
+        assert_eq!(pd(year, 1, 1).days_elapsed_in_year(), Ok(0));
+        assert_eq!(pd(year, 1, 1).days_remaining_in_year(), Ok(len - 1));
+        assert_eq!(pd(year, 12, last_day).days_remaining_in_year(), Ok(0));
+        assert_eq!(pd(year, 12, last_day).days_elapsed_in_year(), Ok(len - 1));
+        for date in [pd(year, 5, 2), pd(year, 7, 1), pd(year, 12, 1)] {
+            let elapsed = date.days_elapsed_in_year().unwrap();
+            let remaining = date.days_remaining_in_year().unwrap();
+            assert_eq!(elapsed + 1 + remaining, len);
+        }
+    }
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(
+        invalid.days_remaining_in_year(),
+        Err(DateError::InvalidDate)
+    );
+    assert_eq!(invalid.days_elapsed_in_year(), Err(DateError::InvalidDate));
+}
+
+#[test]
+fn test_weekday_enum() {
+    use crate::ParsiWeekday;
+
+    // 1403/01/04 (2024-03-23) is a Saturday; walk one full week from there.
+    let saturday = pd(1403, 1, 4);
+    let mut expected = ParsiWeekday::Shanbeh;
+    for date in saturday.iter_until(saturday.add_days(7).unwrap()) {
+        let day = date.weekday_enum().unwrap();
+        assert_eq!(day, expected);
+        assert_eq!(Ok(day.name_persian().to_string()), date.weekday());
+        assert_eq!(Ok(day.num_sat_0()), date.weekday_num_sat_0());
+        assert_eq!(day.name_finglish(), date.format("%a"));
+        expected = expected.succ();
+    }
+    assert_eq!(pd(1403, 5, 2).weekday_enum(), Ok(ParsiWeekday::Seshanbeh));
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(invalid.weekday_enum(), Err(DateError::InvalidDate));
+}
+
+#[test]
+fn test_is_weekend() {
+    use crate::ParsiWeekday;
+    // 1403/01/04 is a Saturday; check a whole week
+    let start = pd(1403, 1, 4);
+    let weekends: Vec<_> = start
+        .iter_until(pd(1403, 1, 11))
+        .map(|d| d.is_weekend().unwrap())
+        .collect();
+    assert_eq!(weekends, [false, false, false, false, false, false, true]);
+
+    let thursday_and_friday = [ParsiWeekday::Panjshanbeh, ParsiWeekday::Jomeh];
+    let count = start
+        .iter_until(pd(1403, 1, 11))
+        .filter(|d| d.is_weekend_with(&thursday_and_friday).unwrap())
+        .count();
+    assert_eq!(count, 2);
+    assert_eq!(pd(1403, 1, 10).is_weekend_with(&[]), Ok(false));
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(invalid.is_weekend(), Err(DateError::InvalidDate));
+    assert_eq!(
+        invalid.is_weekend_with(&thursday_and_friday),
+        Err(DateError::InvalidDate)
+    );
+}
+
+#[test]
+fn test_business_days() {
+    // Brute-force reference: step one day at a time
+    fn is_business(date: ParsiDate, holidays: &[ParsiDate]) -> bool {
+        !date.is_weekend().unwrap() && !holidays.contains(&date)
+    }
+    fn naive_add(mut date: ParsiDate, n: i64, holidays: &[ParsiDate]) -> ParsiDate {
+        let mut remaining = n.abs();
+        while remaining > 0 {
+            date = date.add_days(n.signum()).unwrap();
+            if is_business(date, holidays) {
+                remaining -= 1;
+            }
+        }
+        date
+    }
+
+    // Unsorted, with a duplicate and a Friday (1404/01/01), across the year boundary
+    let holidays = [
+        pd(1404, 1, 2),
+        pd(1403, 12, 30),
+        pd(1404, 1, 1),
+        pd(1404, 1, 13),
+        pd(1404, 1, 2),
+        pd(1403, 11, 22),
+    ];
+    for start in [
+        pd(1403, 11, 20),
+        pd(1403, 12, 29),
+        pd(1404, 1, 1),
+        pd(1404, 1, 20),
+    ] {
+        for n in -40..=40 {
+            let expected = naive_add(start, n, &holidays);
+            assert_eq!(
+                start.add_business_days(n, &holidays),
+                Ok(expected),
+                "{start} + {n}"
+            );
+            assert_eq!(
+                start.add_business_days(n, &[]),
+                Ok(naive_add(start, n, &[]))
+            );
+            assert_eq!(
+                start.business_days_between(&expected, &holidays),
+                Ok(n),
+                "{start} .. {expected}"
+            );
+        }
+    }
+    assert_eq!(
+        pd(1404, 1, 1).add_business_days(0, &holidays),
+        Ok(pd(1404, 1, 1))
+    );
+
+    // Large counts match the weekly pattern: 6 business days per week without holidays
+    let saturday = pd(1403, 5, 6);
+    assert_eq!(
+        saturday.add_business_days(6 * 1000, &[]),
+        saturday.add_days(7 * 1000) // Sunday to the next Saturday is six business days
+    );
+    assert_eq!(
+        saturday.business_days_between(&saturday.add_days(7 * 1000).unwrap(), &[]),
+        Ok(6 * 1000)
+    );
+
+    // Errors
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(
+        invalid.add_business_days(1, &[]),
+        Err(DateError::InvalidDate)
+    );
+    assert_eq!(
+        saturday.add_business_days(1, &[invalid]),
+        Err(DateError::InvalidDate)
+    );
+    assert_eq!(
+        saturday.business_days_between(&invalid, &[]),
+        Err(DateError::InvalidDate)
+    );
+    assert!(saturday.add_business_days(i64::MAX, &[]).is_err());
+    assert!(saturday.add_business_days(i64::MIN, &[]).is_err());
+}
+
+#[test]
+fn test_parsi_weekday_succ_pred_days_until() {
+    use crate::ParsiWeekday;
+    let mut day = ParsiWeekday::Shanbeh;
+    for _ in 0..7 {
+        assert_eq!(day.succ().pred(), day);
+        assert_eq!(day.days_until(day), 0);
+        assert_eq!(day.days_until(day.succ()), 1);
+        assert_eq!(day.days_until(day.pred()), 6);
+        day = day.succ();
+    }
+    assert_eq!(day, ParsiWeekday::Shanbeh, "seven succ() calls wrap around");
+    assert_eq!(ParsiWeekday::Jomeh.succ(), ParsiWeekday::Shanbeh);
+    assert_eq!(ParsiWeekday::Shanbeh.pred(), ParsiWeekday::Jomeh);
+    assert_eq!(
+        ParsiWeekday::Panjshanbeh.days_until(ParsiWeekday::Yekshanbeh),
+        3
+    );
+}
+
+#[test]
+fn test_weekdays_in_year() {
+    use crate::ParsiWeekday;
+    for year in [1403, 1404, 1] {
+        let mut total = 0;
+        for num in 0..7u32 {
+            let weekday = [
+                ParsiWeekday::Shanbeh,
+                ParsiWeekday::Yekshanbeh,
+                ParsiWeekday::Doshanbeh,
+                ParsiWeekday::Seshanbeh,
+                ParsiWeekday::Chaharshanbeh,
+                ParsiWeekday::Panjshanbeh,
+                ParsiWeekday::Jomeh,
+            ][num as usize];
+            let dates = ParsiDate::weekdays_in_year(year, weekday).unwrap();
+            assert!(dates.len() == 52 || dates.len() == 53);
+            assert!(dates
+                .iter()
+                .all(|d| d.year() == year && d.weekday_num_sat_0() == Ok(num)));
+            assert!(dates.windows(2).all(|w| w[1] == w[0].add_days(7).unwrap()));
+            total += dates.len();
+        }
+        assert_eq!(
+            total as u32,
+            if ParsiDate::is_persian_leap_year(year) {
+                366
+            } else {
+                365
+            }
+        );
+    }
+
+    // 1404 (common) starts on a Friday: only Fridays occur 53 times.
+    assert_eq!(
+        ParsiDate::weekdays_in_year(1404, ParsiWeekday::Jomeh)
+            .unwrap()
+            .len(),
+        53
+    );
+    assert_eq!(
+        ParsiDate::weekdays_in_year(1404, ParsiWeekday::Shanbeh)
+            .unwrap()
+            .len(),
+        52
+    );
+    assert_eq!(
+        ParsiDate::weekdays_in_year(1403, ParsiWeekday::Panjshanbeh)
+            .unwrap()
+            .last(),
+        Some(&pd(1403, 12, 30))
+    );
+    assert_eq!(
+        ParsiDate::weekdays_in_year(0, ParsiWeekday::Jomeh),
+        Err(DateError::InvalidDate)
+    );
+}
+
+#[test]
+fn test_weekday_numbers_for_month() {
+    for (year, month) in [
+        (1403, 1),
+        (1403, 7),
+        (1403, 12),
+        (1404, 12),
+        (1, 1),
+        (9999, 12),
+    ] {
+        let weekdays = ParsiDate::weekday_numbers_for_month(year, month).unwrap();
+        assert_eq!(weekdays.len() as u32, ParsiDate::days_in_month(year, month));
+        for (i, &weekday) in weekdays.iter().enumerate() {
+            let date = pd(year, month, i as u32 + 1);
+            assert_eq!(Ok(weekday as u32), date.weekday_num_sat_0(), "{}", date);
+        }
+    }
+    assert_eq!(
+        ParsiDate::weekday_numbers_for_month(1403, 13),
+        Err(DateError::InvalidDate)
+    );
+    assert_eq!(
+        ParsiDate::weekday_numbers_for_month(0, 1),
+        Err(DateError::InvalidDate)
+    );
+}
+
+#[test]
+fn test_count_weekday_in_month() {
+    use crate::ParsiWeekday;
+    // Brute-force cross-check against per-day weekday computation
+    for (year, month) in [(1403, 1), (1403, 7), (1403, 12), (1404, 12)] {
+        let last = ParsiDate::days_in_month(year, month);
+        for day in [1, 15, last] {
+            let date = pd(year, month, day);
+            for (num, weekday) in [
+                ParsiWeekday::Shanbeh,
+                ParsiWeekday::Seshanbeh,
+                ParsiWeekday::Jomeh,
+            ]
+            .into_iter()
+            .map(|w| (w.num_sat_0(), w))
+            {
+                let expected = (day..=last)
+                    .filter(|&d| pd(year, month, d).weekday_num_sat_0() == Ok(num))
+                    .count() as u32;
+                assert_eq!(date.count_weekday_in_month_from(weekday), Ok(expected));
+            }
+        }
+    }
+    assert_eq!(
+        pd(1403, 5, 31).count_weekday_in_month_from(ParsiWeekday::Jomeh),
+        Ok(0)
+    );
+    assert_eq!(
+        ParsiDate::count_weekday_in_month(1403, 13, ParsiWeekday::Jomeh),
+        Err(DateError::InvalidDate)
+    );
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(
+        invalid.count_weekday_in_month_from(ParsiWeekday::Jomeh),
+        Err(DateError::InvalidDate)
+    );
+}
+
+#[test]
+fn test_iso_week() {
+    // Week changes exactly on Saturdays, and each week's Tuesday lies in its week-based year
+    let mut date = pd(1400, 1, 1);
+    let mut prev = date.iso_week().unwrap();
+    while date < pd(1406, 1, 1) {
+        date = date.add_days(1).unwrap();
+        let current = date.iso_week().unwrap();
+        let weekday = date.weekday_num_sat_0().unwrap();
+        if weekday == 0 {
+            assert!(
+                current == (prev.0, prev.1 + 1) || current == (prev.0 + 1, 1),
+                "{date:?}: {prev:?} -> {current:?}"
+            );
+        } else {
+            assert_eq!(current, prev, "{date:?}");
+        }
+        if weekday == 3 {
+            assert_eq!(current.0, date.year());
+        }
+        prev = current;
+    }
+    assert_eq!(pd(1403, 12, 30).iso_week(), Ok((1403, 52))); // Thursday
+    assert_eq!(pd(1404, 1, 1).iso_week(), Ok((1403, 52))); // Friday
+
+    // Lower boundary: no year 0 is referenced
+    for day in 1..=9 {
+        assert_eq!(pd(1, 1, day).iso_week(), Ok((1, 1)), "1/1/{day}");
+    }
+    assert_eq!(pd(1, 1, 10).iso_week(), Ok((1, 2)));
+    assert_eq!(pd(1, 1, 1).format("%G-W%V"), "1-W01");
+
+    // Upper boundary stays computable
+    assert!(pd(9999, 12, 29).iso_week().is_ok());
+
+    assert_eq!(pd(1403, 1, 1).format("%G-W%V (%Y)"), "1402-W53 (1403)");
+    assert_eq!(
+        crate::ParsiDateTime::new(1403, 5, 2, 8, 0, 0)
+            .unwrap()
+            .format("%G-W%V %H"),
+        "1403-W18 08"
+    );
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(invalid.iso_week(), Err(DateError::InvalidDate));
+    assert_eq!(invalid.format("%G-%V"), "?WeekError?-?WeekError?");
+}
+
+#[test]
+fn test_view_matches_individual_queries() {
+    for date in [
+        pd(1, 1, 1),
+        pd(1403, 1, 1),
+        pd(1403, 5, 2),
+        pd(1403, 12, 30),
+        pd(1404, 1, 1),
+        pd(1404, 12, 29),
+        pd(9999, 12, 29),
+    ] {
+        let view = date.view().unwrap();
+        assert_eq!(view.date(), date);
+        assert_eq!(Ok(view.gregorian()), date.to_gregorian());
+        assert_eq!(Ok(view.weekday().num_sat_0()), date.weekday_num_sat_0());
+        assert_eq!(Ok(view.ordinal()), date.ordinal());
+        assert_eq!(Ok(view.season()), date.season());
+        assert_eq!(Ok(view.week_of_year()), date.week_of_year());
+    }
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(invalid.view(), Err(DateError::InvalidDate));
+}
+
+#[test]
+fn test_week_of_year_with() {
+    use crate::ParsiWeekday;
+
+    let weekdays = [
+        ParsiWeekday::Shanbeh,
+        ParsiWeekday::Yekshanbeh,
+        ParsiWeekday::Doshanbeh,
+        ParsiWeekday::Seshanbeh,
+        ParsiWeekday::Chaharshanbeh,
+        ParsiWeekday::Panjshanbeh,
+        ParsiWeekday::Jomeh,
+    ];
+    for year in [1400, 1403, 1404] {
+        let first = pd(year, 1, 1);
+        for &start in &weekdays {
+            for min_days in 1..=7 {
+                // Count week starts by walking the year: week 1 begins on Farvardin 1st if the
+                // first week is long enough, and every `start` weekday begins a new week.
+                let first_week_days =
+                    7 - (first.weekday_num_sat_0().unwrap() + 7 - start.num_sat_0()) % 7;
+                let mut week = u32::from(first_week_days >= min_days);
+                for date in first.iter_until(pd(year + 1, 1, 1)) {
+                    if date != first && date.weekday_num_sat_0().unwrap() == start.num_sat_0() {
+                        week += 1;
+                    }
+                    assert_eq!(
+                        date.week_of_year_with(start, min_days),
+                        Ok(week),
+                        "{date} {start:?} {min_days}"
+                    );
+                }
+            }
+        }
+    }
+
+    // The existing methods are the Saturday-based special cases
+    let date = pd(1403, 5, 2);
+    assert_eq!(
+        date.week_of_year_with(ParsiWeekday::Shanbeh, 1),
+        date.week_of_year()
+    );
+    assert_eq!(
+        date.week_of_year_with(ParsiWeekday::Shanbeh, 4),
+        date.week_of_year_us(4)
+    );
+    // Out-of-range minimums are clamped
+    assert_eq!(
+        pd(1403, 1, 1).week_of_year_with(ParsiWeekday::Doshanbeh, 0),
+        pd(1403, 1, 1).week_of_year_with(ParsiWeekday::Doshanbeh, 1)
+    );
+    assert_eq!(
+        pd(1403, 1, 1).week_of_year_with(ParsiWeekday::Doshanbeh, 100),
+        Ok(0)
+    );
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(
+        invalid.week_of_year_with(ParsiWeekday::Jomeh, 4),
+        Err(DateError::InvalidDate)
+    );
+}
+
+#[test]
+fn test_week_of_year_us() {
+    // 1404 starts on a Friday: the first week has a single day.
+    let nowruz_1404 = pd(1404, 1, 1);
+    assert_eq!(nowruz_1404.week_of_year_us(0), Ok(1));
+    assert_eq!(nowruz_1404.week_of_year_us(1), Ok(1));
+    assert_eq!(nowruz_1404.week_of_year_us(2), Ok(0));
+    assert_eq!(pd(1404, 1, 2).week_of_year_us(2), Ok(1)); // Saturday
+    assert_eq!(pd(1404, 12, 29).week_of_year_us(7), Ok(52));
+
+    // A year starting on Saturday has a full first week for any min_days.
+    let saturday_start = (1300..1500)
+        .map(|y| pd(y, 1, 1))
+        .find(|d| d.weekday_num_sat_0() == Ok(0))
+        .unwrap();
+    assert_eq!(saturday_start.week_of_year_us(7), Ok(1));
+    assert_eq!(saturday_start.week_of_year_us(100), Ok(1));
+
+    // Agrees with week_of_year whenever the first week is long enough
+    for date in [pd(1403, 1, 1), pd(1403, 5, 2), pd(1403, 12, 30)] {
+        assert_eq!(date.week_of_year_us(1), date.week_of_year());
+        assert_eq!(date.week_of_year_us(3), date.week_of_year()); // 1403 starts Wednesday: 3 days
+        assert_eq!(date.week_of_year_us(4), date.week_of_year().map(|w| w - 1));
+    }
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 0, 1) };
+    assert_eq!(invalid.week_of_year_us(4), Err(DateError::InvalidDate));
+}
+
+#[test]
+fn test_week_of_year_us_with_roll() {
+    // 1404 starts on a Friday; rolled, Farvardin 1st joins the last week of 1403.
+    let nowruz_1404 = pd(1404, 1, 1);
+    assert_eq!(nowruz_1404.week_of_year_us_with_roll(2, false), Ok(0));
+    assert_eq!(nowruz_1404.week_of_year_us_with_roll(2, true), Ok(53));
+    assert_eq!(pd(1403, 12, 30).week_of_year_us(2), Ok(53));
+    assert_eq!(pd(1404, 1, 2).week_of_year_us_with_roll(2, true), Ok(1)); // Saturday
+
+    // Around Farvardin 1st of several years, rolling only changes week 0
+    for year in 1400..=1410 {
+        let last_of_previous = pd(year, 1, 1).sub_days(1).unwrap();
+        for min_days in 1..=7 {
+            for day in 1..=7 {
+                let date = pd(year, 1, day);
+                let week = date.week_of_year_us(min_days).unwrap();
+                assert_eq!(date.week_of_year_us_with_roll(min_days, false), Ok(week));
+                let expected = if week == 0 {
+                    last_of_previous.week_of_year_us(min_days).unwrap()
+                } else {
+                    week
+                };
+                assert_eq!(
+                    date.week_of_year_us_with_roll(min_days, true),
+                    Ok(expected),
+                    "{date} {min_days}"
+                );
+            }
+        }
+    }
+
+    // Year 1 (starting on a Thursday) has no previous year to roll into
+    assert_eq!(pd(1, 1, 1).week_of_year_us_with_roll(7, true), Ok(0));
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 0, 1) };
+    assert_eq!(
+        invalid.week_of_year_us_with_roll(4, true),
+        Err(DateError::InvalidDate)
+    );
+}
+
+#[test]
+fn test_annotated_range() {
+    // Cross-check weekdays and dates against per-day computation across a year boundary
+    let start = pd(1403, 12, 1);
+    let end = pd(1404, 2, 15);
+    let days: Vec<_> = ParsiDate::annotated_range(start, end).unwrap().collect();
+    assert_eq!(days.len() as i64, start.days_between(&end).unwrap() + 1);
+    for (offset, day) in days.iter().enumerate() {
+        assert_eq!(day.date, start.add_days(offset as i64).unwrap());
+        assert_eq!(Ok(day.weekday.num_sat_0()), day.date.weekday_num_sat_0());
+        assert_eq!(day.is_friday, day.weekday.num_sat_0() == 6);
+        assert_eq!(day.is_holiday, day.holiday_name.is_some());
+    }
+    let holidays: Vec<_> = days
+        .iter()
+        .filter(|d| d.is_holiday)
+        .map(|d| d.date)
+        .collect();
+    assert_eq!(
+        holidays,
+        [
+            pd(1403, 12, 29),
+            pd(1404, 1, 1),
+            pd(1404, 1, 2),
+            pd(1404, 1, 3),
+            pd(1404, 1, 4),
+            pd(1404, 1, 12),
+            pd(1404, 1, 13)
+        ]
+    );
+
+    // Single-day and empty ranges
+    assert_eq!(ParsiDate::annotated_range(end, end).unwrap().count(), 1);
+    assert_eq!(ParsiDate::annotated_range(end, start).unwrap().count(), 0);
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(
+        ParsiDate::annotated_range(invalid, end).err(),
+        Some(DateError::InvalidDate)
+    );
+    assert_eq!(
+        ParsiDate::annotated_range(start, invalid).err(),
+        Some(DateError::InvalidDate)
+    );
+}
+
+#[test]
+fn test_iter_until() {
+    // Matches add_days across leap and common year ends
+    let start = pd(1403, 11, 20);
+    let end = pd(1405, 1, 10);
+    let mut iter = start.iter_until(end);
+    let expected = end.signed_days_between(&start).unwrap() as usize;
+    assert_eq!(iter.size_hint(), (expected, Some(expected)));
+    iter.next();
+    assert_eq!(iter.len(), expected - 1);
+    for (offset, date) in start.iter_until(end).enumerate() {
+        assert_eq!(Ok(date), start.add_days(offset as i64));
+    }
+    assert_eq!(start.iter_until(end).last(), Some(pd(1405, 1, 9)));
+
+    // The range can end right after the last supported day
+    let last: Vec<_> = pd(9999, 12, 28).iter_until(MAX_PARSI_DATE).collect();
+    assert_eq!(last, [pd(9999, 12, 28)]);
+
+    // Empty for reversed ranges and invalid dates
+    assert_eq!(end.iter_until(start).size_hint(), (0, Some(0)));
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(invalid.iter_until(end).count(), 0);
+    assert_eq!(start.iter_until(invalid).count(), 0);
+}
+
+#[test]
+fn test_jdn_round_trip() {
+    // JDN = days from the common era (chrono) + 1721425
+    let via_gregorian = |d: ParsiDate| {
+        i64::from(chrono::Datelike::num_days_from_ce(
+            &d.to_gregorian().unwrap(),
+        )) + 1_721_425
+    };
+    let mut year = 1;
+    while year <= 9999 {
+        for (month, day) in [(1, 1), (6, 31), (12, ParsiDate::days_in_month(year, 12))] {
+            let date = pd(year, month, day);
+            let jdn = date.to_jdn().unwrap();
+            assert_eq!(jdn, via_gregorian(date), "{date}");
+            assert_eq!(ParsiDate::from_jdn(jdn), Ok(date));
+        }
+        year += if year < 40 { 1 } else { 97 };
+    }
+    assert_eq!(MAX_PARSI_DATE.to_jdn(), Ok(via_gregorian(MAX_PARSI_DATE)));
+
+    // Every day of a full 33-year cycle round-trips and is consecutive
+    let start = pd(1399, 1, 1).to_jdn().unwrap();
+    for (offset, date) in pd(1399, 1, 1).iter_until(pd(1432, 1, 1)).enumerate() {
+        assert_eq!(date.to_jdn(), Ok(start + offset as i64));
+        assert_eq!(ParsiDate::from_jdn(start + offset as i64), Ok(date));
+    }
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(invalid.to_jdn(), Err(DateError::InvalidDate));
+    assert_eq!(ParsiDate::from_jdn(i64::MIN), Err(DateError::InvalidDate));
+    assert_eq!(ParsiDate::from_jdn(i64::MAX), Err(DateError::InvalidDate));
+}
+
+#[test]
+fn test_date_range() {
+    use crate::ParsiDateRange;
+
+    let range = ParsiDateRange::new(pd(1403, 12, 28), pd(1404, 1, 2)).unwrap();
+    assert_eq!(
+        (range.start(), range.end()),
+        (pd(1403, 12, 28), pd(1404, 1, 2))
+    );
+    assert!(range.contains(pd(1403, 12, 30)));
+    assert!(range.contains(pd(1404, 1, 2)));
+    assert!(!range.contains(pd(1403, 12, 27)));
+    assert_eq!(range.len_days(), Ok(5));
+    let days: Vec<_> = range.into_iter().collect();
+    assert_eq!(days.len(), 5);
+    assert_eq!(days[2], pd(1403, 12, 30));
+    assert_eq!((&range).into_iter().last(), Some(pd(1404, 1, 2)));
+
+    // Overlap is symmetric and includes touching endpoints
+    let touching = ParsiDateRange::new(pd(1404, 1, 2), pd(1404, 2, 1)).unwrap();
+    let disjoint = ParsiDateRange::new(pd(1404, 1, 3), pd(1404, 2, 1)).unwrap();
+    assert!(range.overlaps(&touching) && touching.overlaps(&range));
+    assert!(!range.overlaps(&disjoint) && !disjoint.overlaps(&range));
+
+    // The range may end on the last supported day
+    let last = ParsiDateRange::new(MAX_PARSI_DATE, MAX_PARSI_DATE).unwrap();
+    assert_eq!(last.into_iter().collect::<Vec<_>>(), [MAX_PARSI_DATE]);
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(
+        ParsiDateRange::new(pd(1404, 1, 2), pd(1404, 1, 1)),
+        Err(DateError::InvalidDate)
+    );
+    assert_eq!(
+        ParsiDateRange::new(pd(1404, 1, 1), invalid),
+        Err(DateError::InvalidDate)
+    );
+}
+
 // --- Arithmetic Tests ---
 #[test]
 fn test_add_sub_days() {
@@ -1176,6 +3725,65 @@ fn test_add_sub_days() {
     assert_eq!(invalid_date.sub_days(1), Err(DateError::InvalidDate));
 }
 
+#[test]
+fn test_add_sub_weeks() {
+    let base = pd(1403, 12, 20);
+    for weeks in [-60, -1, 0, 1, 2, 53] {
+        assert_eq!(base.add_weeks(weeks), base.add_days(7 * weeks));
+    }
+    assert_eq!(base.sub_weeks(3), base.sub_days(21));
+    assert_eq!(base.add_weeks(2), Ok(pd(1404, 1, 4)));
+
+    // Overflowing multiplication and out-of-range results
+    assert_eq!(base.add_weeks(i64::MIN), Err(DateError::ArithmeticOverflow));
+    assert_eq!(base.sub_weeks(u64::MAX), Err(DateError::ArithmeticOverflow));
+    assert_eq!(
+        MAX_PARSI_DATE.add_weeks(1),
+        Err(DateError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        MIN_PARSI_DATE.sub_weeks(1),
+        Err(DateError::ArithmeticOverflow)
+    );
+
+    let invalid_date = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(invalid_date.add_weeks(1), Err(DateError::InvalidDate));
+    assert_eq!(invalid_date.sub_weeks(1), Err(DateError::InvalidDate));
+}
+
+#[test]
+fn test_add_days_fast_path_matches_gregorian_round_trip() {
+    let via_gregorian = |date: ParsiDate, days: i64| {
+        let g = date.to_gregorian().unwrap();
+        let shifted = if days >= 0 {
+            g.checked_add_days(chrono::Days::new(days as u64))
+        } else {
+            g.checked_sub_days(chrono::Days::new(days.unsigned_abs()))
+        };
+        ParsiDate::from_gregorian(shifted.unwrap())
+    };
+    for date in [
+        pd(1403, 1, 1),
+        pd(1403, 6, 31),
+        pd(1403, 7, 1),
+        pd(1403, 7, 30),
+        pd(1403, 12, 30),
+        pd(1404, 12, 29),
+        pd(1404, 12, 1),
+    ] {
+        for days in -35..=35 {
+            assert_eq!(
+                date.add_days(days),
+                via_gregorian(date, days),
+                "{date:?} + {days}"
+            );
+        }
+    }
+    assert_eq!(pd(1, 1, 1).add_days(0), Ok(pd(1, 1, 1)));
+    assert!(pd(1403, 5, 2).add_days(i64::MIN).is_err());
+    assert!(pd(1403, 5, 2).add_days(i64::MAX).is_err());
+}
+
 #[test]
 fn test_add_sub_months() {
     let d_31 = pd(1403, 1, 31); // End of 31-day month (Farvardin, leap year)
@@ -1273,6 +3881,165 @@ fn test_days_between() {
     assert_eq!(d1.days_between(&invalid_date), Err(DateError::InvalidDate));
 }
 
+#[test]
+fn test_signed_days_between() {
+    let d1 = pd(1403, 1, 1);
+    let d4 = pd(1402, 12, 29);
+    assert_eq!(d1.signed_days_between(&d4), Ok(1));
+    assert_eq!(d4.signed_days_between(&d1), Ok(-1));
+    assert_eq!(d1.signed_days_between(&d1), Ok(0));
+
+    // Epoch and upper boundary: the sign follows `self - other` and the magnitude matches
+    // both `days_between` and chrono's own day count.
+    let span = NaiveDate::from_ymd_opt(10621, 3, 19)
+        .unwrap()
+        .signed_duration_since(NaiveDate::from_ymd_opt(622, 3, 21).unwrap())
+        .num_days();
+    assert_eq!(
+        MAX_PARSI_DATE.signed_days_between(&MIN_PARSI_DATE),
+        Ok(span)
+    );
+    assert_eq!(
+        MIN_PARSI_DATE.signed_days_between(&MAX_PARSI_DATE),
+        Ok(-span)
+    );
+    assert_eq!(MIN_PARSI_DATE.days_between(&MAX_PARSI_DATE), Ok(span));
+    assert_eq!(MAX_PARSI_DATE.days_between(&MIN_PARSI_DATE), Ok(span));
+    assert_eq!(pd(1, 1, 2).signed_days_between(&MIN_PARSI_DATE), Ok(1));
+    assert_eq!(
+        pd(9999, 12, 28).signed_days_between(&MAX_PARSI_DATE),
+        Ok(-1)
+    );
+
+    // Adding the signed difference leads back to `self`.
+    for (a, b) in [
+        (d1, d4),
+        (MIN_PARSI_DATE, MAX_PARSI_DATE),
+        (pd(1, 6, 6), pd(5000, 1, 1)),
+    ] {
+        let diff = a.signed_days_between(&b).unwrap();
+        assert_eq!(b.add_days(diff), Ok(a));
+    }
+
+    // Invalid dates are reported, never counted
+    let invalid_date = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(
+        invalid_date.signed_days_between(&d1),
+        Err(DateError::InvalidDate)
+    );
+    assert_eq!(
+        d1.signed_days_between(&invalid_date),
+        Err(DateError::InvalidDate)
+    );
+}
+
+#[test]
+fn test_inclusive_and_exclusive_day_counts() {
+    let d1 = pd(1403, 1, 1);
+    // Same day: inclusive = 1, exclusive = 0
+    assert_eq!(d1.calendar_days_inclusive(&d1), Ok(1));
+    assert_eq!(d1.days_between_exclusive(&d1), Ok(0));
+
+    // A whole leap year counted inclusively has 366 days
+    let last = pd(1403, 12, 30);
+    assert_eq!(d1.calendar_days_inclusive(&last), Ok(366));
+    assert_eq!(last.calendar_days_inclusive(&d1), Ok(366));
+    assert_eq!(d1.days_between_exclusive(&last), Ok(365));
+    assert_eq!(d1.days_between_exclusive(&last), d1.days_between(&last));
+
+    let invalid_date = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(
+        d1.calendar_days_inclusive(&invalid_date),
+        Err(DateError::InvalidDate)
+    );
+    assert_eq!(
+        invalid_date.days_between_exclusive(&d1),
+        Err(DateError::InvalidDate)
+    );
+}
+
+#[test]
+fn test_overflowing_month_arithmetic() {
+    let d = pd(1403, 6, 31);
+    assert_eq!(d.add_months_overflowing(0), Ok((d, false)));
+    assert_eq!(d.add_months_overflowing(1), Ok((pd(1403, 7, 30), true)));
+    assert_eq!(d.add_months_overflowing(6), Ok((pd(1403, 12, 30), true))); // Leap Esfand
+    assert_eq!(d.add_months_overflowing(18), Ok((pd(1404, 12, 29), true))); // Common Esfand
+    assert_eq!(d.add_months_overflowing(-6), Ok((pd(1402, 12, 29), true)));
+    assert_eq!(
+        pd(1403, 7, 15).add_months_overflowing(1),
+        Ok((pd(1403, 8, 15), false))
+    );
+    assert_eq!(
+        pd(9999, 1, 1).add_months_overflowing(12),
+        Err(DateError::ArithmeticOverflow)
+    );
+
+    assert_eq!(d.with_month_overflowing(7), Ok((pd(1403, 7, 30), true)));
+    assert_eq!(d.with_month_overflowing(1), Ok((pd(1403, 1, 31), false)));
+    assert_eq!(d.with_month_overflowing(13), Err(DateError::InvalidDate));
+}
+
+#[test]
+fn test_sub_parsi_date_interval() {
+    use crate::ParsiInterval;
+    let iv = |years, months, days| ParsiInterval {
+        years,
+        months,
+        days,
+    };
+    assert_eq!(pd(1403, 1, 5) - pd(1402, 11, 20), Ok(iv(0, 1, 14)));
+    assert_eq!(pd(1402, 11, 20) - pd(1403, 1, 5), Ok(iv(0, -1, -14)));
+    assert_eq!(pd(1403, 5, 2) - pd(1403, 5, 2), Ok(iv(0, 0, 0)));
+    assert_eq!(pd(1404, 5, 2) - pd(1403, 5, 2), Ok(iv(1, 0, 0)));
+    assert_eq!(pd(1404, 3, 1) - pd(1403, 5, 2), Ok(iv(0, 9, 30)));
+    // Earlier day clamped when borrowing from a shorter month
+    assert_eq!(pd(1403, 1, 1) - pd(1402, 6, 31), Ok(iv(0, 6, 1)));
+    assert_eq!(pd(1403, 8, 1) - pd(1403, 6, 31), Ok(iv(0, 1, 1)));
+
+    // Round trip: earlier + years + months + days == later
+    let earlier = pd(1399, 6, 31);
+    for later in [
+        pd(1403, 1, 1),
+        pd(1403, 12, 30),
+        pd(1400, 7, 30),
+        pd(1399, 7, 1),
+    ] {
+        let interval = (later - earlier).unwrap();
+        assert!(!interval.is_negative());
+        let rebuilt = earlier
+            .add_years(interval.years)
+            .and_then(|d| d.add_months(interval.months))
+            .and_then(|d| d.add_days(interval.days as i64));
+        assert_eq!(rebuilt, Ok(later), "interval {:?}", interval);
+    }
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+    assert_eq!(invalid - pd(1403, 1, 1), Err(DateError::InvalidDate));
+}
+
+#[test]
+fn test_humanize_difference() {
+    let start = pd(1400, 2, 10);
+    assert_eq!(
+        pd(1402, 5, 15).humanize_difference(&start),
+        "۲ سال و ۳ ماه و ۵ روز"
+    );
+    assert_eq!(
+        start.humanize_difference(&pd(1402, 5, 15)),
+        "۲ سال و ۳ ماه و ۵ روز"
+    );
+    assert_eq!(
+        pd(1410, 2, 25).humanize_difference(&start),
+        "۱۰ سال و ۱۵ روز"
+    ); // Months omitted
+    assert_eq!(pd(1400, 4, 10).humanize_difference(&start), "۲ ماه");
+    assert_eq!(start.humanize_difference(&start), "همان روز");
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
+    assert_eq!(invalid.humanize_difference(&start), "?InvalidDate?");
+}
+
 // --- Helper Method Tests ---
 #[test]
 fn test_with_year() {
@@ -1285,6 +4052,94 @@ fn test_with_year() {
     assert_eq!(d_mid_leap.with_year(0), Err(DateError::InvalidDate));
 }
 
+#[test]
+fn test_with_year_saturating() {
+    let date = pd(1403, 5, 2);
+    assert_eq!(date.with_year_saturating(1410), pd(1410, 5, 2));
+    assert_eq!(date.with_year_saturating(10000), pd(9999, 5, 2));
+    assert_eq!(date.with_year_saturating(0), pd(1, 5, 2));
+    assert_eq!(date.with_year_saturating(i32::MIN), pd(1, 5, 2));
+
+    // Clamp first, then adjust Esfand 30th for the (common) clamped year.
+    let leap_day = pd(1403, 12, 30);
+    assert_eq!(leap_day.with_year_saturating(20000), pd(9999, 12, 29));
+    assert_eq!(leap_day.with_year_saturating(1408), pd(1408, 12, 30)); // 1408 is leap
+    assert_eq!(leap_day.with_year_saturating(1404), pd(1404, 12, 29));
+
+    // Invalid start dates are returned unchanged.
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
+    assert_eq!(invalid.with_year_saturating(1404), invalid);
+}
+
+#[test]
+fn test_anniversary() {
+    let leap_day = pd(1403, 12, 30);
+    assert_eq!(leap_day.anniversary_in_year(1404), Ok(pd(1404, 12, 29)));
+    assert_eq!(leap_day.anniversary_in_year(1408), Ok(pd(1408, 12, 30)));
+    assert_eq!(leap_day.anniversary_in_year(1400), Ok(pd(1400, 12, 29))); // Earlier years work too
+    assert_eq!(pd(1403, 5, 2).anniversary_in_year(1410), Ok(pd(1410, 5, 2)));
+    assert_eq!(
+        leap_day.anniversary_in_year(10000),
+        Err(DateError::InvalidDate)
+    );
+
+    assert!(pd(1404, 12, 29).is_anniversary_of(&leap_day));
+    assert!(pd(1408, 12, 30).is_anniversary_of(&leap_day));
+    assert!(!pd(1408, 12, 29).is_anniversary_of(&leap_day)); // Leap year observes the 30th
+    assert!(!pd(1402, 12, 29).is_anniversary_of(&leap_day)); // Before the original
+    assert!(!leap_day.is_anniversary_of(&leap_day));
+    assert!(pd(1404, 5, 2).is_anniversary_of(&pd(1403, 5, 2)));
+    assert!(!pd(1404, 5, 3).is_anniversary_of(&pd(1403, 5, 2)));
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
+    assert_eq!(
+        invalid.anniversary_in_year(1404),
+        Err(DateError::InvalidDate)
+    );
+    assert!(!pd(1404, 1, 1).is_anniversary_of(&invalid));
+}
+
+#[test]
+fn test_parsi_datelike_generic() {
+    use crate::{ParsiDateTime, ParsiDatelike};
+
+    fn summary<D: ParsiDatelike>(value: &D) -> (i32, u32, u32, u32, u32, bool) {
+        (
+            value.year(),
+            value.month(),
+            value.day(),
+            value.ordinal().unwrap(),
+            value.weekday_number().unwrap(),
+            value.is_leap_year(),
+        )
+    }
+    fn to_leap_day<D: ParsiDatelike>(value: &D) -> Result<D, DateError> {
+        value.with_month(12)?.with_day(30)
+    }
+
+    let date = pd(1403, 5, 2); // Tuesday
+    let dt = ParsiDateTime::new(1403, 5, 2, 10, 20, 30).unwrap();
+    assert_eq!(summary(&date), (1403, 5, 2, 126, 3, true));
+    assert_eq!(summary(&dt), summary(&date));
+    assert_eq!(ParsiDatelike::date(&dt), date);
+
+    assert_eq!(to_leap_day(&date), Ok(pd(1403, 12, 30)));
+    assert_eq!(
+        to_leap_day(&dt),
+        Ok(ParsiDateTime::new(1403, 12, 30, 10, 20, 30).unwrap())
+    );
+    assert_eq!(
+        to_leap_day(&ParsiDatelike::with_year(&date, 1404).unwrap()),
+        Err(DateError::InvalidDate)
+    );
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
+    assert_eq!(
+        ParsiDatelike::weekday_number(&invalid),
+        Err(DateError::InvalidDate)
+    );
+}
+
 #[test]
 fn test_with_month() {
     let d_31 = pd(1403, 1, 31);
@@ -1333,6 +4188,148 @@ fn test_day_of_boundaries() {
     assert_eq!(d_mid_common.last_day_of_year(), pd(1404, 12, 29));
 }
 
+#[test]
+fn test_fixed_observances() {
+    assert_eq!(ParsiDate::nowruz_plus(1404, 0), Ok(pd(1404, 1, 1)));
+    assert_eq!(ParsiDate::nowruz_plus(1404, 31), Ok(pd(1404, 2, 1)));
+    assert_eq!(ParsiDate::nowruz_plus(1404, 364), Ok(pd(1404, 12, 29)));
+    assert_eq!(ParsiDate::nowruz_plus(1404, 365), Ok(pd(1405, 1, 1))); // 1404 is common
+    assert_eq!(ParsiDate::sizdah_bedar(1404), Ok(pd(1404, 1, 13)));
+    assert_eq!(ParsiDate::sizdah_bedar(0), Err(DateError::InvalidDate));
+    assert_eq!(ParsiDate::sizdah_bedar(10000), Err(DateError::InvalidDate));
+    assert!(ParsiDate::nowruz_plus(9999, 365).is_err());
+}
+
+#[test]
+fn test_sorted_slice_lookups() {
+    let table = [
+        pd(1402, 12, 29),
+        pd(1403, 1, 1),
+        pd(1403, 1, 13),
+        pd(1403, 3, 14),
+    ];
+    assert_eq!(ParsiDate::binary_search_in(&table, &pd(1403, 1, 13)), Ok(2));
+    assert_eq!(ParsiDate::binary_search_in(&table, &pd(1403, 1, 2)), Err(2));
+    assert_eq!(ParsiDate::binary_search_in(&table, &pd(1300, 1, 1)), Err(0));
+
+    // Exact hit
+    assert_eq!(pd(1403, 1, 1).nearest_in_sorted(&table), Some(&table[1]));
+    // Across a year boundary: 1402/12/29 is one day before 1403/01/01
+    assert_eq!(pd(1402, 12, 20).nearest_in_sorted(&table), Some(&table[0]));
+    // Closer to the later entry
+    assert_eq!(pd(1403, 1, 12).nearest_in_sorted(&table), Some(&table[2]));
+    // Tie goes to the earlier entry
+    assert_eq!(pd(1403, 1, 7).nearest_in_sorted(&table), Some(&table[1]));
+    // Beyond both ends
+    assert_eq!(pd(1300, 1, 1).nearest_in_sorted(&table), Some(&table[0]));
+    assert_eq!(pd(1500, 1, 1).nearest_in_sorted(&table), Some(&table[3]));
+    assert_eq!(pd(1403, 1, 1).nearest_in_sorted(&[]), None);
+}
+
+#[test]
+fn test_next_prev_in_month() {
+    assert_eq!(pd(1403, 1, 30).next_in_month(), Some(pd(1403, 1, 31)));
+    assert_eq!(pd(1403, 1, 31).next_in_month(), None);
+    assert_eq!(pd(1403, 12, 29).next_in_month(), Some(pd(1403, 12, 30))); // Leap Esfand
+    assert_eq!(pd(1404, 12, 29).next_in_month(), None); // Common Esfand
+    assert_eq!(pd(1403, 8, 2).prev_in_month(), Some(pd(1403, 8, 1)));
+    assert_eq!(pd(1403, 8, 1).prev_in_month(), None);
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 7, 31) };
+    assert_eq!(invalid.next_in_month(), None);
+    assert_eq!(invalid.prev_in_month(), None);
+
+    // Walking a month forward visits exactly days_in_month days
+    let count = std::iter::successors(Some(pd(1403, 7, 1)), |d| d.next_in_month()).count();
+    assert_eq!(count, 30);
+}
+
+#[test]
+fn test_month_year_season_bounds() {
+    let date = pd(1403, 12, 10);
+    assert_eq!(date.month_bounds(), (pd(1403, 12, 1), pd(1403, 12, 30)));
+    assert_eq!(date.year_bounds(), (pd(1403, 1, 1), pd(1403, 12, 30)));
+    assert_eq!(
+        date.season_bounds(),
+        Ok((pd(1403, 10, 1), pd(1403, 12, 30)))
+    );
+
+    let common = pd(1404, 2, 31);
+    assert_eq!(common.month_bounds(), (pd(1404, 2, 1), pd(1404, 2, 31)));
+    assert_eq!(common.year_bounds(), (pd(1404, 1, 1), pd(1404, 12, 29)));
+    assert_eq!(
+        common.season_bounds(),
+        Ok((pd(1404, 1, 1), pd(1404, 3, 31)))
+    );
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
+    assert_eq!(invalid.season_bounds(), Err(DateError::InvalidDate));
+}
+
+#[test]
+fn test_start_of_next_previous_period() {
+    let date = pd(1403, 12, 30);
+    assert_eq!(date.start_of_next_month(), Ok(pd(1404, 1, 1)));
+    assert_eq!(date.start_of_previous_month(), Ok(pd(1403, 11, 1)));
+    assert_eq!(date.start_of_next_year(), Ok(pd(1404, 1, 1)));
+    assert_eq!(date.start_of_previous_year(), Ok(pd(1402, 1, 1)));
+    assert_eq!(
+        pd(1404, 1, 1).start_of_previous_month(),
+        Ok(pd(1403, 12, 1))
+    );
+    assert_eq!(pd(1403, 6, 31).start_of_next_month(), Ok(pd(1403, 7, 1)));
+
+    // Matches the add_months based formulation
+    for month in 1..=12 {
+        let d = pd(1402, month, 15);
+        assert_eq!(
+            d.start_of_next_month(),
+            Ok(d.add_months(1).unwrap().first_day_of_month())
+        );
+    }
+
+    // Range boundaries
+    assert_eq!(pd(9999, 11, 1).start_of_next_month(), Ok(pd(9999, 12, 1)));
+    assert_eq!(
+        pd(9999, 12, 1).start_of_next_month(),
+        Err(DateError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        pd(9999, 5, 1).start_of_next_year(),
+        Err(DateError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        pd(1, 1, 31).start_of_previous_month(),
+        Err(DateError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        pd(1, 6, 1).start_of_previous_year(),
+        Err(DateError::ArithmeticOverflow)
+    );
+
+    let invalid = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
+    assert_eq!(invalid.start_of_next_month(), Err(DateError::InvalidDate));
+    assert_eq!(
+        invalid.start_of_previous_year(),
+        Err(DateError::InvalidDate)
+    );
+}
+
+#[test]
+fn test_new_validated_components_matches_new() {
+    for year in [1, 1403, 1404, 9999] {
+        for month in 0..=13 {
+            for day in 0..=32 {
+                assert_eq!(
+                    ParsiDate::new_validated_components(year, month, day),
+                    ParsiDate::new(year, month, day),
+                    "{year}/{month}/{day}"
+                );
+            }
+        }
+    }
+}
+
 // --- Constant Tests ---
 #[test]
 fn test_constants_validity_and_values() {
@@ -1345,11 +4342,49 @@ fn test_constants_validity_and_values() {
     assert!(!ParsiDate::is_persian_leap_year(9999));
 }
 
+#[test]
+fn test_max_gregorian_convertible() {
+    let max = ParsiDate::MAX_GREGORIAN_CONVERTIBLE;
+    assert_eq!(max, MAX_PARSI_DATE);
+    let g = max.to_gregorian().unwrap();
+    assert_eq!(g, NaiveDate::from_ymd_opt(10621, 3, 19).unwrap());
+    assert_eq!(ParsiDate::from_gregorian(g), Ok(max));
+    // Nothing later is representable
+    assert!(ParsiDate::from_gregorian(g.succ_opt().unwrap()).is_err());
+    assert!(max.add_days(1).is_err());
+}
+
 // --- Serde Tests (conditional on 'serde' feature) ---
 #[cfg(feature = "serde")]
 mod serde_tests {
     use super::*;
 
+    #[test]
+    fn test_range_deserialization_validates() {
+        use crate::ParsiDateRange;
+
+        let range = ParsiDateRange::new(pd(1404, 1, 1), pd(1404, 2, 1)).unwrap();
+        let json = serde_json::to_string(&range).unwrap();
+        assert_eq!(
+            json,
+            r#"{"start":{"year":1404,"month":1,"day":1},"end":{"year":1404,"month":2,"day":1}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<ParsiDateRange>(&json).unwrap(),
+            range
+        );
+
+        // A reversed range or an invalid date is rejected like in `new`
+        let reversed =
+            r#"{"start":{"year":1404,"month":2,"day":1},"end":{"year":1404,"month":1,"day":1}}"#;
+        let invalid =
+            r#"{"start":{"year":1404,"month":1,"day":1},"end":{"year":1404,"month":12,"day":30}}"#;
+        for json in [reversed, invalid] {
+            let err = serde_json::from_str::<ParsiDateRange>(json).unwrap_err();
+            assert_eq!(err.to_string(), DateError::InvalidDate.to_string());
+        }
+    }
+
     #[test]
     fn test_serialization_deserialization_valid() {
         let date = pd(1403, 5, 2);
@@ -1376,6 +4411,277 @@ mod serde_tests {
         let json_missing_field = r#"{"year":1403,"month":5}"#;
         assert!(serde_json::from_str::<ParsiDate>(json_missing_field).is_err());
     }
+
+    #[test]
+    fn test_compact_representation() {
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        struct Row {
+            #[serde(with = "crate::serde::compact")]
+            date: ParsiDate,
+            #[serde(with = "crate::serde::compact")]
+            datetime: crate::ParsiDateTime,
+        }
+
+        let row = Row {
+            date: pd(1403, 12, 30),
+            datetime: crate::ParsiDateTime::new(1403, 12, 30, 23, 59, 59).unwrap(),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(
+            json,
+            r#"{"date":[1403,12,30],"datetime":[1403,12,30,23,59,59]}"#
+        );
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+        // Invalid components and wrong lengths are rejected
+        let invalid_date = r#"{"date":[1404,12,30],"datetime":[1403,1,1,0,0,0]}"#;
+        assert!(serde_json::from_str::<Row>(invalid_date).is_err());
+        let invalid_time = r#"{"date":[1403,1,1],"datetime":[1403,1,1,24,0,0]}"#;
+        assert!(serde_json::from_str::<Row>(invalid_time).is_err());
+        let short_array = r#"{"date":[1403,1],"datetime":[1403,1,1,0,0,0]}"#;
+        assert!(serde_json::from_str::<Row>(short_array).is_err());
+
+        // Values built without validation are not written, as they could not be read back
+        let unchecked_date = Row {
+            date: unsafe { ParsiDate::new_unchecked(1404, 12, 30) },
+            datetime: row.datetime,
+        };
+        let err = serde_json::to_string(&unchecked_date).unwrap_err();
+        assert_eq!(err.to_string(), DateError::InvalidDate.to_string());
+        let unchecked_time = Row {
+            date: row.date,
+            datetime: unsafe { crate::ParsiDateTime::new_unchecked(1403, 1, 1, 24, 0, 0) },
+        };
+        let err = serde_json::to_string(&unchecked_time).unwrap_err();
+        assert_eq!(err.to_string(), DateError::InvalidTime.to_string());
+        let unchecked_both = Row {
+            date: row.date,
+            datetime: unsafe { crate::ParsiDateTime::new_unchecked(1404, 12, 30, 24, 0, 0) },
+        };
+        let err = serde_json::to_string(&unchecked_both).unwrap_err();
+        assert_eq!(err.to_string(), DateError::InvalidDate.to_string());
+    }
+
+    #[test]
+    fn test_auto_representation() {
+        use serde_test::{
+            assert_de_tokens_error, assert_ser_tokens_error, assert_tokens, Configure, Token,
+        };
+
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
+        struct Row {
+            #[serde(with = "crate::serde::auto")]
+            date: ParsiDate,
+            #[serde(with = "crate::serde::auto")]
+            datetime: crate::ParsiDateTime,
+        }
+
+        let row = Row {
+            date: pd(1403, 5, 2),
+            datetime: crate::ParsiDateTime::new(1403, 5, 2, 8, 5, 9).unwrap(),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(
+            json,
+            r#"{"date":"1403/05/02","datetime":"1403/05/02 08:05:09"}"#
+        );
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+        // Readable formats use the string, compact formats the component arrays
+        assert_tokens(
+            &row.clone().readable(),
+            &[
+                Token::Struct {
+                    name: "Row",
+                    len: 2,
+                },
+                Token::Str("date"),
+                Token::Str("1403/05/02"),
+                Token::Str("datetime"),
+                Token::Str("1403/05/02 08:05:09"),
+                Token::StructEnd,
+            ],
+        );
+        let compact_tokens = [
+            Token::Struct {
+                name: "Row",
+                len: 2,
+            },
+            Token::Str("date"),
+            Token::Tuple { len: 3 },
+            Token::I32(1403),
+            Token::U32(5),
+            Token::U32(2),
+            Token::TupleEnd,
+            Token::Str("datetime"),
+            Token::Tuple { len: 6 },
+            Token::I32(1403),
+            Token::U32(5),
+            Token::U32(2),
+            Token::U32(8),
+            Token::U32(5),
+            Token::U32(9),
+            Token::TupleEnd,
+            Token::StructEnd,
+        ];
+        assert_tokens(&row.compact(), &compact_tokens);
+
+        // Both branches validate
+        let invalid_json = r#"{"date":"1404/12/30","datetime":"1403/05/02 08:05:09"}"#;
+        assert!(serde_json::from_str::<Row>(invalid_json).is_err());
+        let bad_time = r#"{"date":"1403/05/02","datetime":"1403/05/02 24:00:00"}"#;
+        assert!(serde_json::from_str::<Row>(bad_time).is_err());
+        let mut invalid_compact = compact_tokens;
+        invalid_compact[4] = Token::U32(13);
+        assert_de_tokens_error::<serde_test::Compact<Row>>(
+            &invalid_compact[..7],
+            "Invalid Persian date: year, month, or day is out of range or inconsistent",
+        );
+
+        // Type errors name the form of each field
+        assert_de_tokens_error::<serde_test::Readable<Row>>(
+            &[compact_tokens[0], Token::Str("date"), Token::I32(1403)],
+            "invalid type: integer `1403`, expected a Persian date string such as \"1403/05/02\"",
+        );
+        assert_de_tokens_error::<serde_test::Readable<Row>>(
+            &[
+                compact_tokens[0],
+                Token::Str("date"),
+                Token::Str("1403/05/02"),
+                Token::Str("datetime"),
+                Token::I32(1403),
+            ],
+            "invalid type: integer `1403`, expected a Persian date-time string such as \"1403/05/02 08:05:09\"",
+        );
+
+        // Neither branch writes a value built without validation
+        let unchecked_date = Row {
+            date: unsafe { ParsiDate::new_unchecked(1404, 12, 30) },
+            datetime: crate::ParsiDateTime::new(1403, 5, 2, 8, 5, 9).unwrap(),
+        };
+        let unchecked_time = Row {
+            date: pd(1403, 5, 2),
+            datetime: unsafe { crate::ParsiDateTime::new_unchecked(1403, 5, 2, 24, 0, 0) },
+        };
+        let date_error = DateError::InvalidDate.to_string();
+        let time_error = DateError::InvalidTime.to_string();
+        assert_ser_tokens_error(
+            &unchecked_date.clone().readable(),
+            &compact_tokens[..2],
+            &date_error,
+        );
+        assert_ser_tokens_error(&unchecked_date.compact(), &compact_tokens[..2], &date_error);
+        assert_ser_tokens_error(
+            &unchecked_time.clone().readable(),
+            &[
+                compact_tokens[0],
+                Token::Str("date"),
+                Token::Str("1403/05/02"),
+                Token::Str("datetime"),
+            ],
+            &time_error,
+        );
+        assert_ser_tokens_error(&unchecked_time.compact(), &compact_tokens[..8], &time_error);
+    }
+
+    #[test]
+    fn test_whole_second_representations_reject_nanoseconds() {
+        #[derive(serde::Serialize)]
+        struct Row {
+            #[serde(with = "crate::serde::compact")]
+            compact: crate::ParsiDateTime,
+            #[serde(with = "crate::serde::string_datetime")]
+            string: crate::ParsiDateTime,
+            #[serde(with = "crate::serde::auto")]
+            auto: crate::ParsiDateTime,
+        }
+
+        let whole = crate::ParsiDateTime::new(1403, 5, 2, 8, 5, 9).unwrap();
+        let precise = whole.with_nanosecond(1).unwrap();
+        let row = |compact, string, auto| Row {
+            compact,
+            string,
+            auto,
+        };
+        assert!(serde_json::to_string(&row(precise, whole, whole)).is_err());
+        assert!(serde_json::to_string(&row(whole, precise, whole)).is_err());
+        // Human-readable `auto` keeps the fraction in the string
+        assert_eq!(
+            serde_json::to_string(&row(whole, whole, precise)).unwrap(),
+            r#"{"compact":[1403,5,2,8,5,9],"string":"1403-05-02 08:05:09","auto":"1403/05/02 08:05:09.000000001"}"#
+        );
+    }
+
+    #[test]
+    fn test_string_representation() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        struct Row {
+            #[serde(with = "crate::serde::string")]
+            date: ParsiDate,
+            #[serde(with = "crate::serde::string_datetime")]
+            datetime: crate::ParsiDateTime,
+        }
+
+        let row = Row {
+            date: pd(1403, 5, 2),
+            datetime: crate::ParsiDateTime::new(1403, 5, 2, 15, 30, 45).unwrap(),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(
+            json,
+            r#"{"date":"1403-05-02","datetime":"1403-05-02 15:30:45"}"#
+        );
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+        // The same strings regardless of is_human_readable(), and padded early years
+        let early = Row {
+            date: pd(50, 1, 1),
+            datetime: crate::ParsiDateTime::new(50, 1, 1, 0, 0, 0).unwrap(),
+        };
+        let tokens = [
+            Token::Struct {
+                name: "Row",
+                len: 2,
+            },
+            Token::Str("date"),
+            Token::Str("0050-01-01"),
+            Token::Str("datetime"),
+            Token::Str("0050-01-01 00:00:00"),
+            Token::StructEnd,
+        ];
+        assert_tokens(&early, &tokens);
+
+        // Deserialization validates and expects exactly the ISO-like form
+        for bad in [
+            r#"{"date":"1404-12-30","datetime":"1403-05-02 15:30:45"}"#,
+            r#"{"date":"1403/05/02","datetime":"1403-05-02 15:30:45"}"#,
+            r#"{"date":"1403-05-02","datetime":"1403-05-02 24:00:00"}"#,
+            r#"{"date":"1403-05-02","datetime":"1403-05-02"}"#,
+            r#"{"date":[1403,5,2],"datetime":"1403-05-02 15:30:45"}"#,
+        ] {
+            assert!(serde_json::from_str::<Row>(bad).is_err(), "{bad}");
+        }
+        assert_de_tokens_error::<Row>(
+            &[tokens[0], tokens[1], Token::I32(1403)],
+            "invalid type: integer `1403`, expected a Persian date string such as \"1403-05-02\"",
+        );
+
+        // Invalid values built without validation are not written
+        let invalid_date = Row {
+            date: unsafe { ParsiDate::new_unchecked(1404, 12, 30) },
+            datetime: row.datetime,
+        };
+        let err = serde_json::to_string(&invalid_date).unwrap_err();
+        assert_eq!(err.to_string(), DateError::InvalidDate.to_string());
+        let invalid_time = Row {
+            date: row.date,
+            datetime: unsafe { crate::ParsiDateTime::new_unchecked(1403, 5, 2, 24, 0, 0) },
+        };
+        let err = serde_json::to_string(&invalid_time).unwrap_err();
+        assert_eq!(err.to_string(), DateError::InvalidTime.to_string());
+    }
 }
 
 #[cfg(test)]
@@ -1434,6 +4740,31 @@ mod season_tests {
         let d_winter_common = pd(1404, 11, 10);
         assert_eq!(d_winter_common.end_of_season(), Ok(pd(1404, 12, 29)));
     }
+
+    #[test]
+    fn test_quarter_and_boundaries() {
+        for month in 1..=12 {
+            let date = pd(1403, month, 1);
+            assert_eq!(date.quarter(), Ok((month - 1) / 3 + 1));
+            // Quarters coincide with seasons
+            assert_eq!(date.start_of_quarter(), date.start_of_season());
+            assert_eq!(date.end_of_quarter(), date.end_of_season());
+        }
+        assert_eq!(pd(1404, 12, 29).end_of_quarter(), Ok(pd(1404, 12, 29)));
+        assert_eq!(pd(1403, 12, 30).start_of_quarter(), Ok(pd(1403, 10, 1)));
+
+        assert_eq!(pd(1403, 8, 20).format("Q%q %Y"), "Q3 1403");
+        assert_eq!(
+            pdt(1403, 1, 1, 9, 0, 0).format("%Y-Q%q %H:%M"),
+            "1403-Q1 09:00"
+        );
+
+        let invalid = unsafe { ParsiDate::new_unchecked(1403, 13, 1) };
+        assert_eq!(invalid.quarter(), Err(DateError::InvalidDate));
+        assert_eq!(invalid.start_of_quarter(), Err(DateError::InvalidDate));
+        assert_eq!(invalid.end_of_quarter(), Err(DateError::InvalidDate));
+        assert_eq!(invalid.format("%q"), "?");
+    }
 }
 
 // This module is only compiled when the 'timezone' feature is enabled.
@@ -1546,6 +4877,45 @@ mod zoned_datetime_tests {
         );
     }
 
+    #[test]
+    fn test_from_zoned_uses_local_wall_clock() {
+        use crate::ParsiDateTime;
+        use chrono::{TimeZone, Utc};
+
+        // 21:00 UTC on Esfand 30th 1403 is already Nowruz 1404 in Tehran (+03:30).
+        let utc = Utc.with_ymd_and_hms(2025, 3, 20, 21, 0, 0).unwrap();
+        assert_eq!(
+            ParsiDate::from_zoned(utc),
+            Ok(ParsiDate::new(1403, 12, 30).unwrap())
+        );
+        let tehran = utc.with_timezone(&Tehran);
+        assert_eq!(
+            ParsiDate::from_zoned(tehran),
+            Ok(ParsiDate::new(1404, 1, 1).unwrap())
+        );
+        assert_eq!(
+            ParsiDateTime::from_zoned(tehran),
+            Ok(ParsiDateTime::new(1404, 1, 1, 0, 30, 0).unwrap())
+        );
+        // New York (-04:00 in March) is still on the previous day.
+        assert_eq!(
+            ParsiDateTime::from_zoned(utc.with_timezone(&New_York)),
+            Ok(ParsiDateTime::new(1403, 12, 30, 17, 0, 0).unwrap())
+        );
+
+        // Agrees with the ZonedParsiDateTime view of the same instant.
+        let zdt = z_pdt(1403, 5, 2, 23, 59, 59, London).with_timezone(&Tehran);
+        let chrono_dt = Utc.with_ymd_and_hms(2024, 7, 23, 22, 59, 59).unwrap();
+        assert_eq!(
+            ParsiDate::from_zoned(chrono_dt.with_timezone(&Tehran)),
+            Ok(zdt.date())
+        );
+        assert_eq!(
+            ParsiDateTime::from_zoned(chrono_dt.with_timezone(&Tehran)),
+            Ok(zdt.datetime())
+        );
+    }
+
     #[test]
     fn test_display_and_debug_format() {
         // Tehran winter offset is +03:30.
@@ -1558,3 +4928,97 @@ mod zoned_datetime_tests {
         assert!(debug_str.contains("timezone: Asia/Tehran"));
     }
 }
+
+#[cfg(all(test, feature = "hijri"))]
+mod hijri_tests {
+    use crate::{DateError, HijriDate, ParsiDate};
+
+    fn pd(y: i32, m: u32, d: u32) -> ParsiDate {
+        ParsiDate::new(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_hijri_date_validation() {
+        assert!(HijriDate::new(1445, 12, 29).is_ok());
+        assert!(HijriDate::is_leap_year(1445)); // Year 5 of its 30-year cycle
+        assert_eq!(HijriDate::days_in_month(1445, 12), 30);
+        assert_eq!(HijriDate::days_in_month(1446, 12), 29);
+        assert_eq!(HijriDate::new(1446, 12, 30), Err(DateError::InvalidDate));
+        assert_eq!(HijriDate::new(1446, 2, 30), Err(DateError::InvalidDate));
+        assert_eq!(HijriDate::new(0, 1, 1), Err(DateError::InvalidDate));
+        assert_eq!(HijriDate::new(1446, 13, 1), Err(DateError::InvalidDate));
+        // 11 leap years in every 30-year cycle
+        assert_eq!((1..=30).filter(|&y| HijriDate::is_leap_year(y)).count(), 11);
+    }
+
+    #[test]
+    fn test_hijri_round_trip_and_continuity() {
+        // Consecutive Persian days map to consecutive Hijri days and back
+        let mut date = pd(1402, 11, 1);
+        let mut prev = date.to_hijri().unwrap();
+        for _ in 0..800 {
+            date = date.add_days(1).unwrap();
+            let hijri = date.to_hijri().unwrap();
+            let expected_next = HijriDate::new(prev.year(), prev.month(), prev.day() + 1)
+                .or_else(|_| HijriDate::new(prev.year(), prev.month() + 1, 1))
+                .or_else(|_| HijriDate::new(prev.year() + 1, 1, 1))
+                .unwrap();
+            assert_eq!(hijri, expected_next, "{date:?}");
+            assert_eq!(ParsiDate::from_hijri(hijri), Ok(date));
+            prev = hijri;
+        }
+    }
+
+    #[test]
+    fn test_hijri_boundaries() {
+        let epoch = HijriDate::new(1, 1, 1).unwrap();
+        assert_eq!(ParsiDate::from_hijri(epoch), Ok(pd(1, 4, 28)));
+        assert_eq!(pd(1, 4, 28).to_hijri(), Ok(epoch));
+        assert_eq!(pd(1, 4, 27).to_hijri(), Err(DateError::ArithmeticOverflow));
+        assert_eq!(
+            pd(9999, 12, 29).to_hijri(),
+            Err(DateError::ArithmeticOverflow)
+        );
+        assert!(ParsiDate::from_hijri(HijriDate::new(9999, 12, 29).unwrap()).is_ok());
+        let invalid = unsafe { ParsiDate::new_unchecked(1404, 12, 30) };
+        assert_eq!(invalid.to_hijri(), Err(DateError::InvalidDate));
+    }
+}
+
+#[cfg(all(test, feature = "arbitrary"))]
+mod arbitrary_tests {
+    use crate::{ParsiDate, ParsiDateTime};
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn test_arbitrary_generates_valid_instances() {
+        // Deterministic pseudo-random input bytes (xorshift)
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let bytes: Vec<u8> = (0..64 * 1024)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect();
+        let mut u = Unstructured::new(&bytes);
+        let mut saw_leap_day = false;
+        while !u.is_empty() {
+            let date = ParsiDate::arbitrary(&mut u).unwrap();
+            assert!(date.is_valid(), "{date:?}");
+            saw_leap_day |= date.month() == 12 && date.day() == 30;
+            let dt = ParsiDateTime::arbitrary(&mut u).unwrap();
+            assert!(dt.is_valid(), "{dt:?}");
+        }
+        assert!(saw_leap_day);
+
+        // Exhausted input still yields valid (minimal) values
+        let mut empty = Unstructured::new(&[]);
+        assert_eq!(
+            ParsiDate::arbitrary(&mut empty),
+            Ok(ParsiDate::new(1, 1, 1).unwrap())
+        );
+        assert!(ParsiDateTime::arbitrary(&mut empty).unwrap().is_valid());
+    }
+}
@@ -0,0 +1,240 @@
+// ~/src/traits.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Generic Calendar Traits
+//!
+//! This module defines [`ParsiDatelike`], a small trait implemented by both
+//! [`ParsiDate`] and [`ParsiDateTime`], so that generic code can accept
+//! "anything with a Persian calendar date" without being written twice. It is modeled loosely on
+//! chrono's `Datelike` trait. [`ParsiTimelike`] is its time-of-day counterpart, mirroring
+//! chrono's `Timelike`, which eases porting chrono-based code.
+//!
+//! The trait methods mirror inherent methods of the same names, which take precedence in
+//! ordinary method calls; the trait only matters in generic code.
+
+use crate::{DateError, ParsiDate, ParsiDateTime};
+
+/// The common date accessors and modifiers of [`ParsiDate`] and [`ParsiDateTime`].
+///
+/// Implementors only provide [`date`](ParsiDatelike::date) and the `with_*` modifiers; the
+/// accessors are derived from the date part.
+///
+/// # Examples
+///
+/// A generic function that moves any Persian date-like value to the first day of its month:
+///
+/// ```rust
+/// use parsidate::{DateError, ParsiDate, ParsiDateTime, ParsiDatelike};
+///
+/// fn start_of_month<D: ParsiDatelike>(value: &D) -> Result<D, DateError> {
+///     value.with_day(1)
+/// }
+///
+/// let date = ParsiDate::new(1403, 5, 20).unwrap();
+/// assert_eq!(start_of_month(&date), Ok(ParsiDate::new(1403, 5, 1).unwrap()));
+///
+/// let dt = ParsiDateTime::new(1403, 5, 20, 14, 30, 0).unwrap();
+/// assert_eq!(start_of_month(&dt), Ok(ParsiDateTime::new(1403, 5, 1, 14, 30, 0).unwrap()));
+///
+/// fn describe(value: &impl ParsiDatelike) -> String {
+///     format!("day {} of {}", value.ordinal().unwrap(), value.year())
+/// }
+/// assert_eq!(describe(&date), "day 144 of 1403");
+/// assert_eq!(describe(&dt), "day 144 of 1403");
+/// ```
+pub trait ParsiDatelike: Sized {
+    /// Returns the Persian date part (the value itself for a [`ParsiDate`]).
+    fn date(&self) -> ParsiDate;
+
+    /// Returns a copy with the year changed, adjusting Esfand 30th to 29th in common years.
+    ///
+    /// See [`ParsiDate::with_year`] for the errors.
+    fn with_year(&self, year: i32) -> Result<Self, DateError>;
+
+    /// Returns a copy with the month changed, clamping the day to the month's length.
+    ///
+    /// See [`ParsiDate::with_month`] for the errors.
+    fn with_month(&self, month: u32) -> Result<Self, DateError>;
+
+    /// Returns a copy with the day of the month changed.
+    ///
+    /// See [`ParsiDate::with_day`] for the errors.
+    fn with_day(&self, day: u32) -> Result<Self, DateError>;
+
+    /// Returns the year.
+    #[inline]
+    fn year(&self) -> i32 {
+        self.date().year()
+    }
+
+    /// Returns the month (1-12).
+    #[inline]
+    fn month(&self) -> u32 {
+        self.date().month()
+    }
+
+    /// Returns the day of the month (1-31).
+    #[inline]
+    fn day(&self) -> u32 {
+        self.date().day()
+    }
+
+    /// Returns the day of the year (1-366). See [`ParsiDate::ordinal`].
+    #[inline]
+    fn ordinal(&self) -> Result<u32, DateError> {
+        self.date().ordinal()
+    }
+
+    /// Returns the weekday as a number with Saturday as 0 and Friday as 6, matching `%w`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidDate)` if the date is invalid, or
+    /// `Err(DateError::GregorianConversionError)` if determining the weekday fails.
+    #[inline]
+    fn weekday_number(&self) -> Result<u32, DateError> {
+        self.date().weekday_num_sat_0()
+    }
+
+    /// Returns `true` if the year is a Persian leap year.
+    #[inline]
+    fn is_leap_year(&self) -> bool {
+        ParsiDate::is_persian_leap_year(self.year())
+    }
+}
+
+impl ParsiDatelike for ParsiDate {
+    #[inline]
+    fn date(&self) -> ParsiDate {
+        *self
+    }
+
+    fn with_year(&self, year: i32) -> Result<Self, DateError> {
+        ParsiDate::with_year(self, year)
+    }
+
+    fn with_month(&self, month: u32) -> Result<Self, DateError> {
+        ParsiDate::with_month(self, month)
+    }
+
+    fn with_day(&self, day: u32) -> Result<Self, DateError> {
+        ParsiDate::with_day(self, day)
+    }
+}
+
+impl ParsiDatelike for ParsiDateTime {
+    #[inline]
+    fn date(&self) -> ParsiDate {
+        ParsiDateTime::date(self)
+    }
+
+    fn with_year(&self, year: i32) -> Result<Self, DateError> {
+        ParsiDateTime::with_year(self, year)
+    }
+
+    fn with_month(&self, month: u32) -> Result<Self, DateError> {
+        ParsiDateTime::with_month(self, month)
+    }
+
+    fn with_day(&self, day: u32) -> Result<Self, DateError> {
+        ParsiDateTime::with_day(self, day)
+    }
+}
+
+/// The common time-of-day accessors and modifiers, mirroring chrono's `Timelike`.
+///
+/// Implemented by [`ParsiDateTime`]. Implementors provide the accessors and
+/// [`with_time`](ParsiTimelike::with_time); the single-component modifiers and the
+/// seconds-from-midnight conversions are derived from them.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::{DateError, ParsiDateTime, ParsiTimelike};
+///
+/// /// Rounds down to the start of the quarter hour.
+/// fn floor_to_quarter<T: ParsiTimelike>(value: &T) -> Result<T, DateError> {
+///     let secs = value.num_seconds_from_midnight();
+///     value.with_seconds_from_midnight(secs - secs % 900)
+/// }
+///
+/// let dt = ParsiDateTime::new(1403, 5, 2, 14, 37, 12).unwrap();
+/// assert_eq!(floor_to_quarter(&dt), Ok(ParsiDateTime::new(1403, 5, 2, 14, 30, 0).unwrap()));
+/// ```
+pub trait ParsiTimelike: Sized {
+    /// Returns the hour (0-23).
+    fn hour(&self) -> u32;
+
+    /// Returns the minute (0-59).
+    fn minute(&self) -> u32;
+
+    /// Returns the second (0-59).
+    fn second(&self) -> u32;
+
+    /// Returns a copy with the time of day replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidTime)` if a component is out of range.
+    fn with_time(&self, hour: u32, minute: u32, second: u32) -> Result<Self, DateError>;
+
+    /// Returns a copy with the hour changed. See [`with_time`](ParsiTimelike::with_time).
+    fn with_hour(&self, hour: u32) -> Result<Self, DateError> {
+        self.with_time(hour, self.minute(), self.second())
+    }
+
+    /// Returns a copy with the minute changed. See [`with_time`](ParsiTimelike::with_time).
+    fn with_minute(&self, minute: u32) -> Result<Self, DateError> {
+        self.with_time(self.hour(), minute, self.second())
+    }
+
+    /// Returns a copy with the second changed. See [`with_time`](ParsiTimelike::with_time).
+    fn with_second(&self, second: u32) -> Result<Self, DateError> {
+        self.with_time(self.hour(), self.minute(), second)
+    }
+
+    /// Returns the number of seconds elapsed since midnight (0-86399).
+    #[inline]
+    fn num_seconds_from_midnight(&self) -> u32 {
+        self.hour() * 3600 + self.minute() * 60 + self.second()
+    }
+
+    /// Returns a copy with the time set from a number of seconds since midnight.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::InvalidTime)` if `secs` is 86400 or more.
+    fn with_seconds_from_midnight(&self, secs: u32) -> Result<Self, DateError> {
+        if secs >= 86_400 {
+            return Err(DateError::InvalidTime);
+        }
+        self.with_time(secs / 3600, secs % 3600 / 60, secs % 60)
+    }
+}
+
+impl ParsiTimelike for ParsiDateTime {
+    #[inline]
+    fn hour(&self) -> u32 {
+        ParsiDateTime::hour(self)
+    }
+
+    #[inline]
+    fn minute(&self) -> u32 {
+        ParsiDateTime::minute(self)
+    }
+
+    #[inline]
+    fn second(&self) -> u32 {
+        ParsiDateTime::second(self)
+    }
+
+    fn with_time(&self, hour: u32, minute: u32, second: u32) -> Result<Self, DateError> {
+        ParsiDateTime::with_time(self, hour, minute, second)
+    }
+}
@@ -0,0 +1,107 @@
+// ~/src/view.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Cached Date Views
+//!
+//! This module defines [`ParsiDateView`], created by [`ParsiDate::view`](crate::ParsiDate::view).
+//! Methods such as [`ParsiDate::weekday`](crate::ParsiDate::weekday) or
+//! [`ParsiDate::week_of_year`](crate::ParsiDate::week_of_year) each convert the date to the
+//! Gregorian calendar on every call; a view performs that conversion once and keeps the derived
+//! properties, which pays off when many of them are queried for the same date (e.g., in a
+//! rendering loop).
+
+use crate::date::ParsiDate;
+use crate::season::Season;
+use crate::weekday::ParsiWeekday;
+use chrono::NaiveDate;
+
+/// A [`ParsiDate`] together with its precomputed derived properties.
+///
+/// All accessors are cheap field reads. A view is a snapshot: it is only valid for the date it was
+/// created from.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::{ParsiDate, ParsiWeekday, Season};
+///
+/// let view = ParsiDate::new(1403, 5, 2).unwrap().view().unwrap();
+/// assert_eq!(view.weekday(), ParsiWeekday::Seshanbeh);
+/// assert_eq!(view.ordinal(), 126);
+/// assert_eq!(view.season(), Season::Tabestan);
+/// assert_eq!(view.week_of_year(), 19);
+/// assert_eq!(view.gregorian().to_string(), "2024-07-23");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct ParsiDateView {
+    date: ParsiDate,
+    gregorian: NaiveDate,
+    weekday: ParsiWeekday,
+    ordinal: u32,
+    season: Season,
+    week_of_year: u32,
+}
+
+impl ParsiDateView {
+    /// **Internal**: Assembles a view from already computed properties.
+    pub(crate) fn new(
+        date: ParsiDate,
+        gregorian: NaiveDate,
+        weekday: ParsiWeekday,
+        ordinal: u32,
+        season: Season,
+    ) -> Self {
+        // Weekday (Saturday = 0) of Farvardin 1st, derived from this date's weekday and ordinal.
+        let first_day_weekday = (weekday.num_sat_0() + 7 - (ordinal - 1) % 7) % 7;
+        ParsiDateView {
+            date,
+            gregorian,
+            weekday,
+            ordinal,
+            season,
+            week_of_year: (ordinal + first_day_weekday - 1) / 7 + 1,
+        }
+    }
+
+    /// Returns the date this view was created from.
+    #[inline]
+    pub fn date(&self) -> ParsiDate {
+        self.date
+    }
+
+    /// Returns the equivalent Gregorian date.
+    #[inline]
+    pub fn gregorian(&self) -> NaiveDate {
+        self.gregorian
+    }
+
+    /// Returns the day of the week.
+    #[inline]
+    pub fn weekday(&self) -> ParsiWeekday {
+        self.weekday
+    }
+
+    /// Returns the day of the year (1-366), as [`ParsiDate::ordinal`] would.
+    #[inline]
+    pub fn ordinal(&self) -> u32 {
+        self.ordinal
+    }
+
+    /// Returns the season, as [`ParsiDate::season`] would.
+    #[inline]
+    pub fn season(&self) -> Season {
+        self.season
+    }
+
+    /// Returns the week number of the year, as [`ParsiDate::week_of_year`] would.
+    #[inline]
+    pub fn week_of_year(&self) -> u32 {
+        self.week_of_year
+    }
+}
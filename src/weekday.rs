@@ -0,0 +1,192 @@
+// ~/src/weekday.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Persian Weekdays
+//!
+//! This module defines the [`ParsiWeekday`] enum, which represents the seven days of the week
+//! following the Persian convention, where the week starts on Saturday (Shanbeh) and ends on
+//! Friday (Jomeh).
+//!
+//! The variant order (and the value of [`ParsiWeekday::num_sat_0`]) matches the numbering of the
+//! `%w` format specifier: Saturday = 0, ..., Friday = 6.
+
+use crate::constants::{WEEKDAY_NAMES_ENGLISH, WEEKDAY_NAMES_FINGLISH, WEEKDAY_NAMES_PERSIAN};
+use std::fmt;
+
+/// Represents a day of the week in the Persian calendar, starting with Saturday.
+///
+/// This enum is `Copy`, `Clone`, `Debug`, `PartialEq`, `Eq`, and `Hash`. It can also be serialized
+/// and deserialized with `serde` if the `serde` feature is enabled.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParsiWeekday {
+    /// **Shanbeh** (شنبه), Saturday. The first day of the Persian week.
+    Shanbeh,
+    /// **Yekshanbeh** (یکشنبه), Sunday.
+    Yekshanbeh,
+    /// **Doshanbeh** (دوشنبه), Monday.
+    Doshanbeh,
+    /// **Seshanbeh** (سه‌شنبه), Tuesday.
+    Seshanbeh,
+    /// **Chaharshanbeh** (چهارشنبه), Wednesday.
+    Chaharshanbeh,
+    /// **Panjshanbeh** (پنجشنبه), Thursday.
+    Panjshanbeh,
+    /// **Jomeh** (جمعه), Friday. The weekly holiday in Iran.
+    Jomeh,
+}
+
+impl ParsiWeekday {
+    /// Returns the full Persian name of the weekday as a static string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiWeekday;
+    ///
+    /// assert_eq!(ParsiWeekday::Shanbeh.name_persian(), "شنبه");
+    /// assert_eq!(ParsiWeekday::Jomeh.name_persian(), "جمعه");
+    /// ```
+    #[inline]
+    pub fn name_persian(&self) -> &'static str {
+        // The enum variants are ordered to match the constants array.
+        WEEKDAY_NAMES_PERSIAN[*self as usize]
+    }
+
+    /// Returns the English name of the weekday as a static string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiWeekday;
+    ///
+    /// assert_eq!(ParsiWeekday::Shanbeh.name_english(), "Saturday");
+    /// assert_eq!(ParsiWeekday::Jomeh.name_english(), "Friday");
+    /// ```
+    #[inline]
+    pub fn name_english(&self) -> &'static str {
+        WEEKDAY_NAMES_ENGLISH[*self as usize]
+    }
+
+    /// Returns the Latin transliteration ("Finglish") of the Persian weekday name, as written by
+    /// the `%a` format specifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiWeekday;
+    ///
+    /// assert_eq!(ParsiWeekday::Shanbeh.name_finglish(), "Shanbeh");
+    /// assert_eq!(ParsiWeekday::Jomeh.name_finglish(), "Jomeh");
+    /// ```
+    #[inline]
+    pub fn name_finglish(&self) -> &'static str {
+        WEEKDAY_NAMES_FINGLISH[*self as usize]
+    }
+
+    /// Returns the weekday number with Saturday as 0, ..., Friday as 6, matching `%w`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiWeekday;
+    ///
+    /// assert_eq!(ParsiWeekday::Shanbeh.num_sat_0(), 0);
+    /// assert_eq!(ParsiWeekday::Seshanbeh.num_sat_0(), 3);
+    /// assert_eq!(ParsiWeekday::Jomeh.num_sat_0(), 6);
+    /// ```
+    #[inline]
+    pub fn num_sat_0(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the following weekday, wrapping from Friday (`Jomeh`) to Saturday (`Shanbeh`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiWeekday;
+    ///
+    /// assert_eq!(ParsiWeekday::Shanbeh.succ(), ParsiWeekday::Yekshanbeh);
+    /// assert_eq!(ParsiWeekday::Jomeh.succ(), ParsiWeekday::Shanbeh);
+    /// ```
+    #[inline]
+    pub fn succ(&self) -> Self {
+        Self::from_num_sat_0((self.num_sat_0() + 1) % 7).expect("value is always in 0..7")
+    }
+
+    /// Returns the preceding weekday, wrapping from Saturday (`Shanbeh`) to Friday (`Jomeh`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiWeekday;
+    ///
+    /// assert_eq!(ParsiWeekday::Yekshanbeh.pred(), ParsiWeekday::Shanbeh);
+    /// assert_eq!(ParsiWeekday::Shanbeh.pred(), ParsiWeekday::Jomeh);
+    /// ```
+    #[inline]
+    pub fn pred(&self) -> Self {
+        Self::from_num_sat_0((self.num_sat_0() + 6) % 7).expect("value is always in 0..7")
+    }
+
+    /// Returns the number of days (0-6) from this weekday forward to the next `target`.
+    ///
+    /// Returns 0 when `target` is the same weekday. This is the weekday-only part of
+    /// "next occurrence of X" calculations and involves no actual date.
+    ///
+    /// # Arguments
+    ///
+    /// * `target`: The weekday to count forward to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiWeekday;
+    ///
+    /// assert_eq!(ParsiWeekday::Shanbeh.days_until(ParsiWeekday::Seshanbeh), 3);
+    /// assert_eq!(ParsiWeekday::Jomeh.days_until(ParsiWeekday::Shanbeh), 1);
+    /// assert_eq!(ParsiWeekday::Seshanbeh.days_until(ParsiWeekday::Doshanbeh), 6);
+    /// assert_eq!(ParsiWeekday::Doshanbeh.days_until(ParsiWeekday::Doshanbeh), 0);
+    /// ```
+    #[inline]
+    pub fn days_until(&self, target: ParsiWeekday) -> u8 {
+        ((target.num_sat_0() + 7 - self.num_sat_0()) % 7) as u8
+    }
+
+    /// **Internal**: Returns the weekday for a Saturday-0 number, or `None` if `num > 6`.
+    pub(crate) fn from_num_sat_0(num: u32) -> Option<Self> {
+        const ALL: [ParsiWeekday; 7] = [
+            ParsiWeekday::Shanbeh,
+            ParsiWeekday::Yekshanbeh,
+            ParsiWeekday::Doshanbeh,
+            ParsiWeekday::Seshanbeh,
+            ParsiWeekday::Chaharshanbeh,
+            ParsiWeekday::Panjshanbeh,
+            ParsiWeekday::Jomeh,
+        ];
+        ALL.get(num as usize).copied()
+    }
+}
+
+/// Implements the `Display` trait for `ParsiWeekday`, using its Persian name.
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::ParsiWeekday;
+///
+/// assert_eq!(ParsiWeekday::Chaharshanbeh.to_string(), "چهارشنبه");
+/// ```
+impl fmt::Display for ParsiWeekday {
+    /// Formats the weekday using its Persian name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name_persian())
+    }
+}
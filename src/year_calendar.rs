@@ -0,0 +1,87 @@
+// ~/src/year_calendar.rs
+//
+//  * Copyright (C) ParsiCore (parsidate) 2024-2025 <parsicore.dev@gmail.com>
+//  * Package : parsidate
+//  * License : Apache-2.0
+//  * Version : 1.7.1
+//  * URL     : https://github.com/parsicore/parsidate
+//  * Sign: parsidate-20250607-fea13e856dcd-459c6e73c83e49e10162ee28b26ac7cd
+//
+//! # Year Calendar Snapshot
+//!
+//! This module defines [`YearCalendar`] and [`MonthInfo`], a precomputed overview of the month
+//! structure of a Persian year, returned by [`ParsiDate::year_calendar`](crate::ParsiDate::year_calendar).
+//!
+//! UI layers rendering a full year (or a month grid) typically need, for each month, its length,
+//! the weekday on which it starts, and its season. Gathering these with individual calls means
+//! one Gregorian conversion per month; the snapshot computes everything from a single conversion
+//! of Farvardin 1st followed by plain arithmetic.
+
+use crate::season::Season;
+
+/// Summary of a single month within a [`YearCalendar`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonthInfo {
+    /// The month number (1 = Farvardin, ..., 12 = Esfand).
+    pub month: u32,
+    /// The number of days in the month (29, 30 or 31).
+    pub length: u32,
+    /// The weekday of the first day of the month (Saturday = 0, ..., Friday = 6), matching `%w`.
+    pub first_weekday: u32,
+    /// The season the month belongs to.
+    pub season: Season,
+}
+
+/// A snapshot of the month structure of a whole Persian year.
+///
+/// Created by [`ParsiDate::year_calendar`](crate::ParsiDate::year_calendar).
+///
+/// # Examples
+///
+/// ```rust
+/// use parsidate::{ParsiDate, Season};
+///
+/// let calendar = ParsiDate::year_calendar(1403).unwrap();
+/// assert!(calendar.is_leap);
+/// assert_eq!(calendar.len_days(), 366);
+///
+/// let mordad = calendar.month(5).unwrap();
+/// assert_eq!(mordad.length, 31);
+/// assert_eq!(mordad.first_weekday, 2); // Mordad 1st, 1403 was a Monday
+/// assert_eq!(mordad.season, Season::Tabestan);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YearCalendar {
+    /// The Persian year described by this snapshot.
+    pub year: i32,
+    /// Whether the year is a Persian leap year (Esfand has 30 days).
+    pub is_leap: bool,
+    /// Information for each month, indexed from 0 (Farvardin) to 11 (Esfand).
+    pub months: [MonthInfo; 12],
+}
+
+impl YearCalendar {
+    /// Returns the information for the given month number (1-12), or `None` if out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parsidate::ParsiDate;
+    ///
+    /// let calendar = ParsiDate::year_calendar(1404).unwrap();
+    /// assert_eq!(calendar.month(12).unwrap().length, 29);
+    /// assert!(calendar.month(13).is_none());
+    /// ```
+    pub fn month(&self, month: u32) -> Option<&MonthInfo> {
+        month
+            .checked_sub(1)
+            .and_then(|idx| self.months.get(idx as usize))
+    }
+
+    /// Returns the total number of days in the year (365 or 366).
+    pub fn len_days(&self) -> u32 {
+        self.months.iter().map(|m| m.length).sum()
+    }
+}
@@ -585,7 +585,7 @@ where
 /// # use chrono_tz::Asia::Tehran;
 /// let dt = ZonedParsiDateTime::new(1403, 8, 15, 9, 5, 0, Tehran).unwrap();
 /// let debug_str = format!("{:?}", dt);
-/// assert!(debug_str.contains("datetime: ParsiDateTime(1403/08/15 09:05:00)"));
+/// assert!(debug_str.contains("datetime: ParsiDateTime { date: ParsiDate(1403-08-15 [Jalali]), hour: 9, minute: 5, second: 0, nanosecond: 0 }"));
 /// assert!(debug_str.contains("timezone: Asia/Tehran"));
 /// # }
 /// ```
@@ -601,3 +601,74 @@ where
             .finish()
     }
 }
+
+// --- Conversions from chrono's zoned values ---
+
+impl ParsiDate {
+    /// Converts a zoned `chrono::DateTime<Tz>` into the Persian date seen in its own timezone.
+    ///
+    /// The conversion uses the **local** wall-clock date of `dt` (`dt.date_naive()`), not the
+    /// UTC date. Near midnight the two can differ, so the same instant may map to different
+    /// Persian dates depending on the timezone it is expressed in. This is a shorthand for
+    /// `ParsiDate::from_gregorian(dt.date_naive())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::GregorianConversionError)` if the local date is outside the
+    /// supported Persian range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "timezone")] {
+    /// use chrono::{TimeZone, Utc};
+    /// use chrono_tz::Asia::Tehran;
+    /// use parsidate::ParsiDate;
+    ///
+    /// // 22:00 UTC on 2024-07-23 is already 01:30 on 2024-07-24 in Tehran.
+    /// let utc = Utc.with_ymd_and_hms(2024, 7, 23, 22, 0, 0).unwrap();
+    /// assert_eq!(ParsiDate::from_zoned(utc), Ok(ParsiDate::new(1403, 5, 2).unwrap()));
+    /// assert_eq!(
+    ///     ParsiDate::from_zoned(utc.with_timezone(&Tehran)),
+    ///     Ok(ParsiDate::new(1403, 5, 3).unwrap())
+    /// );
+    /// # }
+    /// ```
+    pub fn from_zoned<Tz: TimeZone>(dt: DateTime<Tz>) -> Result<ParsiDate, DateError> {
+        ParsiDate::from_gregorian(dt.date_naive())
+    }
+}
+
+impl ParsiDateTime {
+    /// Converts a zoned `chrono::DateTime<Tz>` into the Persian date and time seen in its own
+    /// timezone.
+    ///
+    /// The conversion uses the **local** wall-clock date and time of `dt` (`dt.naive_local()`),
+    /// not UTC, and drops the timezone. To keep the timezone, use
+    /// [`ZonedParsiDateTime`] instead. This is a shorthand for
+    /// `ParsiDateTime::from_gregorian(dt.naive_local())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(DateError::GregorianConversionError)` if the local date is outside the
+    /// supported Persian range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "timezone")] {
+    /// use chrono::{TimeZone, Utc};
+    /// use chrono_tz::Asia::Tehran;
+    /// use parsidate::ParsiDateTime;
+    ///
+    /// let utc = Utc.with_ymd_and_hms(2024, 7, 23, 22, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     ParsiDateTime::from_zoned(utc.with_timezone(&Tehran)),
+    ///     Ok(ParsiDateTime::new(1403, 5, 3, 1, 30, 0).unwrap())
+    /// );
+    /// # }
+    /// ```
+    pub fn from_zoned<Tz: TimeZone>(dt: DateTime<Tz>) -> Result<ParsiDateTime, DateError> {
+        ParsiDateTime::from_gregorian(dt.naive_local())
+    }
+}